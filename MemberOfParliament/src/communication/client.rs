@@ -23,7 +23,7 @@ use util;
 use executor::TaskCommand;
 
 
-fn construct_message(message_id : &String, state: &Arc<RwLock<WorkerState>>, message: ServerMessageType) -> SingleWorkerMessage {
+fn construct_message(message_id : &String, config: &Config, state: &Arc<RwLock<WorkerState>>, message: ServerMessageType) -> SingleWorkerMessage {
     return match message {
         ServerMessageType::ConnectionRequest(authentication, port) => {
             trace!("{} || Sending ConnectionRequest", &message_id);
@@ -31,6 +31,16 @@ fn construct_message(message_id : &String, state: &Arc<RwLock<WorkerState>>, mes
             connection_request.authentication = authentication;
             connection_request.port = port;
 
+            let labels = config.labels.iter()
+                .map(|(key, value)| {
+                    let mut label = WorkerLabel::new();
+                    label.set_key(key.clone());
+                    label.set_value(value.clone());
+                    label
+                })
+                .collect();
+            connection_request.set_labels(RepeatedField::from_vec(labels));
+
             let mut single_request = SingleWorkerMessage::new();
             single_request.set_connection_request(connection_request);
 
@@ -48,6 +58,16 @@ fn construct_message(message_id : &String, state: &Arc<RwLock<WorkerState>>, mes
             let data_out = RepeatedField::from_vec(data.to_vec());
             finished_request.set_data_out(data_out);
 
+            let accumulators = readable_state.task_accumulators.iter()
+                .map(|(name, value)| {
+                    let mut accumulator = WorkerAccumulator::new();
+                    accumulator.set_name(name.clone());
+                    accumulator.set_value(*value);
+                    accumulator
+                })
+                .collect();
+            finished_request.set_accumulators(RepeatedField::from_vec(accumulators));
+
 
             let mut single_request = SingleWorkerMessage::new();
             single_request.set_finished_request(finished_request);
@@ -66,14 +86,56 @@ fn construct_message(message_id : &String, state: &Arc<RwLock<WorkerState>>, mes
             let mut single_request = SingleWorkerMessage::new();
             single_request.set_finished_request(finished_request);
 
+            single_request
+        },
+        ServerMessageType::FinishedRequest(TaskResult::JobTimedOut, _) => {
+            trace!("{} || Sending FinishedRequest:JobTimedOut", &message_id);
+            let readable_state = state.read().unwrap();
+            let mut finished_request = WorkerFinishedRequest::new();
+
+            finished_request.set_worker_id(readable_state.worker_id.clone());
+            finished_request.set_status(WorkerFinishedRequest_WorkerTaskStatus::TASK_TIMED_OUT);
+            finished_request.set_task_id(readable_state.task_id.clone());
+
+            let mut single_request = SingleWorkerMessage::new();
+            single_request.set_finished_request(finished_request);
+
+            single_request
+        },
+        ServerMessageType::FinishedRequest(TaskResult::JobOutOfMemory, _) => {
+            trace!("{} || Sending FinishedRequest:JobOutOfMemory", &message_id);
+            let readable_state = state.read().unwrap();
+            let mut finished_request = WorkerFinishedRequest::new();
+
+            finished_request.set_worker_id(readable_state.worker_id.clone());
+            finished_request.set_status(WorkerFinishedRequest_WorkerTaskStatus::TASK_OUT_OF_MEMORY);
+            finished_request.set_task_id(readable_state.task_id.clone());
+
+            let mut single_request = SingleWorkerMessage::new();
+            single_request.set_finished_request(finished_request);
+
+            single_request
+        },
+        ServerMessageType::FinishedRequest(TaskResult::JobUnavailable, _) => {
+            trace!("{} || Sending FinishedRequest:JobUnavailable", &message_id);
+            let readable_state = state.read().unwrap();
+            let mut finished_request = WorkerFinishedRequest::new();
+
+            finished_request.set_worker_id(readable_state.worker_id.clone());
+            finished_request.set_status(WorkerFinishedRequest_WorkerTaskStatus::TASK_UNAVAILABLE);
+            finished_request.set_task_id(readable_state.task_id.clone());
+
+            let mut single_request = SingleWorkerMessage::new();
+            single_request.set_finished_request(finished_request);
+
             single_request
         }
     };
 }
 
-fn write_single_response(message_id: &String,
-                         message: SingleWorkerMessage,
-                         stream: &mut TcpStream) -> bool {
+pub fn write_single_response(message_id: &String,
+                             message: SingleWorkerMessage,
+                             stream: &mut TcpStream) -> bool {
     let size = message.compute_size();
     if let Ok(_) = stream.write_u32::<BigEndian>(size) {
         let mut output_stream = CodedOutputStream::new(stream);
@@ -96,7 +158,7 @@ fn write_single_response(message_id: &String,
     return true;
 }
 
-fn process_input(mut stream: &TcpStream) -> ProtobufResult<SingleServerMessage> {
+pub fn process_input(mut stream: &TcpStream) -> ProtobufResult<SingleServerMessage> {
     let size_option = stream.read_u32::<BigEndian>();
     if size_option.is_err() {
         error!("Could not write to stream!");
@@ -157,7 +219,7 @@ pub fn start(config: &Config,
 
             let mut stream = stream_option.unwrap();
             let mut retry = true;
-            if write_single_response(&message_id, construct_message(&message_id, &state, message.message_type.clone()), &mut stream) {
+            if write_single_response(&message_id, construct_message(&message_id, &cloned_config, &state, message.message_type.clone()), &mut stream) {
                 match process_input(&stream) {
                     Ok(return_message) => {
                         if let Some(response) = return_message.message {
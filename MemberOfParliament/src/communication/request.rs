@@ -11,7 +11,7 @@ use crossbeam_channel::Sender;
 use log::{error, info, warn};
 use protobuf::{CodedOutputStream, Message};
 
-use config::{Config, WorkerState, WorkerStatus};
+use config::{Config, Executable, ResourceLimits, WorkerState, WorkerStatus};
 use executor::{ServerMessage, TaskCommand};
 use protos::intra_cluster::*;
 use util;
@@ -79,6 +79,8 @@ fn send_heartbeat_message(
     let mut heartbeat_response = WorkerHeartbeatResponse::new();
     unwrapped_state.last_request = util::current_secs();
     heartbeat_response.set_task_id(unwrapped_state.task_id.clone());
+    heartbeat_response.set_progress(unwrapped_state.task_progress);
+    heartbeat_response.set_progress_message(unwrapped_state.task_progress_message.clone());
     match unwrapped_state.status {
         WorkerStatus::Awaiting => {
             heartbeat_response.set_status(WorkerHeartbeatResponse_HeartbeatStatus::AWAITING_TASK)
@@ -157,8 +159,34 @@ impl RequestHandler for WorkerTaskSubmissionRequest {
                 WorkerStatus::Awaiting => {
                     worker_state.data_in = Some(self.take_data_in());
                     worker_state.closure = Some(self.take_closure());
+                    worker_state.closure_digest = self.take_closure_digest();
+                    worker_state.broadcast_digests = self
+                        .take_broadcasts()
+                        .into_iter()
+                        .map(|x| (x.name, x.digest))
+                        .collect();
                     worker_state.task_id = self.take_task_id();
                     worker_state.task_type = Some(util::convert_map_type(&self.map_type));
+                    worker_state.task_timeout = self.task_timeout.max(0) as u64;
+                    worker_state.task_progress = 0.0;
+                    worker_state.task_progress_message = "".to_string();
+                    worker_state.task_accumulators = Vec::new();
+                    worker_state.task_limits = ResourceLimits {
+                        memory_mb: self.get_limits().memory_mb.max(0) as u64,
+                        cpu_percent: self.get_limits().cpu_percent.max(0) as u64,
+                        open_files: self.get_limits().open_files.max(0) as u64,
+                    };
+                    let mut executable = self.take_executable();
+                    worker_state.task_executable = Executable {
+                        name: executable.take_name(),
+                        args: executable.take_args().into_vec(),
+                        env: executable
+                            .take_env()
+                            .into_iter()
+                            .map(|x| (x.key, x.value))
+                            .collect(),
+                        artifact_digest: executable.take_artifact_digest(),
+                    };
                     match executor_sender.send(TaskCommand::StartTask(self.take_docker_name())) {
                         Ok(_) => (info!("{} || Accepted task successfully", &message_id)),
                         Err(e) => error!(
@@ -3,6 +3,9 @@
     Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
 */
 
+use std::collections::HashMap;
+use std::env;
+
 use executor::TaskType;
 use protobuf::RepeatedField;
 
@@ -12,6 +15,31 @@ pub struct ConfigServer {
     pub port: i32,
 }
 
+// A value of 0 means no limit
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct ResourceLimits {
+    pub memory_mb: u64,
+    pub cpu_percent: u64,
+    pub open_files: u64,
+}
+
+// Program and arguments requested by the user for tasks run without Docker
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Executable {
+    pub name: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub artifact_digest: String,
+}
+
+// When Docker images are pulled before a task is started
+#[derive(PartialEq, Clone, Debug)]
+pub enum PullPolicy {
+    Always,
+    IfNotPresent,
+    Never,
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub master: ConfigServer,
@@ -19,6 +47,21 @@ pub struct Config {
     pub executor: ConfigServer,
     pub single_run_mode: bool,
     pub timeout: i32,
+    pub labels: HashMap<String, String>,
+    pub limits: ResourceLimits,
+    pub cgroup_parent: String,
+    pub executables_dir: String,
+    pub default_executable: String,
+    pub artifact_cache: String,
+    pub closure_cache_size: usize,
+    pub broadcast_cache: String,
+    pub warm_tasks: u32,
+    pub executor_socket: String,
+    pub docker_host: String,
+    pub pull_policy: PullPolicy,
+    pub docker_network: String,
+    pub docker_gateway: String,
+    pub sandbox: bool,
 }
 
 impl Default for Config {
@@ -38,6 +81,31 @@ impl Default for Config {
             },
             single_run_mode: false,
             timeout: 60,
+            labels: HashMap::new(),
+            limits: ResourceLimits::default(),
+            cgroup_parent: "/sys/fs/cgroup/parliament".to_string(),
+            executables_dir: match env::home_dir() {
+                Some(home_dir) => format!("{}/executables", home_dir.display()),
+                None => "executables".to_string(),
+            },
+            default_executable: "parallel.exe".to_string(),
+            artifact_cache: match env::home_dir() {
+                Some(home_dir) => format!("{}/.parliament/artifacts", home_dir.display()),
+                None => ".parliament/artifacts".to_string(),
+            },
+            closure_cache_size: 16,
+            broadcast_cache: match env::home_dir() {
+                Some(home_dir) => format!("{}/.parliament/broadcasts", home_dir.display()),
+                None => ".parliament/broadcasts".to_string(),
+            },
+            warm_tasks: 0,
+            executor_socket: "".to_string(),
+            docker_host: "unix:///var/run/docker.sock".to_string(),
+            pull_policy: PullPolicy::IfNotPresent,
+            // host.docker.internal does not resolve on Linux without extra setup
+            docker_network: if cfg!(target_os = "linux") { "host" } else { "bridge" }.to_string(),
+            docker_gateway: "host.docker.internal".to_string(),
+            sandbox: false,
         };
     }
 }
@@ -58,6 +126,21 @@ pub struct WorkerState {
     pub last_request: u64,
     pub data_in: Option<RepeatedField<Vec<u8>>>,
     pub closure: Option<Vec<u8>>,
+    pub closure_digest: String,
+    // Name and digest of each broadcast of the task, and their values once they have been loaded
+    pub broadcast_digests: Vec<(String, String)>,
+    pub broadcasts: Vec<(String, Vec<u8>)>,
     pub task_type: Option<TaskType>,
     pub task_id: String,
+    pub task_timeout: u64,
+    pub task_limits: ResourceLimits,
+    pub task_executable: Executable,
+    pub executor_token: String,
+    // Token of the previous task, which a warm executor still presents when asking for its next one
+    pub warm_executor_token: String,
+    // Socket the running executor connects to, empty when it uses the executor server
+    pub executor_socket: String,
+    pub task_progress: f32,
+    pub task_progress_message: String,
+    pub task_accumulators: Vec<(String, f64)>,
 }
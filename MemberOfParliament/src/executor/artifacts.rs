@@ -0,0 +1,139 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    artifacts.rs - Fetches uploaded executables from the master and caches them by digest
+*/
+
+use std::fs;
+use std::net::TcpStream;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use log::{error, info, warn};
+
+use communication::client::{process_input, write_single_response};
+use config::{Config, WorkerState};
+use protos::intra_cluster::*;
+use util;
+
+fn request_artifact(message_id: &String,
+                    config: &Config,
+                    worker_id: String,
+                    digest: &String) -> Option<Vec<u8>> {
+    let mut stream = match TcpStream::connect(format!("{}:{}", config.master.hostname, config.master.port)) {
+        Ok(stream) => stream,
+        Err(e) => {
+            error!("{} || Could not connect to master! Error: {}", &message_id, e.to_string());
+            return None;
+        }
+    };
+
+    let mut artifact_request = WorkerArtifactRequest::new();
+    artifact_request.set_worker_id(worker_id);
+    artifact_request.set_digest(digest.clone());
+
+    let mut single_request = SingleWorkerMessage::new();
+    single_request.set_artifact_request(artifact_request);
+    if !write_single_response(&message_id, single_request, &mut stream) {
+        return None;
+    }
+
+    return match process_input(&stream) {
+        Ok(mut message) => {
+            if !message.has_artifact_response() {
+                error!("{} || Received a message from the master of an incorrect type!", &message_id);
+                return None;
+            }
+            let mut response = message.take_artifact_response();
+            if !response.found {
+                error!("{} || Master does not have artifact {}", &message_id, &digest);
+                return None;
+            }
+            Some(response.take_artifact())
+        }
+        Err(e) => {
+            error!("{} || Could not decode message from TCP stream Error: {}", &message_id, e.to_string());
+            None
+        }
+    };
+}
+
+fn store_artifact(path: &Path, artifact: &Vec<u8>) -> std::io::Result<()> {
+    // Written under a temporary name first so a partial download is never executed
+    let partial_path = path.with_extension("partial");
+    fs::write(&partial_path, artifact)?;
+    fs::set_permissions(&partial_path, fs::Permissions::from_mode(0o755))?;
+    return fs::rename(&partial_path, path);
+}
+
+// Returns the digest the artifact actually has if it isn't the one it was requested by
+fn verify_digest(artifact: &[u8], digest: &String) -> Result<(), String> {
+    let actual_digest = util::sha256_digest(artifact);
+    if actual_digest != *digest {
+        return Err(actual_digest);
+    }
+    return Ok(());
+}
+
+pub fn fetch(config: &Config, state: &Arc<RwLock<WorkerState>>, digest: &String) -> Option<PathBuf> {
+    if !util::valid_digest(digest) {
+        warn!("{} is not a valid artifact digest!", &digest);
+        return None;
+    }
+
+    let path = Path::new(&config.artifact_cache).join(digest);
+    if path.is_file() {
+        info!("Using cached artifact {}", path.display());
+        return Some(path);
+    }
+
+    let message_id = util::random_alphanum_string(10);
+    info!("{} || Fetching artifact {} from the master", &message_id, &digest);
+    let worker_id = state.read().unwrap().worker_id.clone();
+    let artifact = request_artifact(&message_id, config, worker_id, digest)?;
+
+    if let Err(actual_digest) = verify_digest(&artifact, digest) {
+        error!("{} || Artifact digest mismatch! Expected: {}, Received: {}", &message_id, &digest, &actual_digest);
+        return None;
+    }
+
+    if let Err(e) = fs::create_dir_all(&config.artifact_cache).and_then(|_| store_artifact(&path, &artifact)) {
+        error!("{} || Could not cache artifact in {}! Error: {}", &message_id, &config.artifact_cache, e.to_string());
+        return None;
+    }
+
+    info!("{} || Cached artifact {} ({} bytes)", &message_id, path.display(), artifact.len());
+    return Some(path);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const ABC_DIGEST: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    #[test]
+    fn test_sanity_verify_digest() {
+        assert_eq!(verify_digest(b"abc", &ABC_DIGEST.to_string()), Ok(()));
+        assert_eq!(verify_digest(b"abd", &ABC_DIGEST.to_string()).is_err(), true);
+        assert_eq!(verify_digest(b"abc", &ABC_DIGEST.to_uppercase()), Err(ABC_DIGEST.to_string()));
+        assert_eq!(util::valid_digest(ABC_DIGEST), true);
+    }
+
+    #[test]
+    fn test_sanity_store_artifact() {
+        let dir = env::temp_dir().join("parliament-artifacts-test");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(ABC_DIGEST);
+
+        store_artifact(&path, &b"abc".to_vec()).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"abc".to_vec());
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o755);
+        assert_eq!(path.with_extension("partial").exists(), false);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
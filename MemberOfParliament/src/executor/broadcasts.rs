@@ -0,0 +1,176 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    broadcasts.rs - Fetches the broadcasts of a task's workload from the master and caches them by digest
+*/
+
+use std::fs;
+use std::net::TcpStream;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use log::{error, info, warn};
+
+use communication::client::{process_input, write_single_response};
+use config::{Config, WorkerState};
+use protos::intra_cluster::*;
+use util;
+
+fn request_broadcast(message_id: &String,
+                     config: &Config,
+                     worker_id: String,
+                     digest: &String) -> Option<Vec<u8>> {
+    let mut stream = match TcpStream::connect(format!("{}:{}", config.master.hostname, config.master.port)) {
+        Ok(stream) => stream,
+        Err(e) => {
+            error!("{} || Could not connect to master! Error: {}", &message_id, e.to_string());
+            return None;
+        }
+    };
+
+    let mut broadcast_request = WorkerBroadcastRequest::new();
+    broadcast_request.set_worker_id(worker_id);
+    broadcast_request.set_digest(digest.clone());
+
+    let mut single_request = SingleWorkerMessage::new();
+    single_request.set_broadcast_request(broadcast_request);
+    if !write_single_response(&message_id, single_request, &mut stream) {
+        return None;
+    }
+
+    return match process_input(&stream) {
+        Ok(mut message) => {
+            if !message.has_broadcast_response() {
+                error!("{} || Received a message from the master of an incorrect type!", &message_id);
+                return None;
+            }
+            let mut response = message.take_broadcast_response();
+            if !response.found {
+                error!("{} || Master does not have broadcast {}", &message_id, &digest);
+                return None;
+            }
+            Some(response.take_value())
+        }
+        Err(e) => {
+            error!("{} || Could not decode message from TCP stream Error: {}", &message_id, e.to_string());
+            None
+        }
+    };
+}
+
+fn store_broadcast(path: &Path, value: &Vec<u8>) -> std::io::Result<()> {
+    // Written under a temporary name first so a partial download is never read
+    let partial_path = path.with_extension("partial");
+    fs::write(&partial_path, value)?;
+    return fs::rename(&partial_path, path);
+}
+
+fn fetch(config: &Config, worker_id: &String, digest: &String) -> Option<Vec<u8>> {
+    if !util::valid_digest(digest) {
+        warn!("{} is not a valid broadcast digest!", &digest);
+        return None;
+    }
+
+    let path = Path::new(&config.broadcast_cache).join(digest);
+    if let Ok(value) = fs::read(&path) {
+        info!("Using cached broadcast {}", path.display());
+        return Some(value);
+    }
+
+    let message_id = util::random_alphanum_string(10);
+    info!("{} || Fetching broadcast {} from the master", &message_id, &digest);
+    let value = request_broadcast(&message_id, config, worker_id.clone(), digest)?;
+
+    let actual_digest = util::sha256_digest(&value);
+    if actual_digest != *digest {
+        error!("{} || Broadcast digest mismatch! Expected: {}, Received: {}", &message_id, &digest, &actual_digest);
+        return None;
+    }
+
+    // The task can still run with a broadcast that could not be cached
+    match fs::create_dir_all(&config.broadcast_cache).and_then(|_| store_broadcast(&path, &value)) {
+        Ok(_) => info!("{} || Cached broadcast {} ({} bytes)", &message_id, path.display(), value.len()),
+        Err(e) => warn!("{} || Could not cache broadcast in {}! Error: {}", &message_id, &config.broadcast_cache, e.to_string()),
+    }
+    return Some(value);
+}
+
+// Loads the values of the submitted task's broadcasts into the worker state, returns false if one can't be found
+pub fn prepare(config: &Config, state: &Arc<RwLock<WorkerState>>) -> bool {
+    let (digests, worker_id) = {
+        let readable_state = state.read().unwrap();
+        (readable_state.broadcast_digests.clone(), readable_state.worker_id.clone())
+    };
+
+    let mut broadcasts = Vec::new();
+    for (name, digest) in digests {
+        match fetch(config, &worker_id, &digest) {
+            Some(value) => broadcasts.push((name, value)),
+            None => return false,
+        }
+    }
+
+    state.write().unwrap().broadcasts = broadcasts;
+    return true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Executable, ResourceLimits};
+    use executor::executor::tests::state;
+    use std::env;
+
+    // A cache directory of its own, and a master nothing listens on
+    fn config(name: &str) -> Config {
+        let mut config = Config::default();
+        let dir = env::temp_dir().join(format!("parliament-broadcasts-test-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        config.broadcast_cache = dir.to_string_lossy().to_string();
+        config.master.hostname = "127.0.0.1".to_string();
+        config.master.port = 1;
+        return config;
+    }
+
+    #[test]
+    fn test_sanity_fetch_cached() {
+        let config = config("cached");
+        let digest = util::sha256_digest(b"lookup table");
+        store_broadcast(&Path::new(&config.broadcast_cache).join(&digest), &b"lookup table".to_vec()).unwrap();
+
+        assert_eq!(fetch(&config, &"worker".to_string(), &digest), Some(b"lookup table".to_vec()));
+        assert_eq!(Path::new(&config.broadcast_cache).join(&digest).with_extension("partial").exists(), false);
+        assert_eq!(fetch(&config, &"worker".to_string(), &"../secret".to_string()), None);
+    }
+
+    #[test]
+    fn test_sanity_prepare() {
+        let config = config("prepare");
+        let first = util::sha256_digest(b"first");
+        let second = util::sha256_digest(b"second");
+        store_broadcast(&Path::new(&config.broadcast_cache).join(&first), &b"first".to_vec()).unwrap();
+        store_broadcast(&Path::new(&config.broadcast_cache).join(&second), &b"second".to_vec()).unwrap();
+
+        let state = state(Executable::default(), ResourceLimits::default());
+        state.write().unwrap().broadcast_digests = vec![("b".to_string(), second), ("a".to_string(), first)];
+        assert_eq!(prepare(&config, &state), true);
+        assert_eq!(
+            state.read().unwrap().broadcasts,
+            vec![("b".to_string(), b"second".to_vec()), ("a".to_string(), b"first".to_vec())]
+        );
+    }
+
+    #[test]
+    fn test_sanity_prepare_missing() {
+        let config = config("missing");
+        let state = state(Executable::default(), ResourceLimits::default());
+        state.write().unwrap().broadcasts = vec![("old".to_string(), b"old".to_vec())];
+        state.write().unwrap().broadcast_digests = vec![("a".to_string(), util::sha256_digest(b"not cached"))];
+
+        // The previous task's broadcasts are left alone, the task is not run
+        assert_eq!(prepare(&config, &state), false);
+        assert_eq!(state.read().unwrap().broadcasts, vec![("old".to_string(), b"old".to_vec())]);
+    }
+}
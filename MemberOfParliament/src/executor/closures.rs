@@ -0,0 +1,193 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    closures.rs - LRU cache of task closures, fetched from the master by digest on a miss
+*/
+
+use std::collections::{HashMap, VecDeque};
+use std::net::TcpStream;
+use std::sync::{Arc, RwLock};
+
+use log::{error, info};
+
+use communication::client::{process_input, write_single_response};
+use config::{Config, WorkerState};
+use protos::intra_cluster::*;
+use util;
+
+pub struct ClosureCache {
+    capacity: usize,
+    closures: HashMap<String, Vec<u8>>,
+    // Least recently used digest at the front
+    order: VecDeque<String>,
+}
+
+impl ClosureCache {
+    pub fn new(capacity: usize) -> ClosureCache {
+        return ClosureCache {
+            capacity,
+            closures: HashMap::new(),
+            order: VecDeque::new(),
+        };
+    }
+
+    fn touch(&mut self, digest: &String) {
+        if let Some(index) = self.order.iter().position(|x| x == digest) {
+            self.order.remove(index);
+        }
+        self.order.push_back(digest.clone());
+    }
+
+    pub fn get(&mut self, digest: &String) -> Option<Vec<u8>> {
+        let closure = self.closures.get(digest).cloned()?;
+        self.touch(digest);
+        return Some(closure);
+    }
+
+    pub fn insert(&mut self, digest: String, closure: Vec<u8>) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.touch(&digest);
+        self.closures.insert(digest, closure);
+
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.closures.remove(&evicted);
+            }
+        }
+    }
+}
+
+fn request_closure(message_id: &String,
+                   config: &Config,
+                   worker_id: String,
+                   task_id: String,
+                   digest: &String) -> Option<Vec<u8>> {
+    let mut stream = match TcpStream::connect(format!("{}:{}", config.master.hostname, config.master.port)) {
+        Ok(stream) => stream,
+        Err(e) => {
+            error!("{} || Could not connect to master! Error: {}", &message_id, e.to_string());
+            return None;
+        }
+    };
+
+    let mut closure_request = WorkerClosureRequest::new();
+    closure_request.set_worker_id(worker_id);
+    closure_request.set_task_id(task_id);
+    closure_request.set_digest(digest.clone());
+
+    let mut single_request = SingleWorkerMessage::new();
+    single_request.set_closure_request(closure_request);
+    if !write_single_response(&message_id, single_request, &mut stream) {
+        return None;
+    }
+
+    return match process_input(&stream) {
+        Ok(mut message) => {
+            if !message.has_closure_response() {
+                error!("{} || Received a message from the master of an incorrect type!", &message_id);
+                return None;
+            }
+            let mut response = message.take_closure_response();
+            if !response.found {
+                error!("{} || Master does not have closure {}", &message_id, &digest);
+                return None;
+            }
+            Some(response.take_closure())
+        }
+        Err(e) => {
+            error!("{} || Could not decode message from TCP stream Error: {}", &message_id, e.to_string());
+            None
+        }
+    };
+}
+
+// Makes sure the closure for the submitted task is in the worker state, returns false if it can't be found
+pub fn prepare(config: &Config, state: &Arc<RwLock<WorkerState>>, cache: &mut ClosureCache) -> bool {
+    let (digest, task_id, worker_id, has_closure) = {
+        let readable_state = state.read().unwrap();
+        (
+            readable_state.closure_digest.clone(),
+            readable_state.task_id.clone(),
+            readable_state.worker_id.clone(),
+            readable_state.closure.as_ref().map_or(false, |x| x.len() > 0),
+        )
+    };
+
+    if digest.len() == 0 {
+        return has_closure;
+    }
+
+    if has_closure {
+        // Masters may still send the bytes along with the digest
+        let closure = state.read().unwrap().closure.clone().unwrap();
+        cache.insert(digest, closure);
+        return true;
+    }
+
+    let closure = match cache.get(&digest) {
+        Some(closure) => {
+            info!("Closure {} found in cache", &digest);
+            closure
+        }
+        None => {
+            let message_id = util::random_alphanum_string(10);
+            info!("{} || Closure {} not cached, fetching from the master", &message_id, &digest);
+            let closure = match request_closure(&message_id, config, worker_id, task_id, &digest) {
+                Some(closure) => closure,
+                None => return false,
+            };
+
+            let actual_digest = util::sha256_digest(&closure);
+            if actual_digest != digest {
+                error!("{} || Closure digest mismatch! Expected: {}, Received: {}", &message_id, &digest, &actual_digest);
+                return false;
+            }
+            cache.insert(digest, closure.clone());
+            closure
+        }
+    };
+
+    state.write().unwrap().closure = Some(closure);
+    return true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanity_closure_cache_eviction_order() {
+        let mut cache = ClosureCache::new(2);
+        cache.insert("a".to_string(), vec![1]);
+        cache.insert("b".to_string(), vec![2]);
+        // Reading "a" makes "b" the least recently used
+        assert_eq!(cache.get(&"a".to_string()), Some(vec![1]));
+        cache.insert("c".to_string(), vec![3]);
+
+        assert_eq!(cache.get(&"b".to_string()), None);
+        assert_eq!(cache.get(&"a".to_string()), Some(vec![1]));
+        assert_eq!(cache.get(&"c".to_string()), Some(vec![3]));
+    }
+
+    #[test]
+    fn test_sanity_closure_cache_reinsert() {
+        let mut cache = ClosureCache::new(2);
+        cache.insert("a".to_string(), vec![1]);
+        cache.insert("b".to_string(), vec![2]);
+        cache.insert("a".to_string(), vec![1]);
+        cache.insert("c".to_string(), vec![3]);
+
+        assert_eq!(cache.get(&"b".to_string()), None);
+        assert_eq!(cache.get(&"a".to_string()), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_sanity_closure_cache_capacity_zero() {
+        let mut cache = ClosureCache::new(0);
+        cache.insert("a".to_string(), vec![1]);
+        assert_eq!(cache.get(&"a".to_string()), None);
+    }
+}
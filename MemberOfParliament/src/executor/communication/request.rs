@@ -4,10 +4,12 @@
 */
 
 use std::sync::{Arc, RwLock};
-use std::net::TcpStream;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
 
-use log::{info, error};
-use protobuf::{CodedOutputStream, Message};
+use log::{info, warn, error};
+use protobuf::{CodedOutputStream, Message, RepeatedField};
 use byteorder::{WriteBytesExt, BigEndian};
 use crossbeam_channel::Sender;
 
@@ -17,19 +19,33 @@ use protos::user_cluster::*;
 use executor::{ServerMessageType, TaskResult};
 use util;
 
+// How long a warm executor waits for its next task before being told to shut down
+const INPUT_WAIT_SECS: u64 = 60;
+const INPUT_POLL_TIME: Duration = Duration::from_millis(20);
 
 pub trait RequestHandler {
     fn handle_message(&mut self,
                       message_id: &String,
-                      stream: &mut TcpStream,
+                      stream: &mut Write,
                       state: Arc<RwLock<WorkerState>>,
                       master_sender: &Sender<::executor::ServerMessage>);
 }
 
+// Stops other local processes from reading or injecting task data
+fn valid_token(message_id: &String, token: &String, state: &Arc<RwLock<WorkerState>>, warm: bool) -> bool {
+    let readable_state = state.read().unwrap();
+    let matches = |expected: &String| expected.len() > 0 && util::constant_time_eq(token.as_bytes(), expected.as_bytes());
+    // Only a warm executor asking for its next task may still use the token of its last one
+    if !matches(&readable_state.executor_token) && !(warm && matches(&readable_state.warm_executor_token)) {
+        warn!("{} || Executor presented an invalid token, ignoring request!", &message_id);
+        return false;
+    }
+    return true;
+}
 
 fn write_single_response(message_id: &String,
                          single_response: SingleWorkerResponse,
-                         stream: &mut TcpStream) {
+                         stream: &mut Write) {
 
     let size = single_response.compute_size();
     if let Ok(_) = stream.write_u32::<BigEndian>(size) {
@@ -54,17 +70,59 @@ fn write_single_response(message_id: &String,
 impl RequestHandler for WorkerInputRequest {
     fn handle_message(&mut self,
                       message_id: &String,
-                      stream: &mut TcpStream,
+                      stream: &mut Write,
                       state: Arc<RwLock<WorkerState>>,
                       _master_sender: &Sender<::executor::ServerMessage>) {
         info!("{} || Processing message as a WorkerInputRequest", &message_id);
-
-        let mut worker_state = state.read().unwrap().clone();
-
+        if !valid_token(&message_id, &self.token, &state, true) {
+            return;
+        }
+
+        // Warm executors ask for their next task before the worker has been assigned one
+        let started = util::current_secs();
+        loop {
+            {
+                let readable_state = state.read().unwrap();
+                if readable_state.status == WorkerStatus::Processing && readable_state.data_in.is_some() {
+                    break;
+                }
+            }
+            if util::current_secs() - started > INPUT_WAIT_SECS {
+                info!("{} || No task assigned in {}s, shutting the executor down", &message_id, INPUT_WAIT_SECS);
+                let mut input_response = WorkerInputResponse::new();
+                input_response.set_shutdown(true);
+
+                let mut single_response = SingleWorkerResponse::new();
+                single_response.set_input_response(input_response);
+                write_single_response(&message_id, single_response, stream);
+                return;
+            }
+            thread::sleep(INPUT_POLL_TIME);
+        }
+
+        let mut worker_state = {
+            let mut writable_state = state.write().unwrap();
+            // The executor presents the task's own token from now on
+            writable_state.warm_executor_token = "".to_string();
+            writable_state.clone()
+        };
         let mut input_response = WorkerInputResponse::new();
+        input_response.set_token(worker_state.executor_token.clone());
         input_response.set_datapacks(worker_state.data_in.take().unwrap());
         input_response.set_map_type(util::convert_map_task_type(&worker_state.task_type.unwrap()));
         input_response.set_function_closure(worker_state.closure.take().unwrap());
+        input_response.set_broadcasts(RepeatedField::from_vec(
+            worker_state
+                .broadcasts
+                .drain(..)
+                .map(|(name, value)| {
+                    let mut broadcast = Broadcast::new();
+                    broadcast.set_name(name);
+                    broadcast.set_value(value);
+                    broadcast
+                })
+                .collect(),
+        ));
 
         let mut single_response = SingleWorkerResponse::new();
         single_response.set_input_response(input_response);
@@ -75,12 +133,25 @@ impl RequestHandler for WorkerInputRequest {
 impl RequestHandler for WorkerOutputRequest {
     fn handle_message(&mut self,
                       message_id: &String,
-                      stream: &mut TcpStream,
+                      stream: &mut Write,
                       state: Arc<RwLock<WorkerState>>,
                       master_sender: &Sender<::executor::ServerMessage>) {
         info!("{} || Processing message as a WorkerOutputRequest", &message_id);
-
-        state.write().unwrap().status = WorkerStatus::Finishing;
+        if !valid_token(&message_id, &self.token, &state, false) {
+            return;
+        }
+
+        {
+            // Sent on to the master with the finished request
+            let mut worker_state = state.write().unwrap();
+            worker_state.status = WorkerStatus::Finishing;
+            worker_state.task_accumulators = self
+                .take_accumulators()
+                .into_iter()
+                .filter(|x| x.value.is_finite())
+                .map(|x| (x.name, x.value))
+                .collect();
+        }
         master_sender.send(::executor::ServerMessage {
             message_type: ServerMessageType::FinishedRequest(TaskResult::JobFinished, Arc::new(self.take_datapacks())),
             retry_count: 0
@@ -91,4 +162,55 @@ impl RequestHandler for WorkerOutputRequest {
         single_response.set_output_response(WorkerOutputResponse::new());
         write_single_response(&message_id, single_response, stream);
     }
-}
\ No newline at end of file
+}
+
+impl RequestHandler for WorkerProgressRequest {
+    fn handle_message(&mut self,
+                      message_id: &String,
+                      stream: &mut Write,
+                      state: Arc<RwLock<WorkerState>>,
+                      _master_sender: &Sender<::executor::ServerMessage>) {
+        info!("{} || Processing message as a WorkerProgressRequest", &message_id);
+        if !valid_token(&message_id, &self.token, &state, false) {
+            return;
+        }
+
+        {
+            // Sent on to the master with the next heartbeat
+            let mut worker_state = state.write().unwrap();
+            if worker_state.status == WorkerStatus::Processing {
+                worker_state.task_progress = if self.progress.is_nan() { 0.0 } else { self.progress.max(0.0).min(1.0) };
+                worker_state.task_progress_message = self.take_message();
+            }
+        }
+
+        let mut single_response = SingleWorkerResponse::new();
+        single_response.set_progress_response(WorkerProgressResponse::new());
+        write_single_response(&message_id, single_response, stream);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::{Executable, ResourceLimits};
+    use executor::executor::tests::state;
+
+    #[test]
+    fn test_sanity_valid_token() {
+        let state = state(Executable::default(), ResourceLimits::default());
+        let message_id = "test".to_string();
+        // Nothing is accepted before a task has been started
+        assert!(!valid_token(&message_id, &"".to_string(), &state, true));
+
+        state.write().unwrap().executor_token = "current".to_string();
+        state.write().unwrap().warm_executor_token = "previous".to_string();
+        assert!(valid_token(&message_id, &"current".to_string(), &state, false));
+        assert!(valid_token(&message_id, &"current".to_string(), &state, true));
+        assert!(!valid_token(&message_id, &"other".to_string(), &state, true));
+
+        // The previous token only fetches the next input
+        assert!(valid_token(&message_id, &"previous".to_string(), &state, true));
+        assert!(!valid_token(&message_id, &"previous".to_string(), &state, false));
+    }
+}
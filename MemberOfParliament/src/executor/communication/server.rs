@@ -3,10 +3,16 @@
     Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
 */
 
-use std::net::{TcpListener, TcpStream};
+use std::env;
+use std::fs::{self, DirBuilder};
+use std::net::TcpListener;
 use std::io::{Error, ErrorKind};
 use std::thread::{self, JoinHandle};
-use std::io::Read;
+use std::io::{Read, Write};
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 
 use log::{info, trace, warn, error};
@@ -30,6 +36,19 @@ fn current_millis() -> u64 {
         since_the_epoch.subsec_nanos() as u64 / 1_000_000;
 }
 
+fn handle_connection<S: Read + Write>(mut stream: S,
+                                     message_id: String,
+                                     state: Arc<RwLock<WorkerState>>,
+                                     master_sender: Sender<::executor::ServerMessage>) {
+    trace!("{} || Time: {}", &message_id, current_millis());
+    match process_input(&mut stream) {
+        Ok(message) => {
+            handle_message(&message_id, message, &mut stream, state, master_sender);
+        }
+        Err(e) => error!("{} || Could not decode message from stream Error: {}", &message_id, e.to_string())
+    }
+}
+
 fn server(listener: TcpListener,
           port: i32,
           state: Arc<RwLock<WorkerState>>,
@@ -39,17 +58,36 @@ fn server(listener: TcpListener,
         let state = state.clone();
         let channel = master_sender.clone();
         thread::spawn(move || {
-            if let Ok(mut stream) = wrapped_stream {
+            if let Ok(stream) = wrapped_stream {
                 let ip_addr = stream.local_addr().unwrap().ip().to_string();
                 let message_id = util::random_alphanum_string(10);
                 trace!("{} || Message on executor port received @ ip_addr [{}] received!", &message_id, ip_addr);
-                trace!("{} || Time: {}", &message_id, current_millis());
-                match process_input(&stream) {
-                    Ok(message) => {
-                        handle_message(&message_id, message, &mut stream, state,  channel);
-                    }
-                    Err(e) => error!("{} || Could not decode message from TCP stream Error: {}", &message_id, e.to_string())
-                }
+                handle_connection(stream, message_id, state, channel);
+            }else {
+                warn!("Error in accepting an incoming stream!");
+            }
+        });
+    }
+}
+
+fn unix_server(listener: UnixListener,
+               path: String,
+               closed: Arc<AtomicBool>,
+               state: Arc<RwLock<WorkerState>>,
+               master_sender: Sender<::executor::ServerMessage>) {
+    info!("Started executor socket server thread, listening on {}", path);
+    for wrapped_stream in listener.incoming() {
+        if closed.load(Ordering::SeqCst) {
+            info!("Closed executor socket {}", path);
+            return;
+        }
+        let state = state.clone();
+        let channel = master_sender.clone();
+        thread::spawn(move || {
+            if let Ok(stream) = wrapped_stream {
+                let message_id = util::random_alphanum_string(10);
+                trace!("{} || Message on executor socket received!", &message_id);
+                handle_connection(stream, message_id, state, channel);
             }else {
                 warn!("Error in accepting an incoming stream!");
             }
@@ -57,7 +95,7 @@ fn server(listener: TcpListener,
     }
 }
 
-fn process_input(mut stream: &TcpStream) -> ProtobufResult<SingleWorkerRequest> {
+fn process_input(stream: &mut Read) -> ProtobufResult<SingleWorkerRequest> {
     if let Ok(size) = stream.read_u32::<BigEndian>() {
         let mut buffer = vec![0u8; size as usize];
         if let Ok(_) = stream.read_exact(&mut buffer) {
@@ -79,7 +117,7 @@ fn process_input(mut stream: &TcpStream) -> ProtobufResult<SingleWorkerRequest>
 
 fn handle_message(message_id: &String,
                   worker_request: SingleWorkerRequest,
-                  stream: &mut TcpStream,
+                  stream: &mut Write,
                   state: Arc<RwLock<WorkerState>>,
                   master_sender: Sender<::executor::ServerMessage>) {
 
@@ -87,6 +125,7 @@ fn handle_message(message_id: &String,
         match request {
             SingleWorkerRequest_oneof_request::input_request(mut x) => x.handle_message(&message_id, stream, state, &master_sender),
             SingleWorkerRequest_oneof_request::output_request(mut x) => x.handle_message(&message_id, stream, state, &master_sender),
+            SingleWorkerRequest_oneof_request::progress_request(mut x) => x.handle_message(&message_id, stream, state, &master_sender),
         }
     }else {
         warn!("{} || Message from executor did not send an action.", &message_id);
@@ -97,10 +136,67 @@ fn handle_message(message_id: &String,
 pub fn start(config: &Config,
              state: Arc<RwLock<WorkerState>>,
              master_sender: Sender<::executor::ServerMessage>) -> std::io::Result<JoinHandle<()>> {
+    if config.executor_socket.len() > 0 {
+        let path = config.executor_socket.clone();
+        // Left behind if a previous worker was killed
+        let _ = fs::remove_file(&path);
+        return match UnixListener::bind(&path) {
+            Ok(listener) => {
+                // Only this user's processes may connect, tasks still have to present their token
+                fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+                let closed = Arc::new(AtomicBool::new(false));
+                thread::Builder::new().name("server".to_string()).spawn(move || unix_server(listener, path, closed, state, master_sender))
+            }
+            Err(e) => Err(Error::new(ErrorKind::Other, e.to_string()))
+        };
+    }
+
     let export_ip = &config.executor.hostname;
     let port = config.executor.port.clone();
     return match TcpListener::bind(format!("{}:{}", export_ip, &port)) {
         Ok(listener) => (thread::Builder::new().name("server".to_string()).spawn(move || server(listener, port, state, master_sender))),
         Err(e) => Err(Error::new(ErrorKind::Other, e.to_string()))
     };
-}
\ No newline at end of file
+}
+
+// A socket in a directory only the worker's user can enter, so other local processes cannot reach the task
+pub struct TaskSocket {
+    pub path: String,
+    dir: PathBuf,
+    closed: Arc<AtomicBool>,
+}
+
+pub fn start_task_socket(config: &Config,
+                         state: Arc<RwLock<WorkerState>>,
+                         master_sender: Sender<::executor::ServerMessage>) -> std::io::Result<TaskSocket> {
+    let dir = env::temp_dir().join(format!("parliament-{}-{}", config.worker.port, util::random_alphanum_string(10)));
+    DirBuilder::new().mode(0o700).create(&dir)?;
+    let path = match dir.join("executor.sock").to_str() {
+        Some(path) => path.to_string(),
+        None => {
+            let _ = fs::remove_dir(&dir);
+            return Err(Error::new(ErrorKind::InvalidInput, "Temporary directory is not valid UTF-8"));
+        }
+    };
+
+    let socket = TaskSocket {
+        path: path.clone(),
+        dir,
+        closed: Arc::new(AtomicBool::new(false)),
+    };
+    let listener = UnixListener::bind(&path)?;
+    let closed = socket.closed.clone();
+    thread::Builder::new().name("task-server".to_string()).spawn(move || unix_server(listener, path, closed, state, master_sender))?;
+    return Ok(socket);
+}
+
+impl Drop for TaskSocket {
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::SeqCst);
+        // Wakes the server thread up so it sees the socket is closed
+        let _ = UnixStream::connect(&self.path);
+        if let Err(e) = fs::remove_dir_all(&self.dir) {
+            warn!("Could not remove executor socket directory {}! Error: {}", self.dir.display(), e.to_string());
+        }
+    }
+}
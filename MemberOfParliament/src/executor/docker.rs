@@ -5,20 +5,29 @@
 
 use std::sync::{Arc, RwLock, Mutex};
 use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
 
 use crossbeam_channel::Sender;
-use protobuf::RepeatedField;
-use log::info;
-use shiplift::{ContainerOptions, Docker, Container};
-use shiplift::rep::ContainerDetails;
-use tokio::prelude::Future;
+use log::{error, info, warn};
+use shiplift::{ContainerOptions, Docker, Container, PullOptions, RmContainerOptions};
+use tokio::prelude::{Future, Stream};
 use tokio::runtime::current_thread::Runtime;
 
-use config::{Config, WorkerState, WorkerStatus};
-use executor::{Executor, TaskResult, ServerMessage, ServerMessageType};
+use config::{Config, PullPolicy, WorkerState, WorkerStatus};
+use executor::{halt_task, Executor, TaskResult, ServerMessage};
+use executor::limits;
+use util;
 use core::mem;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+// Where the executor socket is mounted inside containers
+const CONTAINER_SOCKET: &str = "/var/run/parliament.sock";
+
+const DOCKER_API_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub struct DockerExecutor {
     pub id : String,
     pub docker : Docker,
@@ -32,6 +41,126 @@ fn current_millis() -> u64 {
         since_the_epoch.subsec_nanos() as u64 / 1_000_000;
 }
 
+pub fn connect(config: &Config) -> Docker {
+    return match util::docker_socket_path(&config.docker_host) {
+        Some(path) => Docker::unix(path.to_string()),
+        None => match config.docker_host.parse() {
+            Ok(uri) => Docker::host(uri),
+            Err(e) => {
+                error!("{} is not a valid Docker host, using the default! Error: {}", &config.docker_host, e.to_string());
+                Docker::new()
+            }
+        }
+    };
+}
+
+// Every task gets its own container, so a worker never collides with its previous ones
+fn container_name(worker_id: &String) -> String {
+    return format!("parliament-{}-{}", worker_id, util::random_alphanum_string(8));
+}
+
+// Where the child process reaches the executor server from inside the container
+fn executor_host(config: &Config) -> String {
+    if config.docker_network == "host" {
+        if config.executor.hostname == "0.0.0.0" {
+            return "127.0.0.1".to_string();
+        }
+        return config.executor.hostname.clone();
+    }
+    return config.docker_gateway.clone();
+}
+
+// Where a Docker host given as a URL listens, shiplift only speaks TLS to it when DOCKER_CERT_PATH is set
+fn docker_tcp_address(docker_host: &str) -> Option<String> {
+    let address = ["tcp://", "http://"].iter()
+        .filter(|x| docker_host.starts_with(**x))
+        .map(|x| &docker_host[x.len()..])
+        .next()?;
+    let address = address.split('/').next().unwrap_or(address);
+    return Some(if address.contains(':') { address.to_string() } else { format!("{}:80", address) });
+}
+
+fn send_docker_request<S: Read + Write>(mut stream: S, request: &String) -> io::Result<String> {
+    let mut response = String::new();
+    stream.write_all(request.as_bytes())?;
+    stream.read_to_string(&mut response)?;
+    return Ok(response);
+}
+
+// shiplift's ContainerOptions only take CPU shares, a relative weight, so the quota is set through
+// the Engine API's update endpoint before the container is started
+fn limit_cpu(config: &Config, id: &str, cpu_percent: u64) -> io::Result<()> {
+    let body = format!(
+        "{{\"CpuPeriod\":{},\"CpuQuota\":{}}}",
+        limits::CPU_PERIOD,
+        cpu_percent * limits::CPU_PERIOD / 100
+    );
+    let request = format!(
+        "POST /containers/{}/update HTTP/1.1\r\nHost: docker\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        id,
+        body.len(),
+        body
+    );
+
+    let response = match (util::docker_socket_path(&config.docker_host), docker_tcp_address(&config.docker_host)) {
+        (Some(path), _) => {
+            let stream = UnixStream::connect(path)?;
+            stream.set_read_timeout(Some(DOCKER_API_TIMEOUT))?;
+            send_docker_request(stream, &request)?
+        }
+        (None, Some(address)) => {
+            let stream = TcpStream::connect(address)?;
+            stream.set_read_timeout(Some(DOCKER_API_TIMEOUT))?;
+            send_docker_request(stream, &request)?
+        }
+        (None, None) => return Err(io::Error::new(io::ErrorKind::InvalidInput, "Docker host is not a socket or plain TCP URL")),
+    };
+
+    let status_line = response.lines().next().unwrap_or("");
+    if status_line.split_whitespace().nth(1) != Some("200") {
+        return Err(io::Error::new(io::ErrorKind::Other, status_line.to_string()));
+    }
+    return Ok(());
+}
+
+fn image_present(docker: &Docker, rt: &mut Runtime, image: &String) -> bool {
+    return rt.block_on(docker.images().get(image).inspect()).is_ok();
+}
+
+fn pull_image(docker: &Docker, rt: &mut Runtime, image: &String) -> bool {
+    info!("Pulling image {}", &image);
+    let options = PullOptions::builder().image(image.as_str()).build();
+    return match rt.block_on(docker.images().pull(&options).collect()) {
+        // Pull failures are reported as events of a successful response
+        Ok(events) => match events.iter().filter_map(|x| x.get("error")).next() {
+            Some(e) => {
+                error!("Could not pull image {}! Error: {}", &image, e);
+                false
+            }
+            None => true
+        },
+        Err(e) => {
+            error!("Could not pull image {}! Error: {}", &image, e.to_string());
+            false
+        }
+    };
+}
+
+pub fn ensure_image(docker: &Docker, rt: &mut Runtime, image: &String, policy: &PullPolicy) -> bool {
+    return match *policy {
+        PullPolicy::Always => pull_image(docker, rt, image),
+        PullPolicy::IfNotPresent => image_present(docker, rt, image) || pull_image(docker, rt, image),
+        PullPolicy::Never => {
+            if image_present(docker, rt, image) {
+                true
+            } else {
+                error!("Image {} is not present and the pull policy is never!", &image);
+                false
+            }
+        }
+    };
+}
+
 impl Executor for DockerExecutor {
 
     fn start_job(config: &Config,
@@ -41,27 +170,59 @@ impl Executor for DockerExecutor {
                  docker_name: &String) -> Option<DockerExecutor> {
         info!("Running using DockerExecutor");
         let worker_id = state.read().unwrap().worker_id.clone();
-        let docker = Docker::new();
+        let docker = connect(config);
 
-        // Check image exists
+        if !ensure_image(&docker, rt, docker_name, &config.pull_policy) {
+            halt_task(state, master_sender);
+            return None;
+        }
 
         let mut labels = HashMap::new();
         labels.insert("parliament", "worker");
         labels.insert("worker-id", &worker_id);
 
+        let name = container_name(&worker_id);
+        let host_str = format!("PARLIAMENT_HOST={}", executor_host(config));
         let port_str = format!("PARLIAMENT_PORT={}", config.executor.port);
+        let token_str = format!("PARLIAMENT_TOKEN={}", state.read().unwrap().executor_token);
+        let socket_str = format!("PARLIAMENT_SOCKET={}", CONTAINER_SOCKET);
+        // A socket can only be mounted into containers of a daemon on this host, others use the executor server
+        let executor_socket = if util::docker_socket_path(&config.docker_host).is_some() {
+            state.read().unwrap().executor_socket.clone()
+        } else {
+            "".to_string()
+        };
+        let mount_str = format!("{}:{}", executor_socket, CONTAINER_SOCKET);
 
         let mut envs = Vec::new();
         envs.push("PARLIAMENT_MODE=Worker");
-        envs.push("PARLIAMENT_HOST=host.docker.internal");
+        envs.push(&host_str);
         envs.push(&port_str);
+        envs.push(&token_str);
+        if executor_socket.len() > 0 {
+            envs.push(&socket_str);
+        }
+        if config.warm_tasks > 0 {
+            envs.push("PARLIAMENT_WARM=1");
+        }
 
-        let options = ContainerOptions::builder(&docker_name)
+        let task_limits = limits::effective_limits(&config.limits, &state.read().unwrap().task_limits);
+
+        let mut options_builder = ContainerOptions::builder(&docker_name);
+        options_builder
             .env(envs)
-            .name(&worker_id)
+            .name(&name)
             .labels(&labels)
-            .auto_remove(false)
-            .build();
+            .network_mode(&config.docker_network)
+            // Removed in Drop instead, so crashed containers can still be inspected
+            .auto_remove(false);
+        if executor_socket.len() > 0 {
+            options_builder.volumes(vec![&mount_str]);
+        }
+        if task_limits.memory_mb > 0 {
+            options_builder.memory(task_limits.memory_mb * 1024 * 1024);
+        }
+        let options = options_builder.build();
 
         let id = Arc::new(Mutex::new("".to_string()));
 
@@ -73,7 +234,7 @@ impl Executor for DockerExecutor {
             .create(&options)
             .map(|info| {
                 let id = info.id;
-                info!("Created container ID: {}", &id);
+                info!("Created container {} ID: {}", &name, &id);
                 let mut id_state = id_clone.lock().expect("Could not lock mutex");
                 *id_state = id.clone();
                 let container = Container::new(&docker, id);
@@ -84,9 +245,16 @@ impl Executor for DockerExecutor {
 
                 return container;
             })
-            .and_then(|container: Container| container.start());
+            .and_then(|container: Container| {
+                if task_limits.cpu_percent > 0 {
+                    if let Err(e) = limit_cpu(config, container.id(), task_limits.cpu_percent) {
+                        warn!("Could not set the CPU quota of container {}, running it without one! Error: {}", container.id(), e.to_string());
+                    }
+                }
+                container.start()
+            });
 
-        rt.block_on(fut);
+        let result = rt.block_on(fut);
 
         let after = current_millis();
         let diff = after - before;
@@ -95,36 +263,148 @@ impl Executor for DockerExecutor {
 
         let mut id_state = id.lock().expect("Could not lock mutex");
         let id = mem::replace(&mut *id_state, String::from(""));
-//        state.write().unwrap().status = WorkerStatus::Processing;
         if id.len() > 0 {
-            return Some(DockerExecutor {
-                id: id.clone(),
-                docker: Docker::new()
-            });
+            let executor = DockerExecutor {
+                id,
+                docker,
+            };
+            match result {
+                Ok(_) => return Some(executor),
+                // Dropping the executor removes the container that could not be started
+                Err(e) => error!("Could not start container {}! Error: {}", &executor.id, e.to_string())
+            }
+        } else if let Err(e) = result {
+            error!("Could not create container from {}! Error: {}", &docker_name, e.to_string());
         }
 
-        state.write().unwrap().status = WorkerStatus::Halted;
-        master_sender.send(ServerMessage {
-            message_type: ServerMessageType::FinishedRequest(TaskResult::JobErrored, Arc::new(RepeatedField::default())),
-            retry_count: 0
-        }).expect("Could not send finished request. Internal message broker is broken!");
+        halt_task(state, master_sender);
         return None;
     }
 
-    fn cancel_job(&mut self) {
+    fn cancel_job(&mut self, rt: &mut Runtime) {
         let container = Container::new(&self.docker, &self.id);
-        container.kill(None);
+        if let Err(e) = rt.block_on(container.kill(None)) {
+            error!("Could not kill container {}! Error: {}", &self.id, e.to_string());
+        }
     }
 
-    fn detect_crash(&mut self, rt: &mut Runtime) -> bool {
+    fn detect_crash(&mut self, rt: &mut Runtime) -> Option<TaskResult> {
         let container = Container::new(&self.docker, &self.id);
         match rt.block_on(container.inspect()) {
             Ok(details) => {
                 let state = details.state;
-                return !state.running && state.exit_code != 0;
+                if !state.running && state.exit_code != 0 {
+                    if state.oom_killed {
+                        return Some(TaskResult::JobOutOfMemory);
+                    }
+                    return Some(TaskResult::JobErrored);
+                }
             },
             Err(_) => ()
         }
-        return false;
+        return None;
+    }
+
+    fn has_exited(&mut self, rt: &mut Runtime) -> bool {
+        let container = Container::new(&self.docker, &self.id);
+        return match rt.block_on(container.inspect()) {
+            Ok(details) => !details.state.running,
+            Err(_) => true
+        };
+    }
+}
+
+// Containers are never reused once their executor is gone, whether the task finished, crashed or was cancelled
+impl Drop for DockerExecutor {
+    fn drop(&mut self) {
+        let container = Container::new(&self.docker, &self.id);
+        let options = RmContainerOptions::builder().force(true).build();
+        let result = Runtime::new()
+            .map_err(|e| e.to_string())
+            .and_then(|mut rt| rt.block_on(container.remove(options)).map_err(|e| e.to_string()));
+        match result {
+            Ok(_) => info!("Removed container {}", &self.id),
+            Err(e) => error!("Could not remove container {}! Error: {}", &self.id, e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Answers a single request with the given status line and body, like the Docker API does
+    fn fake_docker_api(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0; 4096];
+            let size = stream.read(&mut buffer).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            ).unwrap();
+            return String::from_utf8_lossy(&buffer[..size]).to_string();
+        });
+        return (address, handle);
+    }
+
+    #[test]
+    fn test_sanity_missing_image_never_pulled() {
+        let (address, handle) = fake_docker_api("404 Not Found", "{\"message\":\"No such image: parliament:latest\"}");
+        let mut config = Config::default();
+        config.docker_host = address;
+
+        let mut rt = Runtime::new().unwrap();
+        let image = "parliament:latest".to_string();
+        assert_eq!(ensure_image(&connect(&config), &mut rt, &image, &PullPolicy::Never), false);
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /images/parliament:latest/json"));
+    }
+
+    #[test]
+    fn test_sanity_limit_cpu() {
+        let (address, handle) = fake_docker_api("200 OK", "{\"Warnings\":[]}");
+        let mut config = Config::default();
+        config.docker_host = address;
+
+        limit_cpu(&config, "parliament-worker", 150).unwrap();
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /containers/parliament-worker/update HTTP/1.1"));
+        assert!(request.ends_with("\r\n\r\n{\"CpuPeriod\":100000,\"CpuQuota\":150000}"));
+
+        let (address, handle) = fake_docker_api("404 Not Found", "{\"message\":\"No such container\"}");
+        config.docker_host = address;
+        assert!(limit_cpu(&config, "parliament-worker", 50).is_err());
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_sanity_docker_tcp_address() {
+        assert_eq!(docker_tcp_address("tcp://10.0.0.2:2375"), Some("10.0.0.2:2375".to_string()));
+        assert_eq!(docker_tcp_address("http://docker/"), Some("docker:80".to_string()));
+        assert_eq!(docker_tcp_address("https://docker:2376"), None);
+        assert_eq!(docker_tcp_address("unix:///var/run/docker.sock"), None);
+    }
+
+    #[test]
+    fn test_sanity_container_options() {
+        let worker_id = "worker".to_string();
+        assert!(container_name(&worker_id).starts_with("parliament-worker-"));
+        assert_ne!(container_name(&worker_id), container_name(&worker_id));
+
+        let mut config = Config::default();
+        config.docker_network = "host".to_string();
+        assert_eq!(executor_host(&config), "127.0.0.1");
+        config.docker_network = "bridge".to_string();
+        assert_eq!(executor_host(&config), "host.docker.internal");
     }
 }
@@ -3,6 +3,7 @@
     Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
 */
 
+use std::mem;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
@@ -12,13 +13,68 @@ use rand::Rng;
 
 use config::WorkerStatus;
 use config::{Config, WorkerState};
+use executor::broadcasts;
+use executor::closures::{self, ClosureCache};
+use executor::communication::server::{self, TaskSocket};
 use executor::docker::DockerExecutor;
+use executor::sandbox::SandboxExecutor;
 use executor::shell::ShellExecutor;
 use executor::{Executor, ServerMessage, ServerMessageType, TaskCommand, TaskResult};
 use protobuf::RepeatedField;
 use tokio::runtime::current_thread::Runtime;
 use util;
 
+// Tasks may only reuse a warm executor that was started with the same image, executable and limits
+fn warm_key(docker_name: &String, state: &Arc<RwLock<WorkerState>>) -> String {
+    let readable_state = state.read().unwrap();
+    return format!(
+        "{}|{:?}|{:?}",
+        docker_name, readable_state.task_executable, readable_state.task_limits
+    );
+}
+
+// Tasks without a timeout may run for as long as they need
+fn task_deadline(state: &Arc<RwLock<WorkerState>>, now: u64) -> Option<u64> {
+    let task_timeout = state.read().unwrap().task_timeout;
+    return if task_timeout > 0 { Some(now + task_timeout) } else { None };
+}
+
+// A task that already finished or halted is not killed, even if the deadline passed in the meantime
+fn deadline_passed(deadline: Option<u64>, state: &Arc<RwLock<WorkerState>>, now: u64) -> bool {
+    return match deadline {
+        Some(deadline) => now > deadline && state.read().unwrap().status == WorkerStatus::Processing,
+        None => false,
+    };
+}
+
+// Every task gets its own secret, a warm executor is handed it along with its input
+fn rotate_token(state: &Arc<RwLock<WorkerState>>, warm: bool) {
+    let mut writable_state = state.write().unwrap();
+    let previous = mem::replace(&mut writable_state.executor_token, util::random_alphanum_string(32));
+    writable_state.warm_executor_token = if warm { previous } else { "".to_string() };
+}
+
+// Processes and containers reach the worker through a socket of their own unless one is configured
+fn open_socket(config: &Config,
+               state: &Arc<RwLock<WorkerState>>,
+               master_sender: &Sender<ServerMessage>) -> Option<TaskSocket> {
+    if config.executor_socket.len() > 0 {
+        state.write().unwrap().executor_socket = config.executor_socket.clone();
+        return None;
+    }
+    return match server::start_task_socket(config, state.clone(), master_sender.clone()) {
+        Ok(socket) => {
+            state.write().unwrap().executor_socket = socket.path.clone();
+            Some(socket)
+        }
+        Err(e) => {
+            error!("Could not create an executor socket, falling back to the executor server! Error: {}", e.to_string());
+            state.write().unwrap().executor_socket = "".to_string();
+            None
+        }
+    };
+}
+
 pub fn start_executor(
     config: &Config,
     state: Arc<RwLock<WorkerState>>,
@@ -28,6 +84,14 @@ pub fn start_executor(
     const SLEEP_TIME: Duration = Duration::from_millis(300);
 
     let mut executor: Option<Box<Executor>> = None;
+    // Lives exactly as long as the executor
+    let mut task_socket: Option<TaskSocket> = None;
+    let mut task_deadline: Option<u64> = None;
+    // A warm executor is kept alive between tasks, waiting on the executor port for its next one
+    let mut idle = false;
+    let mut current_key = String::new();
+    let mut task_count: u32 = 0;
+    let mut closure_cache = ClosureCache::new(config.closure_cache_size);
     let mut rng = rand::thread_rng();
 
     // Place connection req in master
@@ -48,10 +112,44 @@ pub fn start_executor(
         match executor_receiver.recv_timeout(SLEEP_TIME) {
             Ok(TaskCommand::StartTask(docker_name)) => {
                 info!("Received TaskCommand::StartTask");
-                if executor.is_some() {
+                if executor.is_some() && !idle {
                     error!("A task is already running!");
+                } else if !closures::prepare(config, &state, &mut closure_cache)
+                    || !broadcasts::prepare(config, &state)
+                {
+                    // Usually a network hiccup, so the master gives the task to another worker
+                    error!("Could not find the closure or broadcasts for the task!");
+                    state.write().unwrap().status = WorkerStatus::Halted;
+                    master_sender
+                        .send(ServerMessage {
+                            message_type: ServerMessageType::FinishedRequest(
+                                TaskResult::JobUnavailable,
+                                Arc::new(RepeatedField::default()),
+                            ),
+                            retry_count: 0,
+                        })
+                        .expect("Could not send finished request. Internal message broker is broken!");
                 } else {
-                    if docker_name.len() > 0 {
+                    task_deadline = self::task_deadline(&state, util::current_secs());
+
+                    let key = warm_key(&docker_name, &state);
+                    if idle && (key != current_key || executor.as_mut().unwrap().has_exited(&mut rt)) {
+                        info!("Warm executor cannot run this task, replacing it");
+                        executor.take().unwrap().cancel_job(&mut rt);
+                    }
+                    idle = false;
+                    current_key = key;
+                    task_count = if executor.is_some() { task_count + 1 } else { 1 };
+                    rotate_token(&state, executor.is_some());
+                    if executor.is_none() {
+                        task_socket = open_socket(config, &state, &master_sender);
+                    }
+
+                    if executor.is_some() {
+                        // The warm executor is already waiting for its input
+                        info!("Reusing warm executor for task no. {}", task_count);
+                        state.write().unwrap().status = WorkerStatus::Processing;
+                    } else if docker_name.len() > 0 {
                         match DockerExecutor::start_job(
                             &config,
                             &mut rt,
@@ -66,6 +164,21 @@ pub fn start_executor(
                                 executor = None;
                             }
                         }
+                    } else if config.sandbox {
+                        match SandboxExecutor::start_job(
+                            &config,
+                            &mut rt,
+                            &state,
+                            &master_sender,
+                            &docker_name,
+                        ) {
+                            Some(exec) => {
+                                executor = Some(Box::new(exec));
+                            }
+                            None => {
+                                executor = None;
+                            }
+                        }
                     } else {
                         match ShellExecutor::start_job(
                             &config,
@@ -86,17 +199,26 @@ pub fn start_executor(
             }
             Ok(TaskCommand::SetNone) => {
                 info!("Received TaskCommand::SetNone");
-                executor = None;
+                if executor.is_some() && task_count < config.warm_tasks {
+                    idle = true;
+                } else if let Some(mut exe) = executor.take() {
+                    if config.warm_tasks > 0 {
+                        info!("Warm executor has run {} tasks, recycling it", task_count);
+                        exe.cancel_job(&mut rt);
+                    }
+                    idle = false;
+                }
             }
             Ok(TaskCommand::CancelTask) => {
                 info!("Received TaskCommand::CancelTask");
                 if executor.is_some() {
                     {
                         let mut exec = executor.unwrap();
-                        exec.cancel_job();
+                        exec.cancel_job(&mut rt);
                     }
                     state.write().unwrap().status = WorkerStatus::Halted;
                     executor = None;
+                    idle = false;
                 } else {
                     error!("Attempting to cancel a task that isn't running!");
                 }
@@ -105,15 +227,36 @@ pub fn start_executor(
             Err(_) => {}
         }
 
-        if executor.is_some() {
+        if executor.is_some() && idle {
+            if executor.as_mut().unwrap().has_exited(&mut rt) {
+                info!("Warm executor exited while waiting for a task");
+                executor = None;
+                idle = false;
+            }
+        } else if executor.is_some() {
             let mut exe = executor.unwrap();
-            if exe.detect_crash(&mut rt) {
-                error!("Crash detected!");
+            if deadline_passed(task_deadline, &state, util::current_secs()) {
+                error!("Task has exceeded its timeout, killing it!");
+                exe.cancel_job(&mut rt);
+                state.write().unwrap().status = WorkerStatus::Halted;
+                master_sender
+                    .send(ServerMessage {
+                        message_type: ServerMessageType::FinishedRequest(
+                            TaskResult::JobTimedOut,
+                            Arc::new(RepeatedField::default()),
+                        ),
+                        retry_count: 0,
+                    })
+                    .expect("Could not send finished request. Internal message broker is broken!");
+                task_deadline = None;
+                executor = None;
+            } else if let Some(result) = exe.detect_crash(&mut rt) {
+                error!("Crash detected! Result: {:?}", result);
                 state.write().unwrap().status = WorkerStatus::Halted;
                 master_sender
                     .send(ServerMessage {
                         message_type: ServerMessageType::FinishedRequest(
-                            TaskResult::JobErrored,
+                            result,
                             Arc::new(RepeatedField::default()),
                         ),
                         retry_count: 0,
@@ -125,6 +268,12 @@ pub fn start_executor(
             }
         }
 
+        if executor.is_none() {
+            if let Some(socket) = task_socket.take() {
+                info!("Closing executor socket {}", &socket.path);
+            }
+        }
+
         if (loop_count % 2) == 0 {
             let unwrapped_state = state.read().unwrap();
 
@@ -142,3 +291,136 @@ pub fn start_executor(
         loop_count += 1;
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use config::{Executable, ResourceLimits};
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    // Shared with the tests of other executor modules that read the worker state
+    pub fn state(executable: Executable, limits: ResourceLimits) -> Arc<RwLock<WorkerState>> {
+        return Arc::new(RwLock::new(WorkerState {
+            status: WorkerStatus::Awaiting,
+            worker_id: "worker".to_string(),
+            last_request: 0,
+            data_in: None,
+            closure: None,
+            closure_digest: "".to_string(),
+            broadcast_digests: Vec::new(),
+            broadcasts: Vec::new(),
+            task_type: None,
+            task_id: "".to_string(),
+            task_timeout: 0,
+            task_limits: limits,
+            task_executable: executable,
+            executor_token: "".to_string(),
+            warm_executor_token: "".to_string(),
+            executor_socket: "".to_string(),
+            task_progress: 0.0,
+            task_progress_message: "".to_string(),
+            task_accumulators: Vec::new(),
+        }));
+    }
+
+    fn executable(name: &str, args: Vec<&str>) -> Executable {
+        return Executable {
+            name: name.to_string(),
+            args: args.into_iter().map(|x| x.to_string()).collect(),
+            env: Vec::new(),
+            artifact_digest: "".to_string(),
+        };
+    }
+
+    #[test]
+    fn test_sanity_task_deadline() {
+        let state = state(executable("wordcount", vec![]), ResourceLimits::default());
+        assert_eq!(task_deadline(&state, 1000), None);
+        assert_eq!(deadline_passed(None, &state, 1000), false);
+
+        state.write().unwrap().task_timeout = 60;
+        let deadline = task_deadline(&state, 1000);
+        assert_eq!(deadline, Some(1060));
+
+        state.write().unwrap().status = WorkerStatus::Processing;
+        assert_eq!(deadline_passed(deadline, &state, 1060), false);
+        assert_eq!(deadline_passed(deadline, &state, 1061), true);
+
+        // Already reported back to the master, so there is nothing left to kill
+        state.write().unwrap().status = WorkerStatus::Halted;
+        assert_eq!(deadline_passed(deadline, &state, 1061), false);
+    }
+
+    #[test]
+    fn test_sanity_rotate_token() {
+        let state = state(executable("wordcount", vec![]), ResourceLimits::default());
+        rotate_token(&state, false);
+        let first = state.read().unwrap().executor_token.clone();
+        assert_eq!(first.len(), 32);
+        assert_eq!(state.read().unwrap().warm_executor_token, "");
+
+        // A warm executor keeps its last token until it has been handed the new one
+        rotate_token(&state, true);
+        let second = state.read().unwrap().executor_token.clone();
+        assert_ne!(second, first);
+        assert_eq!(state.read().unwrap().warm_executor_token, first);
+
+        rotate_token(&state, false);
+        assert_ne!(state.read().unwrap().executor_token, second);
+        assert_eq!(state.read().unwrap().warm_executor_token, "");
+    }
+
+    #[test]
+    fn test_sanity_open_socket() {
+        let state = state(executable("wordcount", vec![]), ResourceLimits::default());
+        let (master_sender, _master_receiver) = ::crossbeam_channel::unbounded();
+        let mut config = Config::default();
+        config.worker.port = 40101;
+
+        let socket = open_socket(&config, &state, &master_sender).unwrap();
+        let path = PathBuf::from(&socket.path);
+        assert_eq!(state.read().unwrap().executor_socket, socket.path);
+        assert!(path.exists());
+        let mode = fs::metadata(path.parent().unwrap()).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700);
+
+        // Closing the socket removes its directory
+        drop(socket);
+        assert!(!path.parent().unwrap().exists());
+
+        config.executor_socket = "/run/parliament.sock".to_string();
+        assert!(open_socket(&config, &state, &master_sender).is_none());
+        assert_eq!(state.read().unwrap().executor_socket, "/run/parliament.sock");
+    }
+
+    #[test]
+    fn test_sanity_warm_key_matches() {
+        let first = state(executable("wordcount", vec!["-v"]), ResourceLimits::default());
+        let second = state(executable("wordcount", vec!["-v"]), ResourceLimits::default());
+        assert_eq!(warm_key(&"ocaml:4.07".to_string(), &first), warm_key(&"ocaml:4.07".to_string(), &second));
+        assert_eq!(warm_key(&"".to_string(), &first), warm_key(&"".to_string(), &second));
+    }
+
+    #[test]
+    fn test_sanity_warm_key_differs() {
+        let base = state(executable("wordcount", vec!["-v"]), ResourceLimits::default());
+        let key = warm_key(&"".to_string(), &base);
+
+        assert_ne!(warm_key(&"ocaml:4.07".to_string(), &base), key);
+        assert_ne!(warm_key(&"".to_string(), &state(executable("wordcount", vec![]), ResourceLimits::default())), key);
+        assert_ne!(warm_key(&"".to_string(), &state(executable("grep", vec!["-v"]), ResourceLimits::default())), key);
+
+        let mut with_env = executable("wordcount", vec!["-v"]);
+        with_env.env.push(("LANG".to_string(), "C".to_string()));
+        assert_ne!(warm_key(&"".to_string(), &state(with_env, ResourceLimits::default())), key);
+
+        let mut with_artifact = executable("wordcount", vec!["-v"]);
+        with_artifact.artifact_digest = "ba7816bf".to_string();
+        assert_ne!(warm_key(&"".to_string(), &state(with_artifact, ResourceLimits::default())), key);
+
+        let limits = ResourceLimits { memory_mb: 512, cpu_percent: 0, open_files: 0 };
+        assert_ne!(warm_key(&"".to_string(), &state(executable("wordcount", vec!["-v"]), limits)), key);
+    }
+}
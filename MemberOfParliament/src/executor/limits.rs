@@ -0,0 +1,208 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    limits.rs - Resource limits applied to tasks run by the ShellExecutor
+*/
+
+use std::ffi::{CStr, CString};
+use std::fs;
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use libc;
+use log::{info, warn};
+
+use config::ResourceLimits;
+
+// In microseconds, shared with the quota of Docker tasks
+pub const CPU_PERIOD: u64 = 100000;
+
+fn combine(worker: u64, job: u64) -> u64 {
+    if worker == 0 {
+        return job;
+    } else if job == 0 {
+        return worker;
+    } else {
+        return job.min(worker);
+    }
+}
+
+// Jobs may tighten the limits set on the worker, but never loosen them
+pub fn effective_limits(worker: &ResourceLimits, job: &ResourceLimits) -> ResourceLimits {
+    return ResourceLimits {
+        memory_mb: combine(worker.memory_mb, job.memory_mb),
+        cpu_percent: combine(worker.cpu_percent, job.cpu_percent),
+        open_files: combine(worker.open_files, job.open_files),
+    };
+}
+
+fn set_rlimit(resource: libc::c_int, value: u64) -> io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: value as libc::rlim_t,
+        rlim_max: value as libc::rlim_t,
+    };
+    if unsafe { libc::setrlimit(resource as _, &limit) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    return Ok(());
+}
+
+// Runs between fork and exec, so the path is allocated up front
+fn join_cgroup(procs: &CStr) -> io::Result<()> {
+    let fd = unsafe { libc::open(procs.as_ptr(), libc::O_WRONLY) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // Writing 0 moves the calling process, so the task is limited from its first instruction
+    let written = unsafe { libc::write(fd, b"0".as_ptr() as *const libc::c_void, 1) };
+    let result = if written < 0 { Err(io::Error::last_os_error()) } else { Ok(()) };
+    unsafe { libc::close(fd) };
+    return result;
+}
+
+pub fn apply_limits(command: &mut Command, limits: &ResourceLimits, cgroup: Option<&Cgroup>) -> io::Result<()> {
+    let memory_bytes = limits.memory_mb * 1024 * 1024;
+    let open_files = limits.open_files;
+    let procs = match cgroup {
+        Some(cgroup) => Some(
+            CString::new(cgroup.procs_path().as_os_str().as_bytes())
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Path contains a nul byte"))?,
+        ),
+        None => None,
+    };
+
+    let hook = move || -> io::Result<()> {
+        if let Some(ref procs) = procs {
+            join_cgroup(procs)?;
+        }
+        if memory_bytes > 0 {
+            set_rlimit(libc::RLIMIT_AS as libc::c_int, memory_bytes)?;
+        }
+        if open_files > 0 {
+            set_rlimit(libc::RLIMIT_NOFILE as libc::c_int, open_files)?;
+        }
+        return Ok(());
+    };
+    unsafe { command.pre_exec(hook) };
+    return Ok(());
+}
+
+pub struct Cgroup {
+    path: PathBuf,
+    initial_oom_kills: u64,
+}
+
+impl Cgroup {
+    // Returns None when cgroup v2 is unavailable or the parent is not writable
+    pub fn create(parent: &String, name: &String, limits: &ResourceLimits) -> Option<Cgroup> {
+        if limits.memory_mb == 0 && limits.cpu_percent == 0 {
+            return None;
+        }
+        let parent_path = Path::new(parent);
+        if !parent_path.join("cgroup.controllers").exists() {
+            info!("{} is not a cgroup v2 directory, only applying rlimits", parent);
+            return None;
+        }
+
+        let path = parent_path.join(name);
+        let result = fs::create_dir_all(&path)
+            .and_then(|_| {
+                let _ = fs::write(parent_path.join("cgroup.subtree_control"), "+memory +cpu");
+                if limits.memory_mb > 0 {
+                    fs::write(
+                        path.join("memory.max"),
+                        (limits.memory_mb * 1024 * 1024).to_string(),
+                    )?;
+                    let _ = fs::write(path.join("memory.swap.max"), "0");
+                }
+                if limits.cpu_percent > 0 {
+                    let quota = limits.cpu_percent * CPU_PERIOD / 100;
+                    fs::write(path.join("cpu.max"), format!("{} {}", quota, CPU_PERIOD))?;
+                }
+                return Ok(());
+            });
+
+        return match result {
+            Ok(_) => {
+                let mut cgroup = Cgroup {
+                    path,
+                    initial_oom_kills: 0,
+                };
+                // The cgroup is reused between tasks, so only count kills from now on
+                cgroup.initial_oom_kills = cgroup.oom_kills();
+                Some(cgroup)
+            }
+            Err(e) => {
+                warn!(
+                    "Could not set up cgroup {}, only applying rlimits! Error: {}",
+                    path.display(),
+                    e.to_string()
+                );
+                let _ = fs::remove_dir(&path);
+                None
+            }
+        };
+    }
+
+    pub fn procs_path(&self) -> PathBuf {
+        return self.path.join("cgroup.procs");
+    }
+
+    fn oom_kills(&self) -> u64 {
+        return match fs::read_to_string(self.path.join("memory.events")) {
+            Ok(events) => events
+                .lines()
+                .filter(|x| x.starts_with("oom_kill "))
+                .filter_map(|x| x.split_whitespace().nth(1))
+                .filter_map(|x| x.parse::<u64>().ok())
+                .next()
+                .unwrap_or(0),
+            Err(_) => 0,
+        };
+    }
+
+    pub fn oom_killed(&self) -> bool {
+        return self.oom_kills() > self.initial_oom_kills;
+    }
+}
+
+impl Drop for Cgroup {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(memory_mb: u64, cpu_percent: u64, open_files: u64) -> ResourceLimits {
+        return ResourceLimits {
+            memory_mb,
+            cpu_percent,
+            open_files,
+        };
+    }
+
+    #[test]
+    fn test_sanity_combine() {
+        assert_eq!(combine(0, 0), 0);
+        assert_eq!(combine(0, 512), 512);
+        assert_eq!(combine(512, 0), 512);
+        assert_eq!(combine(512, 256), 256);
+        assert_eq!(combine(256, 512), 256);
+    }
+
+    #[test]
+    fn test_sanity_effective_limits() {
+        let worker = limits(1024, 0, 256);
+        let job = limits(2048, 50, 0);
+        assert_eq!(effective_limits(&worker, &job), limits(1024, 50, 256));
+        assert_eq!(effective_limits(&limits(0, 0, 0), &job), job);
+        assert_eq!(effective_limits(&worker, &limits(0, 0, 0)), worker);
+    }
+}
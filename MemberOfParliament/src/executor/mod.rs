@@ -5,7 +5,7 @@
 
 use protobuf::RepeatedField;
 use std::sync::Arc;
-use config::WorkerState;
+use config::{WorkerState, WorkerStatus};
 use crossbeam_channel::Sender;
 use std::sync::RwLock;
 use config::Config;
@@ -15,6 +15,11 @@ pub mod executor;
 pub mod docker;
 pub mod shell;
 pub mod communication;
+pub mod limits;
+pub mod artifacts;
+pub mod closures;
+pub mod broadcasts;
+pub mod sandbox;
 
 #[derive(PartialEq,Clone,Debug)]
 pub enum TaskType {
@@ -30,10 +35,14 @@ pub enum TaskCommand {
     SetNone,
 }
 
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Debug)]
 pub enum TaskResult {
     JobFinished,
-    JobErrored
+    JobErrored,
+    JobTimedOut,
+    JobOutOfMemory,
+    // The task's closure or broadcasts could not be fetched, so it can be retried elsewhere
+    JobUnavailable
 }
 
 #[derive(PartialEq, Clone)]
@@ -47,6 +56,14 @@ pub struct ServerMessage {
     pub retry_count: i32
 }
 
+// Reports a task that could not be started as errored
+pub fn halt_task(state: &Arc<RwLock<WorkerState>>, master_sender: &Sender<ServerMessage>) {
+    state.write().unwrap().status = WorkerStatus::Halted;
+    master_sender.send(ServerMessage {
+        message_type: ServerMessageType::FinishedRequest(TaskResult::JobErrored, Arc::new(RepeatedField::default())),
+        retry_count: 0
+    }).expect("Could not send finished request. Internal message broker is broken!");
+}
 
 pub trait Executor {
     fn start_job(config: &Config,
@@ -55,7 +72,9 @@ pub trait Executor {
                  master_sender: &Sender<ServerMessage>,
                  docker_name: &String) -> Option<Self> where Self : Sized;
 
-    fn cancel_job(&mut self);
+    fn cancel_job(&mut self, runtime: &mut Runtime);
+
+    fn detect_crash(&mut self, runtime: &mut Runtime) -> Option<TaskResult>;
 
-    fn detect_crash(&mut self, runtime: &mut Runtime) -> bool;
+    fn has_exited(&mut self, runtime: &mut Runtime) -> bool;
 }
\ No newline at end of file
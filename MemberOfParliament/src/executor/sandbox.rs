@@ -0,0 +1,483 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    sandbox.rs - Runs tasks inside unprivileged user, mount, pid & network namespaces
+*/
+
+use std::env;
+use std::ffi::{CStr, CString};
+use std::fs;
+use std::io;
+use std::mem;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::ptr;
+use std::sync::{Arc, RwLock};
+
+use crossbeam_channel::Sender;
+use libc;
+use log::{error, info, warn};
+use tokio::runtime::current_thread::Runtime;
+
+use config::{Config, WorkerState};
+use executor::shell::ShellExecutor;
+use executor::{halt_task, Executor, ServerMessage, TaskResult};
+use util;
+
+// Where the executor socket is mounted inside the sandbox
+pub const SANDBOX_SOCKET: &str = "/run/parliament.sock";
+
+// Host directories visible, read-only, to every task
+const READ_ONLY_DIRS: [&str; 7] = ["/bin", "/sbin", "/usr", "/lib", "/lib32", "/lib64", "/etc"];
+
+const DEVICES: [&str; 4] = ["/dev/null", "/dev/zero", "/dev/random", "/dev/urandom"];
+
+pub struct SandboxExecutor {
+    pub shell: ShellExecutor,
+    // Only held so the root is removed once the task is done
+    _root: SandboxRoot,
+}
+
+// Only an empty mount point on the host, the root itself is a tmpfs private to the task
+pub struct SandboxRoot {
+    pub path: PathBuf,
+}
+
+impl SandboxRoot {
+    pub fn create(config: &Config) -> io::Result<SandboxRoot> {
+        let path = env::temp_dir().join(format!("parliament-sandbox-{}-{}", config.worker.port, util::random_alphanum_string(10)));
+        fs::create_dir(&path)?;
+        return Ok(SandboxRoot { path });
+    }
+}
+
+impl Drop for SandboxRoot {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir(&self.path) {
+            warn!("Could not remove sandbox root {}! Error: {}", self.path.display(), e.to_string());
+        }
+    }
+}
+
+struct BindMount {
+    source: CString,
+    target: CString,
+    read_only: bool,
+}
+
+// Everything the child needs is allocated up front, as it runs between fork and exec
+struct SandboxSpec {
+    root: CString,
+    dirs: Vec<CString>,
+    files: Vec<CString>,
+    mounts: Vec<BindMount>,
+    tmp: CString,
+    proc_dir: CString,
+    uid_map: Vec<u8>,
+    gid_map: Vec<u8>,
+    // Highest descriptor the worker could have open, read before the task's limits lower it
+    max_fd: libc::c_int,
+}
+
+fn c_path(path: &Path) -> io::Result<CString> {
+    return CString::new(path.as_os_str().as_bytes())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Path contains a nul byte"));
+}
+
+fn c_str(bytes: &'static [u8]) -> &'static CStr {
+    return CStr::from_bytes_with_nul(bytes).unwrap();
+}
+
+fn check(result: libc::c_int) -> io::Result<()> {
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    return Ok(());
+}
+
+// Path of a host path once it is placed under the sandbox root
+fn under_root(root: &Path, path: &Path) -> PathBuf {
+    return root.join(path.strip_prefix("/").unwrap_or(path));
+}
+
+fn push_dirs(dirs: &mut Vec<CString>, root: &Path, path: &Path) -> io::Result<()> {
+    let mut current = root.to_path_buf();
+    for component in path.strip_prefix("/").unwrap_or(path).components() {
+        current.push(component);
+        let dir = c_path(&current)?;
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    return Ok(());
+}
+
+fn prepare(root: &Path, socket: &str, executable: &Path, max_fd: libc::c_int) -> io::Result<SandboxSpec> {
+    let mut spec = SandboxSpec {
+        root: c_path(root)?,
+        dirs: Vec::new(),
+        files: Vec::new(),
+        mounts: Vec::new(),
+        tmp: c_path(&root.join("tmp"))?,
+        proc_dir: c_path(&root.join("proc"))?,
+        uid_map: format!("{0} {0} 1", unsafe { libc::getuid() }).into_bytes(),
+        gid_map: format!("{0} {0} 1", unsafe { libc::getgid() }).into_bytes(),
+        max_fd,
+    };
+    push_dirs(&mut spec.dirs, root, Path::new("/tmp"))?;
+    push_dirs(&mut spec.dirs, root, Path::new("/proc"))?;
+    push_dirs(&mut spec.dirs, root, Path::new("/.old"))?;
+
+    let executable_dir = executable.parent().unwrap_or(Path::new("/"));
+    let read_only_dirs = READ_ONLY_DIRS.iter().map(|x| Path::new(*x)).chain(Some(executable_dir));
+    for dir in read_only_dirs.filter(|x| x.is_dir()) {
+        let target = under_root(root, dir);
+        push_dirs(&mut spec.dirs, root, dir)?;
+        spec.mounts.push(BindMount {
+            source: c_path(dir)?,
+            target: c_path(&target)?,
+            read_only: true,
+        });
+    }
+
+    let files = DEVICES.iter()
+        .map(|x| (PathBuf::from(*x), Path::new(*x)))
+        .chain(Some((PathBuf::from(socket), Path::new(SANDBOX_SOCKET))));
+    for (source, inside) in files.filter(|x| x.0.exists()) {
+        let target = under_root(root, inside);
+        push_dirs(&mut spec.dirs, root, inside.parent().unwrap())?;
+        spec.files.push(c_path(&target)?);
+        spec.mounts.push(BindMount {
+            source: c_path(&source)?,
+            target: c_path(&target)?,
+            read_only: false,
+        });
+    }
+
+    return Ok(spec);
+}
+
+fn write_file(path: &CStr, contents: &[u8]) -> io::Result<()> {
+    let fd = unsafe { libc::open(path.as_ptr(), libc::O_WRONLY) };
+    check(fd)?;
+    let written = unsafe { libc::write(fd, contents.as_ptr() as *const libc::c_void, contents.len()) };
+    unsafe { libc::close(fd) };
+    if written < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    return Ok(());
+}
+
+fn mount(source: Option<&CStr>, target: &CStr, fstype: Option<&CStr>, flags: libc::c_ulong) -> io::Result<()> {
+    return check(unsafe {
+        libc::mount(
+            source.map_or(ptr::null(), |x| x.as_ptr()),
+            target.as_ptr(),
+            fstype.map_or(ptr::null(), |x| x.as_ptr()),
+            flags,
+            ptr::null(),
+        )
+    });
+}
+
+// A user namespace may not clear these on mounts it did not create, so they are kept on remount
+fn locked_flags(path: &CStr) -> io::Result<libc::c_ulong> {
+    let mut stat: libc::statvfs = unsafe { mem::zeroed() };
+    check(unsafe { libc::statvfs(path.as_ptr(), &mut stat) })?;
+
+    let mut flags = 0;
+    let mapping = [
+        (libc::ST_NOSUID, libc::MS_NOSUID),
+        (libc::ST_NODEV, libc::MS_NODEV),
+        (libc::ST_NOEXEC, libc::MS_NOEXEC),
+        (libc::ST_NOATIME, libc::MS_NOATIME),
+        (libc::ST_NODIRATIME, libc::MS_NODIRATIME),
+        (libc::ST_RELATIME, libc::MS_RELATIME),
+    ];
+    for &(stat_flag, mount_flag) in mapping.iter() {
+        if stat.f_flag & stat_flag != 0 {
+            flags |= mount_flag;
+        }
+    }
+    return Ok(flags);
+}
+
+// The process that calls unshare stays outside the new pid namespace, only its children enter it.
+// It waits on the task and exits the same way, so crashes and OOM kills are still reported
+fn fork_into_pid_namespace(max_fd: libc::c_int) -> io::Result<()> {
+    // Only the waiting process keeps the write end open, getppid is 0 for the first process of a pid namespace
+    let mut alive: [libc::c_int; 2] = [0; 2];
+    check(unsafe { libc::pipe2(alive.as_mut_ptr(), libc::O_CLOEXEC) })?;
+    let pid = unsafe { libc::fork() };
+    check(pid)?;
+    if pid == 0 {
+        unsafe { libc::close(alive[1]) };
+        // Cancelling kills the waiting process, which must take the task down with it
+        check(unsafe { libc::prctl(libc::PR_SET_PDEATHSIG, libc::SIGKILL) })?;
+        // The waiting process may have been killed before the death signal was set up
+        check(unsafe { libc::fcntl(alive[0], libc::F_SETFL, libc::O_NONBLOCK) })?;
+        let mut byte: u8 = 0;
+        if unsafe { libc::read(alive[0], &mut byte as *mut u8 as *mut libc::c_void, 1) } == 0 {
+            unsafe { libc::_exit(1) };
+        }
+        unsafe { libc::close(alive[0]) };
+        return Ok(());
+    }
+
+    // spawn waits for the close-on-exec pipe it reports exec errors on to close, and this process
+    // never execs. Standard streams are already in place, nothing else is needed while waiting
+    for fd in (3..max_fd).filter(|x| *x != alive[1]) {
+        unsafe { libc::close(fd) };
+    }
+
+    let mut status: libc::c_int = 0;
+    unsafe {
+        while libc::waitpid(pid, &mut status, 0) < 0 {
+            if io::Error::last_os_error().kind() != io::ErrorKind::Interrupted {
+                libc::_exit(1);
+            }
+        }
+        if libc::WIFSIGNALED(status) {
+            let signal = libc::WTERMSIG(status);
+            libc::signal(signal, libc::SIG_DFL);
+            libc::kill(libc::getpid(), signal);
+        }
+        libc::_exit(if libc::WIFEXITED(status) { libc::WEXITSTATUS(status) } else { 1 });
+    }
+}
+
+fn build_root(spec: &SandboxSpec) -> io::Result<()> {
+    let tmpfs = c_str(b"tmpfs\0");
+    let procfs = c_str(b"proc\0");
+    let slash = c_str(b"/\0");
+
+    // Keep our mounts from propagating back to the host
+    mount(None, slash, None, libc::MS_REC | libc::MS_PRIVATE)?;
+    mount(Some(tmpfs), &spec.root, Some(tmpfs), libc::MS_NOSUID | libc::MS_NODEV)?;
+
+    for dir in spec.dirs.iter() {
+        if unsafe { libc::mkdir(dir.as_ptr(), 0o755) } < 0 {
+            let error = io::Error::last_os_error();
+            if error.kind() != io::ErrorKind::AlreadyExists {
+                return Err(error);
+            }
+        }
+    }
+    for file in spec.files.iter() {
+        let fd = unsafe { libc::open(file.as_ptr(), libc::O_WRONLY | libc::O_CREAT, 0o644) };
+        check(fd)?;
+        unsafe { libc::close(fd) };
+    }
+    for bind in spec.mounts.iter() {
+        mount(Some(&bind.source), &bind.target, None, libc::MS_BIND | libc::MS_REC)?;
+        if bind.read_only {
+            let flags = locked_flags(&bind.target)?;
+            mount(None, &bind.target, None, libc::MS_BIND | libc::MS_REMOUNT | libc::MS_RDONLY | flags)?;
+        }
+    }
+    mount(Some(tmpfs), &spec.tmp, Some(tmpfs), libc::MS_NOSUID | libc::MS_NODEV)?;
+    mount(Some(procfs), &spec.proc_dir, Some(procfs), libc::MS_NOSUID | libc::MS_NODEV | libc::MS_NOEXEC)?;
+
+    check(unsafe { libc::chdir(spec.root.as_ptr()) })?;
+    let pivoted = unsafe { libc::syscall(libc::SYS_pivot_root, c_str(b".\0").as_ptr(), c_str(b".old\0").as_ptr()) };
+    check(pivoted as libc::c_int)?;
+    check(unsafe { libc::umount2(c_str(b"/.old\0").as_ptr(), libc::MNT_DETACH) })?;
+    check(unsafe { libc::rmdir(c_str(b"/.old\0").as_ptr()) })?;
+
+    mount(None, slash, None, libc::MS_REMOUNT | libc::MS_RDONLY | libc::MS_NOSUID | libc::MS_NODEV)?;
+    return check(unsafe { libc::chdir(c_str(b"/tmp\0").as_ptr()) });
+}
+
+// The task has already joined its cgroup by the time this runs, see limits::apply_limits
+pub fn apply(command: &mut Command, root: &SandboxRoot, socket: &str, executable: &Path) -> io::Result<()> {
+    let max_fd = unsafe { libc::sysconf(libc::_SC_OPEN_MAX) };
+    let spec = prepare(&root.path, socket, executable, if max_fd > 0 { max_fd as libc::c_int } else { 1024 })?;
+    let setgroups = c_str(b"/proc/self/setgroups\0");
+    let uid_map = c_str(b"/proc/self/uid_map\0");
+    let gid_map = c_str(b"/proc/self/gid_map\0");
+
+    let hook = move || -> io::Result<()> {
+        check(unsafe {
+            libc::unshare(
+                libc::CLONE_NEWUSER
+                    | libc::CLONE_NEWNS
+                    | libc::CLONE_NEWPID
+                    | libc::CLONE_NEWNET
+                    | libc::CLONE_NEWIPC
+                    | libc::CLONE_NEWUTS,
+            )
+        })?;
+        // Tasks keep the worker's uid, so they lose all capabilities on exec
+        write_file(setgroups, b"deny")?;
+        write_file(uid_map, &spec.uid_map)?;
+        write_file(gid_map, &spec.gid_map)?;
+
+        fork_into_pid_namespace(spec.max_fd)?;
+        return build_root(&spec);
+    };
+    unsafe { command.pre_exec(hook) };
+    return Ok(());
+}
+
+impl Executor for SandboxExecutor {
+
+    fn start_job(config: &Config,
+                 _rt: &mut Runtime,
+                 state: &Arc<RwLock<WorkerState>>,
+                 master_sender: &Sender<ServerMessage>,
+                 _docker_name: &String) -> Option<SandboxExecutor> {
+        info!("Running using SandboxExecutor");
+        // Tasks have no network, so the executor socket is their only way out
+        if state.read().unwrap().executor_socket.len() == 0 {
+            error!("Sandboxed tasks need an executor socket!");
+            halt_task(state, master_sender);
+            return None;
+        }
+
+        let root = match SandboxRoot::create(config) {
+            Ok(root) => root,
+            Err(e) => {
+                error!("Could not create the sandbox root! Error: {}", e.to_string());
+                halt_task(state, master_sender);
+                return None;
+            }
+        };
+        return ShellExecutor::launch(config, state, master_sender, Some(&root))
+            .map(|shell| SandboxExecutor { shell, _root: root });
+    }
+
+    fn cancel_job(&mut self, rt: &mut Runtime) {
+        self.shell.cancel_job(rt);
+    }
+
+    fn detect_crash(&mut self, rt: &mut Runtime) -> Option<TaskResult> {
+        return self.shell.detect_crash(rt);
+    }
+
+    fn has_exited(&mut self, rt: &mut Runtime) -> bool {
+        return self.shell.has_exited(rt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn test_root(port: i32) -> SandboxRoot {
+        let mut config = Config::default();
+        config.worker.port = port;
+        return SandboxRoot::create(&config).unwrap();
+    }
+
+    fn find_mount<'a>(spec: &'a SandboxSpec, source: &Path) -> Option<&'a BindMount> {
+        let source = c_path(source).unwrap();
+        return spec.mounts.iter().find(|x| x.source == source);
+    }
+
+    #[test]
+    fn test_sanity_sandbox_root() {
+        let first = test_root(40001);
+        let second = test_root(40001);
+        assert_ne!(first.path, second.path);
+        assert!(first.path.is_dir());
+
+        // Every task's root is removed with its executor
+        let path = first.path.clone();
+        drop(first);
+        assert!(!path.exists());
+        assert!(second.path.is_dir());
+    }
+
+    #[test]
+    fn test_sanity_prepare_mounts() {
+        let root = test_root(40001);
+        let spec = prepare(&root.path, "", Path::new("/nonexistent/wordcount"), 1024).unwrap();
+
+        let usr = find_mount(&spec, Path::new("/usr")).unwrap();
+        assert_eq!(usr.target, c_path(&root.path.join("usr")).unwrap());
+        assert_eq!(usr.read_only, true);
+
+        let null = find_mount(&spec, Path::new("/dev/null")).unwrap();
+        assert_eq!(null.target, c_path(&root.path.join("dev/null")).unwrap());
+        assert_eq!(null.read_only, false);
+        assert!(spec.files.contains(&null.target));
+
+        // Missing directories are not mounted
+        assert!(find_mount(&spec, Path::new("/nonexistent")).is_none());
+    }
+
+    #[test]
+    fn test_sanity_prepare_executable_and_socket() {
+        let root = test_root(40002);
+        let outside = env::temp_dir().join("parliament-sandbox-test-outside");
+        fs::create_dir_all(&outside).unwrap();
+        let socket = outside.join("executor.sock");
+        fs::write(&socket, b"").unwrap();
+
+        let spec = prepare(&root.path, socket.to_str().unwrap(), &outside.join("wordcount"), 1024).unwrap();
+
+        let executable_dir = find_mount(&spec, &outside).unwrap();
+        assert_eq!(executable_dir.target, c_path(&under_root(&root.path, &outside)).unwrap());
+        assert_eq!(executable_dir.read_only, true);
+
+        let mounted_socket = find_mount(&spec, &socket).unwrap();
+        assert_eq!(mounted_socket.target, c_path(&root.path.join("run/parliament.sock")).unwrap());
+        assert!(spec.files.contains(&mounted_socket.target));
+        assert!(spec.dirs.contains(&c_path(&root.path.join("run")).unwrap()));
+
+        // Parents are always created before their children
+        let position = |path: &Path| spec.dirs.iter().position(|x| *x == c_path(path).unwrap());
+        let parent = position(&under_root(&root.path, outside.parent().unwrap())).unwrap();
+        assert!(parent < position(&under_root(&root.path, &outside)).unwrap());
+    }
+
+    #[test]
+    fn test_sanity_prepare_dirs() {
+        let root = test_root(40003);
+        let spec = prepare(&root.path, "", Path::new("/nonexistent/wordcount"), 1024).unwrap();
+
+        for dir in ["tmp", "proc", ".old", "usr", "dev"].iter() {
+            assert!(spec.dirs.contains(&c_path(&root.path.join(dir)).unwrap()), "{} is missing", dir);
+        }
+        assert_eq!(spec.tmp, c_path(&root.path.join("tmp")).unwrap());
+        assert_eq!(spec.proc_dir, c_path(&root.path.join("proc")).unwrap());
+        assert_eq!(spec.dirs.iter().filter(|x| **x == spec.tmp).count(), 1);
+    }
+
+    #[test]
+    fn test_sanity_prepare_id_maps() {
+        let root = test_root(40004);
+        let spec = prepare(&root.path, "", Path::new("/nonexistent/wordcount"), 1024).unwrap();
+        let uid = unsafe { libc::getuid() };
+        let gid = unsafe { libc::getgid() };
+        assert_eq!(spec.uid_map, format!("{} {} 1", uid, uid).into_bytes());
+        assert_eq!(spec.gid_map, format!("{} {} 1", gid, gid).into_bytes());
+    }
+
+    #[test]
+    fn test_sanity_spawn_returns_before_exit() {
+        let root = test_root(40005);
+        let mut command = Command::new("/bin/sleep");
+        command.arg("5");
+        apply(&mut command, &root, "", Path::new("/bin/sleep")).unwrap();
+
+        let started = Instant::now();
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                // Not every machine allows unprivileged user namespaces
+                eprintln!("Could not start a sandbox, skipping! Error: {}", e.to_string());
+                return;
+            }
+        };
+        let spawn_time = started.elapsed();
+        assert_eq!(child.try_wait().unwrap(), None);
+        child.kill().unwrap();
+        child.wait().unwrap();
+        assert!(spawn_time < Duration::from_secs(2), "spawn took {:?}", spawn_time);
+    }
+}
@@ -3,49 +3,121 @@
     Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
 */
 
-use std::{env, thread};
+use std::thread;
 use std::sync::{Arc, RwLock};
 use std::io::{BufReader, BufRead};
+use std::os::unix::process::ExitStatusExt;
 use std::process::{Stdio, Child, Command};
 
 use crossbeam_channel::Sender;
-use protobuf::RepeatedField;
-use log::{error, info};
+use log::{error, info, warn};
 
 use tokio::runtime::current_thread::Runtime;
 use config::{Config, WorkerStatus, WorkerState};
-use executor::{Executor, TaskResult, ServerMessage, ServerMessageType};
+use executor::{halt_task, Executor, TaskResult, ServerMessage};
+use executor::artifacts;
+use executor::limits::{self, Cgroup};
+use executor::sandbox::{self, SandboxRoot};
+use util;
 
 
 pub struct ShellExecutor {
-    pub process : Child
+    pub process : Child,
+    pub cgroup: Option<Cgroup>,
 }
 
-impl Executor for ShellExecutor {
+impl ShellExecutor {
+    // Only a cgroup can tell an OOM kill apart from any other SIGKILL
+    fn killed_for_memory(&self) -> bool {
+        return match self.cgroup {
+            Some(ref cgroup) => cgroup.oom_killed(),
+            None => false,
+        };
+    }
 
-    fn start_job(config: &Config,
-                 _rt: &mut Runtime,
-                 state: &Arc<RwLock<WorkerState>>,
-                 master_sender: &Sender<ServerMessage>,
-                 _docker_name: &String) -> Option<ShellExecutor> {
-        info!("Running using ShellExecutor");
-        let home_dir = env::home_dir().unwrap();
-        let command_option = Command::new(format!("{}/executables/parallel.exe", home_dir.display()))
+    // Shared with the SandboxExecutor, which runs the same process inside namespaces
+    pub fn launch(config: &Config,
+                  state: &Arc<RwLock<WorkerState>>,
+                  master_sender: &Sender<ServerMessage>,
+                  sandbox_root: Option<&SandboxRoot>) -> Option<ShellExecutor> {
+        let executable = state.read().unwrap().task_executable.clone();
+        let name = if executable.name.len() > 0 {
+            executable.name.clone()
+        } else {
+            config.default_executable.clone()
+        };
+
+        // Uploaded artifacts take precedence over binaries installed on the worker
+        let path_option = if executable.artifact_digest.len() > 0 {
+            artifacts::fetch(config, state, &executable.artifact_digest)
+        } else {
+            util::resolve_executable(&config.executables_dir, &name)
+        };
+
+        let path = match path_option {
+            Some(path) => path,
+            None => {
+                error!("Executable is not available! Name: {}, Artifact: {}", &name, &executable.artifact_digest);
+                halt_task(state, master_sender);
+                return None;
+            }
+        };
+        info!("Executing {} with args {:?}", path.display(), &executable.args);
+
+        let task_limits = limits::effective_limits(&config.limits, &state.read().unwrap().task_limits);
+        info!("Applying resource limits: {:?}", &task_limits);
+
+        // The master rejects these already, they are dropped again in case it is out of date
+        let envs: Vec<(String, String)> = executable.env
+            .iter()
+            .filter(|(key, _)| {
+                let reserved = util::reserved_env_key(key);
+                if reserved {
+                    warn!("Ignoring reserved environment variable {}!", key);
+                }
+                !reserved
+            })
+            .cloned()
+            .collect();
+
+        let mut command_builder = Command::new(&path);
+        command_builder
+            .args(&executable.args)
+            .envs(envs)
             .env("PARLIAMENT_MODE".to_string(),"Worker".to_string())
             .env("PARLIAMENT_HOST".to_string(), "localhost".to_string())
             .env("PARLIAMENT_PORT".to_string(), config.executor.port.to_string())
+            .env("PARLIAMENT_TOKEN".to_string(), state.read().unwrap().executor_token.clone())
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn();
+            .stderr(Stdio::piped());
+        let executor_socket = state.read().unwrap().executor_socket.clone();
+        if sandbox_root.is_some() {
+            command_builder.env("PARLIAMENT_SOCKET".to_string(), sandbox::SANDBOX_SOCKET.to_string());
+        } else if executor_socket.len() > 0 {
+            command_builder.env("PARLIAMENT_SOCKET".to_string(), executor_socket.clone());
+        }
+        if config.warm_tasks > 0 {
+            command_builder.env("PARLIAMENT_WARM".to_string(), "1".to_string());
+        }
+        let cgroup = Cgroup::create(&config.cgroup_parent, &format!("worker-{}", config.worker.port), &task_limits);
+        if let Err(e) = limits::apply_limits(&mut command_builder, &task_limits, cgroup.as_ref()) {
+            error!("Could not prepare the resource limits! Error: {}", e.to_string());
+            halt_task(state, master_sender);
+            return None;
+        }
+        if let Some(root) = sandbox_root {
+            if let Err(e) = sandbox::apply(&mut command_builder, root, &executor_socket, &path) {
+                error!("Could not prepare the sandbox! Error: {}", e.to_string());
+                halt_task(state, master_sender);
+                return None;
+            }
+        }
+        let command_option = command_builder.spawn();
 
         if command_option.is_err() {
             let err = command_option.unwrap_err();
             error!("Received error when attempting to execute process: {}", err.to_string());
-            state.write().unwrap().status = WorkerStatus::Halted;
-            master_sender.send(ServerMessage {
-                message_type: ServerMessageType::FinishedRequest(TaskResult::JobErrored, Arc::new(RepeatedField::default())),
-                retry_count: 0
-            }).expect("Could not send finished request. Internal message broker is broken!");
+            halt_task(state, master_sender);
             return None;
         }
 
@@ -71,24 +143,51 @@ impl Executor for ShellExecutor {
         });
 
         return Some(ShellExecutor {
-            process: command
+            process: command,
+            cgroup,
         });
     }
+}
+
+impl Executor for ShellExecutor {
 
-    fn cancel_job(&mut self) {
+    fn start_job(config: &Config,
+                 _rt: &mut Runtime,
+                 state: &Arc<RwLock<WorkerState>>,
+                 master_sender: &Sender<ServerMessage>,
+                 _docker_name: &String) -> Option<ShellExecutor> {
+        info!("Running using ShellExecutor");
+        return ShellExecutor::launch(config, state, master_sender, None);
+    }
+
+    fn cancel_job(&mut self, _rt: &mut Runtime) {
         self.process.kill();
     }
 
-    fn detect_crash(&mut self, _rt: &mut Runtime) -> bool {
+    fn detect_crash(&mut self, _rt: &mut Runtime) -> Option<TaskResult> {
         match self.process.try_wait() {
             Ok(Some(status)) => {
                 if !status.success() {
-                    error!("Process ended in with an unsuccessful error code! Error code: {}", status.code().unwrap());
-                    return true;
+                    if self.killed_for_memory() {
+                        error!("Process was killed for exceeding its memory limit!");
+                        return Some(TaskResult::JobOutOfMemory);
+                    }
+                    match status.code() {
+                        Some(code) => error!("Process ended in with an unsuccessful error code! Error code: {}", code),
+                        None => error!("Process was terminated by signal {:?}!", status.signal()),
+                    }
+                    return Some(TaskResult::JobErrored);
                 }
             },
-            _ => return false
+            _ => return None
         }
-        return false;
+        return None;
+    }
+
+    fn has_exited(&mut self, _rt: &mut Runtime) -> bool {
+        return match self.process.try_wait() {
+            Ok(None) => false,
+            _ => true
+        };
     }
 }
@@ -7,10 +7,12 @@ extern crate byteorder;
 extern crate clap;
 extern crate core;
 extern crate crossbeam_channel;
+extern crate libc;
 extern crate log;
 extern crate man;
 extern crate protobuf;
 extern crate rand;
+extern crate sha2;
 extern crate shiplift;
 extern crate simple_logger;
 extern crate tokio;
@@ -40,7 +42,7 @@ fn load_config(arg: ArgMatches) -> Config {
 
             Standard tasks are run as a subprocess of the Member Of Parliament process and hence will run using the same permissions & any process constraints.
 
-            To run Docker tasks, please ensure that the Docker daemon (https://docker.com) is installed on the machine and the running user has access to the docker group. Member of Parliament communicates using the default UNIX Docker socket (/var/run/docker.sock)")
+            To run Docker tasks, please ensure that the Docker daemon (https://docker.com) is installed on the machine and the running user has access to the docker group. Member of Parliament communicates using the default UNIX Docker socket (/var/run/docker.sock) unless told otherwise. Containers are removed once their task finishes or is cancelled")
             .option(
                 Opt::new("config")
                     .short("-c")
@@ -74,6 +76,92 @@ fn load_config(arg: ArgMatches) -> Config {
                     .long("--executor")
                     .help("[IP:Port] of the exposed worker server, for communication with Parliament processes. Default: 0.0.0.0:1100"),
             )
+            .option(
+                Opt::new("label")
+                    .short("-l")
+                    .long("--label")
+                    .help("[key=value] label advertised to the Prime Minister, can be given multiple times. Overrides the detected arch, docker & memory_mb labels"),
+            )
+            .option(
+                Opt::new("memory limit")
+                    .long("--memory")
+                    .help("Maximum memory in MB for a task. Jobs may request less. Default: unlimited"),
+            )
+            .option(
+                Opt::new("cpu limit")
+                    .long("--cpu")
+                    .help("Maximum CPU usage, as a percentage of one core, for a task. Requires cgroup v2 for tasks run without Docker. Default: unlimited"),
+            )
+            .option(
+                Opt::new("file descriptor limit")
+                    .long("--fds")
+                    .help("Maximum number of open files for a task run without Docker. Default: unlimited"),
+            )
+            .option(
+                Opt::new("cgroup")
+                    .long("--cgroup")
+                    .help("cgroup v2 directory task cgroups are created under. Default: /sys/fs/cgroup/parliament"),
+            )
+            .option(
+                Opt::new("executables")
+                    .long("--executables")
+                    .help("Directory of binaries users may choose to run tasks without Docker. Names outside of it are rejected. Default: ~/executables"),
+            )
+            .option(
+                Opt::new("default executable")
+                    .long("--exe")
+                    .help("Binary in the executables directory run when a user does not name one. Default: parallel.exe"),
+            )
+            .option(
+                Opt::new("artifact cache")
+                    .long("--artifacts")
+                    .help("Directory executables uploaded by users are cached in once fetched from the Prime Minister. Default: ~/.parliament/artifacts"),
+            )
+            .option(
+                Opt::new("closure cache")
+                    .long("--closures")
+                    .help("No. of task closures kept in memory, so they are only fetched from the Prime Minister once. Default: 16"),
+            )
+            .option(
+                Opt::new("broadcast cache")
+                    .long("--broadcasts")
+                    .help("Directory broadcasts of user workloads are cached in once fetched from the Prime Minister. Default: ~/.parliament/broadcasts"),
+            )
+            .option(
+                Opt::new("warm executors")
+                    .long("--warm")
+                    .help("No. of tasks a process or container runs before being replaced, keeping it alive between tasks. 0 starts a new one for every task. Default: 0"),
+            )
+            .option(
+                Opt::new("executor socket")
+                    .long("--socket")
+                    .help("Path of a Unix domain socket shared by all tasks. By default every process or container gets a socket of its own in a private directory. Docker tasks have it mounted at /var/run/parliament.sock"),
+            )
+            .option(
+                Opt::new("docker host")
+                    .long("--docker")
+                    .help("Socket path or URL of the Docker API. Default: unix:///var/run/docker.sock"),
+            )
+            .option(
+                Opt::new("pull policy")
+                    .long("--pull")
+                    .help("[always|missing|never] When Docker images are pulled before a task is started. Default: missing"),
+            )
+            .option(
+                Opt::new("docker network")
+                    .long("--network")
+                    .help("Network mode of task containers. Default: host on Linux, bridge elsewhere"),
+            )
+            .option(
+                Opt::new("docker gateway")
+                    .long("--gateway")
+                    .help("Hostname containers reach the executor server at when not using host networking. Default: host.docker.internal"),
+            )
+            .option(
+                Opt::new("sandbox")
+                    .long("--sandbox")
+                    .help("Run tasks without Docker inside user, mount, pid & network namespaces, with a read-only root and a private /tmp. Requires unprivileged user namespaces"),
+            )
             .option(Opt::new("help").long("--help").help("View the help page"))
             .render();
 
@@ -113,6 +201,79 @@ fn load_config(arg: ArgMatches) -> Config {
             }
         }
 
+        if let Some(memory_limit) = arg.value_of("MEMORY_LIMIT") {
+            config.limits.memory_mb = memory_limit.parse::<u64>().unwrap();
+        }
+
+        if let Some(cpu_limit) = arg.value_of("CPU_LIMIT") {
+            config.limits.cpu_percent = cpu_limit.parse::<u64>().unwrap();
+        }
+
+        if let Some(fd_limit) = arg.value_of("FD_LIMIT") {
+            config.limits.open_files = fd_limit.parse::<u64>().unwrap();
+        }
+
+        if let Some(cgroup_parent) = arg.value_of("CGROUP") {
+            config.cgroup_parent = cgroup_parent.to_string();
+        }
+
+        if let Some(executables_dir) = arg.value_of("EXECUTABLES_DIR") {
+            config.executables_dir = executables_dir.to_string();
+        }
+
+        if let Some(default_executable) = arg.value_of("DEFAULT_EXECUTABLE") {
+            config.default_executable = default_executable.to_string();
+        }
+
+        if let Some(artifact_cache) = arg.value_of("ARTIFACT_CACHE") {
+            config.artifact_cache = artifact_cache.to_string();
+        }
+
+        if let Some(closure_cache_size) = arg.value_of("CLOSURE_CACHE") {
+            config.closure_cache_size = closure_cache_size.parse::<usize>().unwrap();
+        }
+
+        if let Some(broadcast_cache) = arg.value_of("BROADCAST_CACHE") {
+            config.broadcast_cache = broadcast_cache.to_string();
+        }
+
+        if let Some(warm_tasks) = arg.value_of("WARM_TASKS") {
+            config.warm_tasks = warm_tasks.parse::<u32>().unwrap();
+        }
+
+        if let Some(executor_socket) = arg.value_of("EXECUTOR_SOCKET") {
+            config.executor_socket = executor_socket.to_string();
+        }
+
+        if let Some(docker_host) = arg.value_of("DOCKER_HOST") {
+            config.docker_host = docker_host.to_string();
+        }
+
+        if let Some(pull_policy) = arg.value_of("PULL_POLICY") {
+            if let Some(this) = util::parse_pull_policy(pull_policy) {
+                config.pull_policy = this;
+            }
+        }
+
+        if let Some(docker_network) = arg.value_of("DOCKER_NETWORK") {
+            config.docker_network = docker_network.to_string();
+        }
+
+        if let Some(docker_gateway) = arg.value_of("DOCKER_GATEWAY") {
+            config.docker_gateway = docker_gateway.to_string();
+        }
+
+        config.sandbox = arg.is_present("SANDBOX");
+
+        config.labels = util::detect_labels(&config.docker_host);
+        if let Some(labels) = arg.values_of("LABEL") {
+            for label in labels {
+                if let Some((key, value)) = util::split_label(label) {
+                    config.labels.insert(key, value);
+                }
+            }
+        }
+
         return config;
     }
 }
@@ -125,8 +286,22 @@ fn print_config(config: &Config) {
     info!("Export Port: {}", &config.worker.port);
     info!("Executor IP: {}", &config.executor.hostname);
     info!("Executor Port: {}", &config.executor.port);
+    info!("Executor Socket: {}", &config.executor_socket);
     info!("Master Hostname: {}", &config.master.hostname);
     info!("Master Port: {}", &config.master.port);
+    info!("Labels: {:?}", &config.labels);
+    info!("Resource Limits: {:?}", &config.limits);
+    info!("Executables Directory: {}", &config.executables_dir);
+    info!("Default Executable: {}", &config.default_executable);
+    info!("Artifact Cache: {}", &config.artifact_cache);
+    info!("Closure Cache Size: {}", &config.closure_cache_size);
+    info!("Broadcast Cache: {}", &config.broadcast_cache);
+    info!("Warm Executor Tasks: {}", &config.warm_tasks);
+    info!("Docker Host: {}", &config.docker_host);
+    info!("Docker Pull Policy: {:?}", &config.pull_policy);
+    info!("Docker Network: {}", &config.docker_network);
+    info!("Docker Gateway: {}", &config.docker_gateway);
+    info!("Sandbox: {}", &config.sandbox);
 }
 
 fn main() {
@@ -140,8 +315,20 @@ fn main() {
         last_request: util::current_secs(),
         data_in: None,
         closure: None,
+        closure_digest: "".to_string(),
+        broadcast_digests: Vec::new(),
+        broadcasts: Vec::new(),
         task_type: None,
         task_id: "".to_string(),
+        task_timeout: 0,
+        task_limits: config::ResourceLimits::default(),
+        task_executable: config::Executable::default(),
+        executor_token: "".to_string(),
+        warm_executor_token: "".to_string(),
+        executor_socket: "".to_string(),
+        task_progress: 0.0,
+        task_progress_message: "".to_string(),
+        task_accumulators: Vec::new(),
     }));
 
     //PubSub for sending messages to master
@@ -165,6 +352,27 @@ fn main() {
 
         (@arg MASTER_IP: --master +takes_value "[IP:Port] of the user server of the Prime Minister. Default: 127.0.0.1:1240")
 
+        (@arg LABEL: -l --label +takes_value +multiple "[key=value] label advertised to the Prime Minister")
+
+        (@arg MEMORY_LIMIT: --memory +takes_value "Maximum memory in MB for a task")
+        (@arg CPU_LIMIT: --cpu +takes_value "Maximum CPU usage, as a percentage of one core, for a task")
+        (@arg FD_LIMIT: --fds +takes_value "Maximum number of open files for a task run without Docker")
+        (@arg CGROUP: --cgroup +takes_value "cgroup v2 directory task cgroups are created under")
+
+        (@arg EXECUTABLES_DIR: --executables +takes_value "Directory of binaries users may choose to run tasks without Docker")
+        (@arg DEFAULT_EXECUTABLE: --exe +takes_value "Binary in the executables directory run when a user does not name one")
+        (@arg ARTIFACT_CACHE: --artifacts +takes_value "Directory uploaded executables are cached in")
+        (@arg CLOSURE_CACHE: --closures +takes_value "No. of task closures kept in memory")
+        (@arg BROADCAST_CACHE: --broadcasts +takes_value "Directory broadcasts of user workloads are cached in")
+        (@arg WARM_TASKS: --warm +takes_value "No. of tasks a process or container runs before being replaced")
+        (@arg EXECUTOR_SOCKET: --socket +takes_value "Path of a Unix domain socket shared by all tasks instead of one per task")
+
+        (@arg DOCKER_HOST: --docker +takes_value "Socket path or URL of the Docker API. Default: unix:///var/run/docker.sock")
+        (@arg PULL_POLICY: --pull +takes_value "[always|missing|never] When Docker images are pulled. Default: missing")
+        (@arg DOCKER_NETWORK: --network +takes_value "Network mode of task containers")
+        (@arg DOCKER_GATEWAY: --gateway +takes_value "Hostname containers reach the executor server at when not using host networking")
+        (@arg SANDBOX: --sandbox "Run tasks without Docker inside namespaces")
+
         (@arg MAN_PAGE: -m --man "Display man page")
     );
     let config = load_config(matches.get_matches());
@@ -546,79 +546,235 @@ impl ::protobuf::reflect::ProtobufValue for InputAction {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct MapAction {
+pub struct PreviousOutputAction {
     // message fields
-    pub mapType: MapAction_MapType,
-    pub job_id_in: i32,
-    pub function_closure: ::std::vec::Vec<u8>,
+    pub job_id: i32,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl MapAction {
-    pub fn new() -> MapAction {
+impl PreviousOutputAction {
+    pub fn new() -> PreviousOutputAction {
         ::std::default::Default::default()
     }
 
-    // .MapAction.MapType mapType = 1;
+    // int32 job_id = 1;
 
-    pub fn clear_mapType(&mut self) {
-        self.mapType = MapAction_MapType::SINGLE_IN_MULTI_OUT;
+    pub fn clear_job_id(&mut self) {
+        self.job_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_mapType(&mut self, v: MapAction_MapType) {
-        self.mapType = v;
+    pub fn set_job_id(&mut self, v: i32) {
+        self.job_id = v;
     }
 
-    pub fn get_mapType(&self) -> MapAction_MapType {
-        self.mapType
+    pub fn get_job_id(&self) -> i32 {
+        self.job_id
     }
+}
 
-    // int32 job_id_in = 2;
+impl ::protobuf::Message for PreviousOutputAction {
+    fn is_initialized(&self) -> bool {
+        true
+    }
 
-    pub fn clear_job_id_in(&mut self) {
-        self.job_id_in = 0;
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.job_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_job_id_in(&mut self, v: i32) {
-        self.job_id_in = v;
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.job_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.job_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    pub fn get_job_id_in(&self) -> i32 {
-        self.job_id_in
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.job_id != 0 {
+            os.write_int32(1, self.job_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // bytes function_closure = 3;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
 
-    pub fn clear_function_closure(&mut self) {
-        self.function_closure.clear();
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PreviousOutputAction {
+        PreviousOutputAction::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_id",
+                    |m: &PreviousOutputAction| { &m.job_id },
+                    |m: &mut PreviousOutputAction| { &mut m.job_id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PreviousOutputAction>(
+                    "PreviousOutputAction",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static PreviousOutputAction {
+        static mut instance: ::protobuf::lazy::Lazy<PreviousOutputAction> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const PreviousOutputAction,
+        };
+        unsafe {
+            instance.get(PreviousOutputAction::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for PreviousOutputAction {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PreviousOutputAction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PreviousOutputAction {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileInputAction {
+    // message fields
+    pub path: ::std::string::String,
+    pub split_mode: FileInputAction_SplitMode,
+    pub split_size: i32,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl FileInputAction {
+    pub fn new() -> FileInputAction {
+        ::std::default::Default::default()
+    }
+
+    // string path = 1;
+
+    pub fn clear_path(&mut self) {
+        self.path.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_function_closure(&mut self, v: ::std::vec::Vec<u8>) {
-        self.function_closure = v;
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_function_closure(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.function_closure
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
     }
 
     // Take field
-    pub fn take_function_closure(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.function_closure, ::std::vec::Vec::new())
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
     }
 
-    pub fn get_function_closure(&self) -> &[u8] {
-        &self.function_closure
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+
+    // .FileInputAction.SplitMode split_mode = 2;
+
+    pub fn clear_split_mode(&mut self) {
+        self.split_mode = FileInputAction_SplitMode::LINES;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_split_mode(&mut self, v: FileInputAction_SplitMode) {
+        self.split_mode = v;
+    }
+
+    pub fn get_split_mode(&self) -> FileInputAction_SplitMode {
+        self.split_mode
+    }
+
+    // int32 split_size = 3;
+
+    pub fn clear_split_size(&mut self) {
+        self.split_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_split_size(&mut self, v: i32) {
+        self.split_size = v;
+    }
+
+    pub fn get_split_size(&self) -> i32 {
+        self.split_size
     }
 }
 
-impl ::protobuf::Message for MapAction {
+impl ::protobuf::Message for FileInputAction {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -628,17 +784,17 @@ impl ::protobuf::Message for MapAction {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.mapType, 1, &mut self.unknown_fields)?
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
                 },
                 2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.split_mode, 2, &mut self.unknown_fields)?
+                },
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_int32()?;
-                    self.job_id_in = tmp;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.function_closure)?;
+                    self.split_size = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -652,14 +808,14 @@ impl ::protobuf::Message for MapAction {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.mapType != MapAction_MapType::SINGLE_IN_MULTI_OUT {
-            my_size += ::protobuf::rt::enum_size(1, self.mapType);
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
         }
-        if self.job_id_in != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.job_id_in, ::protobuf::wire_format::WireTypeVarint);
+        if self.split_mode != FileInputAction_SplitMode::LINES {
+            my_size += ::protobuf::rt::enum_size(2, self.split_mode);
         }
-        if !self.function_closure.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.function_closure);
+        if self.split_size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.split_size, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -667,14 +823,14 @@ impl ::protobuf::Message for MapAction {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.mapType != MapAction_MapType::SINGLE_IN_MULTI_OUT {
-            os.write_enum(1, self.mapType.value())?;
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
         }
-        if self.job_id_in != 0 {
-            os.write_int32(2, self.job_id_in)?;
+        if self.split_mode != FileInputAction_SplitMode::LINES {
+            os.write_enum(2, self.split_mode.value())?;
         }
-        if !self.function_closure.is_empty() {
-            os.write_bytes(3, &self.function_closure)?;
+        if self.split_size != 0 {
+            os.write_int32(3, self.split_size)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -706,8 +862,8 @@ impl ::protobuf::Message for MapAction {
         Self::descriptor_static()
     }
 
-    fn new() -> MapAction {
-        MapAction::new()
+    fn new() -> FileInputAction {
+        FileInputAction::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -718,23 +874,23 @@ impl ::protobuf::Message for MapAction {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<MapAction_MapType>>(
-                    "mapType",
-                    |m: &MapAction| { &m.mapType },
-                    |m: &mut MapAction| { &mut m.mapType },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    |m: &FileInputAction| { &m.path },
+                    |m: &mut FileInputAction| { &mut m.path },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "job_id_in",
-                    |m: &MapAction| { &m.job_id_in },
-                    |m: &mut MapAction| { &mut m.job_id_in },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FileInputAction_SplitMode>>(
+                    "split_mode",
+                    |m: &FileInputAction| { &m.split_mode },
+                    |m: &mut FileInputAction| { &mut m.split_mode },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "function_closure",
-                    |m: &MapAction| { &m.function_closure },
-                    |m: &mut MapAction| { &mut m.function_closure },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "split_size",
+                    |m: &FileInputAction| { &m.split_size },
+                    |m: &mut FileInputAction| { &mut m.split_size },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<MapAction>(
-                    "MapAction",
+                ::protobuf::reflect::MessageDescriptor::new::<FileInputAction>(
+                    "FileInputAction",
                     fields,
                     file_descriptor_proto()
                 )
@@ -742,64 +898,64 @@ impl ::protobuf::Message for MapAction {
         }
     }
 
-    fn default_instance() -> &'static MapAction {
-        static mut instance: ::protobuf::lazy::Lazy<MapAction> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static FileInputAction {
+        static mut instance: ::protobuf::lazy::Lazy<FileInputAction> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MapAction,
+            ptr: 0 as *const FileInputAction,
         };
         unsafe {
-            instance.get(MapAction::new)
+            instance.get(FileInputAction::new)
         }
     }
 }
 
-impl ::protobuf::Clear for MapAction {
+impl ::protobuf::Clear for FileInputAction {
     fn clear(&mut self) {
-        self.clear_mapType();
-        self.clear_job_id_in();
-        self.clear_function_closure();
+        self.clear_path();
+        self.clear_split_mode();
+        self.clear_split_size();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for MapAction {
+impl ::std::fmt::Debug for FileInputAction {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MapAction {
+impl ::protobuf::reflect::ProtobufValue for FileInputAction {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum MapAction_MapType {
-    SINGLE_IN_MULTI_OUT = 0,
-    SINGLE_IN_SINGLE_OUT = 1,
-    MULTI_IN_SINGLE_OUT = 2,
+pub enum FileInputAction_SplitMode {
+    LINES = 0,
+    BYTES = 1,
+    FILES = 2,
 }
 
-impl ::protobuf::ProtobufEnum for MapAction_MapType {
+impl ::protobuf::ProtobufEnum for FileInputAction_SplitMode {
     fn value(&self) -> i32 {
         *self as i32
     }
 
-    fn from_i32(value: i32) -> ::std::option::Option<MapAction_MapType> {
+    fn from_i32(value: i32) -> ::std::option::Option<FileInputAction_SplitMode> {
         match value {
-            0 => ::std::option::Option::Some(MapAction_MapType::SINGLE_IN_MULTI_OUT),
-            1 => ::std::option::Option::Some(MapAction_MapType::SINGLE_IN_SINGLE_OUT),
-            2 => ::std::option::Option::Some(MapAction_MapType::MULTI_IN_SINGLE_OUT),
+            0 => ::std::option::Option::Some(FileInputAction_SplitMode::LINES),
+            1 => ::std::option::Option::Some(FileInputAction_SplitMode::BYTES),
+            2 => ::std::option::Option::Some(FileInputAction_SplitMode::FILES),
             _ => ::std::option::Option::None
         }
     }
 
     fn values() -> &'static [Self] {
-        static values: &'static [MapAction_MapType] = &[
-            MapAction_MapType::SINGLE_IN_MULTI_OUT,
-            MapAction_MapType::SINGLE_IN_SINGLE_OUT,
-            MapAction_MapType::MULTI_IN_SINGLE_OUT,
+        static values: &'static [FileInputAction_SplitMode] = &[
+            FileInputAction_SplitMode::LINES,
+            FileInputAction_SplitMode::BYTES,
+            FileInputAction_SplitMode::FILES,
         ];
         values
     }
@@ -811,175 +967,97 @@ impl ::protobuf::ProtobufEnum for MapAction_MapType {
         };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("MapAction_MapType", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("FileInputAction_SplitMode", file_descriptor_proto())
             })
         }
     }
 }
 
-impl ::std::marker::Copy for MapAction_MapType {
+impl ::std::marker::Copy for FileInputAction_SplitMode {
 }
 
-impl ::std::default::Default for MapAction_MapType {
+impl ::std::default::Default for FileInputAction_SplitMode {
     fn default() -> Self {
-        MapAction_MapType::SINGLE_IN_MULTI_OUT
+        FileInputAction_SplitMode::LINES
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MapAction_MapType {
+impl ::protobuf::reflect::ProtobufValue for FileInputAction_SplitMode {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Job {
+pub struct Label {
     // message fields
-    pub job_id: i32,
-    // message oneof groups
-    pub action: ::std::option::Option<Job_oneof_action>,
+    pub key: ::std::string::String,
+    pub value: ::std::string::String,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-#[derive(Clone,PartialEq)]
-pub enum Job_oneof_action {
-    input(InputAction),
-    map(MapAction),
-}
-
-impl Job {
-    pub fn new() -> Job {
+impl Label {
+    pub fn new() -> Label {
         ::std::default::Default::default()
     }
 
-    // int32 job_id = 1;
+    // string key = 1;
 
-    pub fn clear_job_id(&mut self) {
-        self.job_id = 0;
+    pub fn clear_key(&mut self) {
+        self.key.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_job_id(&mut self, v: i32) {
-        self.job_id = v;
-    }
-
-    pub fn get_job_id(&self) -> i32 {
-        self.job_id
-    }
-
-    // .InputAction input = 4;
-
-    pub fn clear_input(&mut self) {
-        self.action = ::std::option::Option::None;
-    }
-
-    pub fn has_input(&self) -> bool {
-        match self.action {
-            ::std::option::Option::Some(Job_oneof_action::input(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_input(&mut self, v: InputAction) {
-        self.action = ::std::option::Option::Some(Job_oneof_action::input(v))
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_input(&mut self) -> &mut InputAction {
-        if let ::std::option::Option::Some(Job_oneof_action::input(_)) = self.action {
-        } else {
-            self.action = ::std::option::Option::Some(Job_oneof_action::input(InputAction::new()));
-        }
-        match self.action {
-            ::std::option::Option::Some(Job_oneof_action::input(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
     }
 
     // Take field
-    pub fn take_input(&mut self) -> InputAction {
-        if self.has_input() {
-            match self.action.take() {
-                ::std::option::Option::Some(Job_oneof_action::input(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            InputAction::new()
-        }
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
     }
 
-    pub fn get_input(&self) -> &InputAction {
-        match self.action {
-            ::std::option::Option::Some(Job_oneof_action::input(ref v)) => v,
-            _ => InputAction::default_instance(),
-        }
+    pub fn get_key(&self) -> &str {
+        &self.key
     }
 
-    // .MapAction map = 5;
-
-    pub fn clear_map(&mut self) {
-        self.action = ::std::option::Option::None;
-    }
+    // string value = 2;
 
-    pub fn has_map(&self) -> bool {
-        match self.action {
-            ::std::option::Option::Some(Job_oneof_action::map(..)) => true,
-            _ => false,
-        }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_map(&mut self, v: MapAction) {
-        self.action = ::std::option::Option::Some(Job_oneof_action::map(v))
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_map(&mut self) -> &mut MapAction {
-        if let ::std::option::Option::Some(Job_oneof_action::map(_)) = self.action {
-        } else {
-            self.action = ::std::option::Option::Some(Job_oneof_action::map(MapAction::new()));
-        }
-        match self.action {
-            ::std::option::Option::Some(Job_oneof_action::map(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
     }
 
     // Take field
-    pub fn take_map(&mut self) -> MapAction {
-        if self.has_map() {
-            match self.action.take() {
-                ::std::option::Option::Some(Job_oneof_action::map(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            MapAction::new()
-        }
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
     }
 
-    pub fn get_map(&self) -> &MapAction {
-        match self.action {
-            ::std::option::Option::Some(Job_oneof_action::map(ref v)) => v,
-            _ => MapAction::default_instance(),
-        }
+    pub fn get_value(&self) -> &str {
+        &self.value
     }
 }
 
-impl ::protobuf::Message for Job {
+impl ::protobuf::Message for Label {
     fn is_initialized(&self) -> bool {
-        if let Some(Job_oneof_action::input(ref v)) = self.action {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Job_oneof_action::map(ref v)) = self.action {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -988,23 +1066,10 @@ impl ::protobuf::Message for Job {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.job_id = tmp;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.action = ::std::option::Option::Some(Job_oneof_action::input(is.read_message()?));
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
                 },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.action = ::std::option::Option::Some(Job_oneof_action::map(is.read_message()?));
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1018,20 +1083,11 @@ impl ::protobuf::Message for Job {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.job_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.job_id, ::protobuf::wire_format::WireTypeVarint);
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
         }
-        if let ::std::option::Option::Some(ref v) = self.action {
-            match v {
-                &Job_oneof_action::input(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Job_oneof_action::map(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1039,22 +1095,11 @@ impl ::protobuf::Message for Job {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.job_id != 0 {
-            os.write_int32(1, self.job_id)?;
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.action {
-            match v {
-                &Job_oneof_action::input(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Job_oneof_action::map(ref v) => {
-                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1086,8 +1131,8 @@ impl ::protobuf::Message for Job {
         Self::descriptor_static()
     }
 
-    fn new() -> Job {
-        Job::new()
+    fn new() -> Label {
+        Label::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1098,23 +1143,18 @@ impl ::protobuf::Message for Job {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "job_id",
-                    |m: &Job| { &m.job_id },
-                    |m: &mut Job| { &mut m.job_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, InputAction>(
-                    "input",
-                    Job::has_input,
-                    Job::get_input,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &Label| { &m.key },
+                    |m: &mut Label| { &mut m.key },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, MapAction>(
-                    "map",
-                    Job::has_map,
-                    Job::get_map,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &Label| { &m.value },
+                    |m: &mut Label| { &mut m.value },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Job>(
-                    "Job",
+                ::protobuf::reflect::MessageDescriptor::new::<Label>(
+                    "Label",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1122,112 +1162,101 @@ impl ::protobuf::Message for Job {
         }
     }
 
-    fn default_instance() -> &'static Job {
-        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Label {
+        static mut instance: ::protobuf::lazy::Lazy<Label> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Job,
+            ptr: 0 as *const Label,
         };
         unsafe {
-            instance.get(Job::new)
+            instance.get(Label::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Job {
+impl ::protobuf::Clear for Label {
     fn clear(&mut self) {
-        self.clear_job_id();
-        self.clear_input();
-        self.clear_map();
+        self.clear_key();
+        self.clear_value();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Job {
+impl ::std::fmt::Debug for Label {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Job {
+impl ::protobuf::reflect::ProtobufValue for Label {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobSubmission {
+pub struct ResourceLimits {
     // message fields
-    pub user_id: ::std::string::String,
-    pub jobs: ::protobuf::RepeatedField<Job>,
+    pub memory_mb: i32,
+    pub cpu_percent: i32,
+    pub open_files: i32,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl JobSubmission {
-    pub fn new() -> JobSubmission {
+impl ResourceLimits {
+    pub fn new() -> ResourceLimits {
         ::std::default::Default::default()
     }
 
-    // string user_id = 1;
+    // int32 memory_mb = 1;
 
-    pub fn clear_user_id(&mut self) {
-        self.user_id.clear();
+    pub fn clear_memory_mb(&mut self) {
+        self.memory_mb = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_user_id(&mut self, v: ::std::string::String) {
-        self.user_id = v;
+    pub fn set_memory_mb(&mut self, v: i32) {
+        self.memory_mb = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
-        &mut self.user_id
+    pub fn get_memory_mb(&self) -> i32 {
+        self.memory_mb
     }
 
-    // Take field
-    pub fn take_user_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
-    }
+    // int32 cpu_percent = 2;
 
-    pub fn get_user_id(&self) -> &str {
-        &self.user_id
+    pub fn clear_cpu_percent(&mut self) {
+        self.cpu_percent = 0;
     }
 
-    // repeated .Job jobs = 2;
-
-    pub fn clear_jobs(&mut self) {
-        self.jobs.clear();
+    // Param is passed by value, moved
+    pub fn set_cpu_percent(&mut self, v: i32) {
+        self.cpu_percent = v;
     }
 
-    // Param is passed by value, moved
-    pub fn set_jobs(&mut self, v: ::protobuf::RepeatedField<Job>) {
-        self.jobs = v;
+    pub fn get_cpu_percent(&self) -> i32 {
+        self.cpu_percent
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_jobs(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
-        &mut self.jobs
+    // int32 open_files = 3;
+
+    pub fn clear_open_files(&mut self) {
+        self.open_files = 0;
     }
 
-    // Take field
-    pub fn take_jobs(&mut self) -> ::protobuf::RepeatedField<Job> {
-        ::std::mem::replace(&mut self.jobs, ::protobuf::RepeatedField::new())
+    // Param is passed by value, moved
+    pub fn set_open_files(&mut self, v: i32) {
+        self.open_files = v;
     }
 
-    pub fn get_jobs(&self) -> &[Job] {
-        &self.jobs
+    pub fn get_open_files(&self) -> i32 {
+        self.open_files
     }
 }
 
-impl ::protobuf::Message for JobSubmission {
+impl ::protobuf::Message for ResourceLimits {
     fn is_initialized(&self) -> bool {
-        for v in &self.jobs {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -1236,10 +1265,25 @@ impl ::protobuf::Message for JobSubmission {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.memory_mb = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.jobs)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.cpu_percent = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.open_files = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1253,27 +1297,30 @@ impl ::protobuf::Message for JobSubmission {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.user_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        if self.memory_mb != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.memory_mb, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_percent != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cpu_percent, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.open_files != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.open_files, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.jobs {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.user_id.is_empty() {
-            os.write_string(1, &self.user_id)?;
+        if self.memory_mb != 0 {
+            os.write_int32(1, self.memory_mb)?;
+        }
+        if self.cpu_percent != 0 {
+            os.write_int32(2, self.cpu_percent)?;
+        }
+        if self.open_files != 0 {
+            os.write_int32(3, self.open_files)?;
         }
-        for v in &self.jobs {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1304,8 +1351,8 @@ impl ::protobuf::Message for JobSubmission {
         Self::descriptor_static()
     }
 
-    fn new() -> JobSubmission {
-        JobSubmission::new()
+    fn new() -> ResourceLimits {
+        ResourceLimits::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1316,18 +1363,23 @@ impl ::protobuf::Message for JobSubmission {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "user_id",
-                    |m: &JobSubmission| { &m.user_id },
-                    |m: &mut JobSubmission| { &mut m.user_id },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "memory_mb",
+                    |m: &ResourceLimits| { &m.memory_mb },
+                    |m: &mut ResourceLimits| { &mut m.memory_mb },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Job>>(
-                    "jobs",
-                    |m: &JobSubmission| { &m.jobs },
-                    |m: &mut JobSubmission| { &mut m.jobs },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "cpu_percent",
+                    |m: &ResourceLimits| { &m.cpu_percent },
+                    |m: &mut ResourceLimits| { &mut m.cpu_percent },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobSubmission>(
-                    "JobSubmission",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "open_files",
+                    |m: &ResourceLimits| { &m.open_files },
+                    |m: &mut ResourceLimits| { &mut m.open_files },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResourceLimits>(
+                    "ResourceLimits",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1335,68 +1387,80 @@ impl ::protobuf::Message for JobSubmission {
         }
     }
 
-    fn default_instance() -> &'static JobSubmission {
-        static mut instance: ::protobuf::lazy::Lazy<JobSubmission> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ResourceLimits {
+        static mut instance: ::protobuf::lazy::Lazy<ResourceLimits> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobSubmission,
+            ptr: 0 as *const ResourceLimits,
         };
         unsafe {
-            instance.get(JobSubmission::new)
+            instance.get(ResourceLimits::new)
         }
     }
 }
 
-impl ::protobuf::Clear for JobSubmission {
+impl ::protobuf::Clear for ResourceLimits {
     fn clear(&mut self) {
-        self.clear_user_id();
-        self.clear_jobs();
+        self.clear_memory_mb();
+        self.clear_cpu_percent();
+        self.clear_open_files();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobSubmission {
+impl ::std::fmt::Debug for ResourceLimits {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobSubmission {
+impl ::protobuf::reflect::ProtobufValue for ResourceLimits {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobSubmissionResponse {
+pub struct OutputSink {
     // message fields
-    pub job_accepted: bool,
+    pub path: ::std::string::String,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl JobSubmissionResponse {
-    pub fn new() -> JobSubmissionResponse {
+impl OutputSink {
+    pub fn new() -> OutputSink {
         ::std::default::Default::default()
     }
 
-    // bool job_accepted = 1;
+    // string path = 1;
 
-    pub fn clear_job_accepted(&mut self) {
-        self.job_accepted = false;
+    pub fn clear_path(&mut self) {
+        self.path.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_job_accepted(&mut self, v: bool) {
-        self.job_accepted = v;
+    pub fn set_path(&mut self, v: ::std::string::String) {
+        self.path = v;
     }
 
-    pub fn get_job_accepted(&self) -> bool {
-        self.job_accepted
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_path(&mut self) -> &mut ::std::string::String {
+        &mut self.path
+    }
+
+    // Take field
+    pub fn take_path(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.path, ::std::string::String::new())
+    }
+
+    pub fn get_path(&self) -> &str {
+        &self.path
     }
 }
 
-impl ::protobuf::Message for JobSubmissionResponse {
+impl ::protobuf::Message for OutputSink {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1406,11 +1470,7 @@ impl ::protobuf::Message for JobSubmissionResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.job_accepted = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.path)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1424,8 +1484,8 @@ impl ::protobuf::Message for JobSubmissionResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.job_accepted != false {
-            my_size += 2;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1433,8 +1493,8 @@ impl ::protobuf::Message for JobSubmissionResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.job_accepted != false {
-            os.write_bool(1, self.job_accepted)?;
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1466,8 +1526,8 @@ impl ::protobuf::Message for JobSubmissionResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> JobSubmissionResponse {
-        JobSubmissionResponse::new()
+    fn new() -> OutputSink {
+        OutputSink::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1478,13 +1538,13 @@ impl ::protobuf::Message for JobSubmissionResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "job_accepted",
-                    |m: &JobSubmissionResponse| { &m.job_accepted },
-                    |m: &mut JobSubmissionResponse| { &mut m.job_accepted },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "path",
+                    |m: &OutputSink| { &m.path },
+                    |m: &mut OutputSink| { &mut m.path },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobSubmissionResponse>(
-                    "JobSubmissionResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<OutputSink>(
+                    "OutputSink",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1492,78 +1552,221 @@ impl ::protobuf::Message for JobSubmissionResponse {
         }
     }
 
-    fn default_instance() -> &'static JobSubmissionResponse {
-        static mut instance: ::protobuf::lazy::Lazy<JobSubmissionResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static OutputSink {
+        static mut instance: ::protobuf::lazy::Lazy<OutputSink> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobSubmissionResponse,
+            ptr: 0 as *const OutputSink,
         };
         unsafe {
-            instance.get(JobSubmissionResponse::new)
+            instance.get(OutputSink::new)
         }
     }
 }
 
-impl ::protobuf::Clear for JobSubmissionResponse {
+impl ::protobuf::Clear for OutputSink {
     fn clear(&mut self) {
-        self.clear_job_accepted();
+        self.clear_path();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobSubmissionResponse {
+impl ::std::fmt::Debug for OutputSink {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobSubmissionResponse {
+impl ::protobuf::reflect::ProtobufValue for OutputSink {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CreateConnectionRequest {
+pub struct MapAction {
     // message fields
-    pub authentication: ::std::string::String,
+    pub mapType: MapAction_MapType,
+    pub job_id_in: i32,
+    pub function_closure: ::std::vec::Vec<u8>,
+    pub required_labels: ::protobuf::RepeatedField<Label>,
+    pub task_timeout: i32,
+    pub limits: ::protobuf::SingularPtrField<ResourceLimits>,
+    pub sink: ::protobuf::SingularPtrField<OutputSink>,
     pub docker_name: ::std::string::String,
+    pub executable: ::protobuf::SingularPtrField<Executable>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl CreateConnectionRequest {
-    pub fn new() -> CreateConnectionRequest {
+impl MapAction {
+    pub fn new() -> MapAction {
         ::std::default::Default::default()
     }
 
-    // string authentication = 1;
+    // .MapAction.MapType mapType = 1;
 
-    pub fn clear_authentication(&mut self) {
-        self.authentication.clear();
+    pub fn clear_mapType(&mut self) {
+        self.mapType = MapAction_MapType::SINGLE_IN_MULTI_OUT;
     }
 
     // Param is passed by value, moved
-    pub fn set_authentication(&mut self, v: ::std::string::String) {
-        self.authentication = v;
+    pub fn set_mapType(&mut self, v: MapAction_MapType) {
+        self.mapType = v;
+    }
+
+    pub fn get_mapType(&self) -> MapAction_MapType {
+        self.mapType
+    }
+
+    // int32 job_id_in = 2;
+
+    pub fn clear_job_id_in(&mut self) {
+        self.job_id_in = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id_in(&mut self, v: i32) {
+        self.job_id_in = v;
+    }
+
+    pub fn get_job_id_in(&self) -> i32 {
+        self.job_id_in
+    }
+
+    // bytes function_closure = 3;
+
+    pub fn clear_function_closure(&mut self) {
+        self.function_closure.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_function_closure(&mut self, v: ::std::vec::Vec<u8>) {
+        self.function_closure = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_authentication(&mut self) -> &mut ::std::string::String {
-        &mut self.authentication
+    pub fn mut_function_closure(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.function_closure
     }
 
     // Take field
-    pub fn take_authentication(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.authentication, ::std::string::String::new())
+    pub fn take_function_closure(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.function_closure, ::std::vec::Vec::new())
     }
 
-    pub fn get_authentication(&self) -> &str {
-        &self.authentication
+    pub fn get_function_closure(&self) -> &[u8] {
+        &self.function_closure
     }
 
-    // string docker_name = 2;
+    // repeated .Label required_labels = 4;
+
+    pub fn clear_required_labels(&mut self) {
+        self.required_labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_required_labels(&mut self, v: ::protobuf::RepeatedField<Label>) {
+        self.required_labels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_required_labels(&mut self) -> &mut ::protobuf::RepeatedField<Label> {
+        &mut self.required_labels
+    }
+
+    // Take field
+    pub fn take_required_labels(&mut self) -> ::protobuf::RepeatedField<Label> {
+        ::std::mem::replace(&mut self.required_labels, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_required_labels(&self) -> &[Label] {
+        &self.required_labels
+    }
+
+    // int32 task_timeout = 5;
+
+    pub fn clear_task_timeout(&mut self) {
+        self.task_timeout = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_timeout(&mut self, v: i32) {
+        self.task_timeout = v;
+    }
+
+    pub fn get_task_timeout(&self) -> i32 {
+        self.task_timeout
+    }
+
+    // .ResourceLimits limits = 6;
+
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    pub fn has_limits(&self) -> bool {
+        self.limits.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limits(&mut self, v: ResourceLimits) {
+        self.limits = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_limits(&mut self) -> &mut ResourceLimits {
+        if self.limits.is_none() {
+            self.limits.set_default();
+        }
+        self.limits.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_limits(&mut self) -> ResourceLimits {
+        self.limits.take().unwrap_or_else(|| ResourceLimits::new())
+    }
+
+    pub fn get_limits(&self) -> &ResourceLimits {
+        self.limits.as_ref().unwrap_or_else(|| ResourceLimits::default_instance())
+    }
+
+    // .OutputSink sink = 7;
+
+    pub fn clear_sink(&mut self) {
+        self.sink.clear();
+    }
+
+    pub fn has_sink(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sink(&mut self, v: OutputSink) {
+        self.sink = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sink(&mut self) -> &mut OutputSink {
+        if self.sink.is_none() {
+            self.sink.set_default();
+        }
+        self.sink.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_sink(&mut self) -> OutputSink {
+        self.sink.take().unwrap_or_else(|| OutputSink::new())
+    }
+
+    pub fn get_sink(&self) -> &OutputSink {
+        self.sink.as_ref().unwrap_or_else(|| OutputSink::default_instance())
+    }
+
+    // string docker_name = 8;
 
     pub fn clear_docker_name(&mut self) {
         self.docker_name.clear();
@@ -1588,10 +1791,63 @@ impl CreateConnectionRequest {
     pub fn get_docker_name(&self) -> &str {
         &self.docker_name
     }
+
+    // .Executable executable = 9;
+
+    pub fn clear_executable(&mut self) {
+        self.executable.clear();
+    }
+
+    pub fn has_executable(&self) -> bool {
+        self.executable.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executable(&mut self, v: Executable) {
+        self.executable = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_executable(&mut self) -> &mut Executable {
+        if self.executable.is_none() {
+            self.executable.set_default();
+        }
+        self.executable.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_executable(&mut self) -> Executable {
+        self.executable.take().unwrap_or_else(|| Executable::new())
+    }
+
+    pub fn get_executable(&self) -> &Executable {
+        self.executable.as_ref().unwrap_or_else(|| Executable::default_instance())
+    }
 }
 
-impl ::protobuf::Message for CreateConnectionRequest {
+impl ::protobuf::Message for MapAction {
     fn is_initialized(&self) -> bool {
+        for v in &self.required_labels {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.limits {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.sink {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.executable {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1600,11 +1856,40 @@ impl ::protobuf::Message for CreateConnectionRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.authentication)?;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.mapType, 1, &mut self.unknown_fields)?
                 },
                 2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.job_id_in = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.function_closure)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.required_labels)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.task_timeout = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.limits)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.sink)?;
+                },
+                8 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.docker_name)?;
                 },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.executable)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1617,23 +1902,77 @@ impl ::protobuf::Message for CreateConnectionRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.authentication.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.authentication);
+        if self.mapType != MapAction_MapType::SINGLE_IN_MULTI_OUT {
+            my_size += ::protobuf::rt::enum_size(1, self.mapType);
         }
-        if !self.docker_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.docker_name);
+        if self.job_id_in != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.job_id_in, ::protobuf::wire_format::WireTypeVarint);
         }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
-    }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.authentication.is_empty() {
-            os.write_string(1, &self.authentication)?;
+        if !self.function_closure.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.function_closure);
         }
-        if !self.docker_name.is_empty() {
-            os.write_string(2, &self.docker_name)?;
+        for value in &self.required_labels {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.task_timeout != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.task_timeout, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.limits.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.sink.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.docker_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.docker_name);
+        }
+        if let Some(ref v) = self.executable.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.mapType != MapAction_MapType::SINGLE_IN_MULTI_OUT {
+            os.write_enum(1, self.mapType.value())?;
+        }
+        if self.job_id_in != 0 {
+            os.write_int32(2, self.job_id_in)?;
+        }
+        if !self.function_closure.is_empty() {
+            os.write_bytes(3, &self.function_closure)?;
+        }
+        for v in &self.required_labels {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.task_timeout != 0 {
+            os.write_int32(5, self.task_timeout)?;
+        }
+        if let Some(ref v) = self.limits.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.sink.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.docker_name.is_empty() {
+            os.write_string(8, &self.docker_name)?;
+        }
+        if let Some(ref v) = self.executable.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1665,8 +2004,8 @@ impl ::protobuf::Message for CreateConnectionRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> CreateConnectionRequest {
-        CreateConnectionRequest::new()
+    fn new() -> MapAction {
+        MapAction::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1677,18 +2016,53 @@ impl ::protobuf::Message for CreateConnectionRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "authentication",
-                    |m: &CreateConnectionRequest| { &m.authentication },
-                    |m: &mut CreateConnectionRequest| { &mut m.authentication },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<MapAction_MapType>>(
+                    "mapType",
+                    |m: &MapAction| { &m.mapType },
+                    |m: &mut MapAction| { &mut m.mapType },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_id_in",
+                    |m: &MapAction| { &m.job_id_in },
+                    |m: &mut MapAction| { &mut m.job_id_in },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "function_closure",
+                    |m: &MapAction| { &m.function_closure },
+                    |m: &mut MapAction| { &mut m.function_closure },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Label>>(
+                    "required_labels",
+                    |m: &MapAction| { &m.required_labels },
+                    |m: &mut MapAction| { &mut m.required_labels },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "task_timeout",
+                    |m: &MapAction| { &m.task_timeout },
+                    |m: &mut MapAction| { &mut m.task_timeout },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ResourceLimits>>(
+                    "limits",
+                    |m: &MapAction| { &m.limits },
+                    |m: &mut MapAction| { &mut m.limits },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OutputSink>>(
+                    "sink",
+                    |m: &MapAction| { &m.sink },
+                    |m: &mut MapAction| { &mut m.sink },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "docker_name",
-                    |m: &CreateConnectionRequest| { &m.docker_name },
-                    |m: &mut CreateConnectionRequest| { &mut m.docker_name },
+                    |m: &MapAction| { &m.docker_name },
+                    |m: &mut MapAction| { &mut m.docker_name },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CreateConnectionRequest>(
-                    "CreateConnectionRequest",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Executable>>(
+                    "executable",
+                    |m: &MapAction| { &m.executable },
+                    |m: &mut MapAction| { &mut m.executable },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MapAction>(
+                    "MapAction",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1696,95 +2070,136 @@ impl ::protobuf::Message for CreateConnectionRequest {
         }
     }
 
-    fn default_instance() -> &'static CreateConnectionRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CreateConnectionRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static MapAction {
+        static mut instance: ::protobuf::lazy::Lazy<MapAction> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CreateConnectionRequest,
+            ptr: 0 as *const MapAction,
         };
         unsafe {
-            instance.get(CreateConnectionRequest::new)
+            instance.get(MapAction::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CreateConnectionRequest {
+impl ::protobuf::Clear for MapAction {
     fn clear(&mut self) {
-        self.clear_authentication();
+        self.clear_mapType();
+        self.clear_job_id_in();
+        self.clear_function_closure();
+        self.clear_required_labels();
+        self.clear_task_timeout();
+        self.clear_limits();
+        self.clear_sink();
         self.clear_docker_name();
+        self.clear_executable();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CreateConnectionRequest {
+impl ::std::fmt::Debug for MapAction {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CreateConnectionRequest {
+impl ::protobuf::reflect::ProtobufValue for MapAction {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(PartialEq,Clone,Default)]
-pub struct CreateConnectionResponse {
-    // message fields
-    pub user_id: ::std::string::String,
-    pub connection_accepted: bool,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum MapAction_MapType {
+    SINGLE_IN_MULTI_OUT = 0,
+    SINGLE_IN_SINGLE_OUT = 1,
+    MULTI_IN_SINGLE_OUT = 2,
+    FLAT_MAP = 3,
 }
 
-impl CreateConnectionResponse {
-    pub fn new() -> CreateConnectionResponse {
-        ::std::default::Default::default()
+impl ::protobuf::ProtobufEnum for MapAction_MapType {
+    fn value(&self) -> i32 {
+        *self as i32
     }
 
-    // string user_id = 1;
+    fn from_i32(value: i32) -> ::std::option::Option<MapAction_MapType> {
+        match value {
+            0 => ::std::option::Option::Some(MapAction_MapType::SINGLE_IN_MULTI_OUT),
+            1 => ::std::option::Option::Some(MapAction_MapType::SINGLE_IN_SINGLE_OUT),
+            2 => ::std::option::Option::Some(MapAction_MapType::MULTI_IN_SINGLE_OUT),
+            3 => ::std::option::Option::Some(MapAction_MapType::FLAT_MAP),
+            _ => ::std::option::Option::None
+        }
+    }
 
-    pub fn clear_user_id(&mut self) {
-        self.user_id.clear();
+    fn values() -> &'static [Self] {
+        static values: &'static [MapAction_MapType] = &[
+            MapAction_MapType::SINGLE_IN_MULTI_OUT,
+            MapAction_MapType::SINGLE_IN_SINGLE_OUT,
+            MapAction_MapType::MULTI_IN_SINGLE_OUT,
+            MapAction_MapType::FLAT_MAP,
+        ];
+        values
     }
 
-    // Param is passed by value, moved
-    pub fn set_user_id(&mut self, v: ::std::string::String) {
-        self.user_id = v;
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("MapAction_MapType", file_descriptor_proto())
+            })
+        }
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
-        &mut self.user_id
+impl ::std::marker::Copy for MapAction_MapType {
+}
+
+impl ::std::default::Default for MapAction_MapType {
+    fn default() -> Self {
+        MapAction_MapType::SINGLE_IN_MULTI_OUT
     }
+}
 
-    // Take field
-    pub fn take_user_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+impl ::protobuf::reflect::ProtobufValue for MapAction_MapType {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
     }
+}
 
-    pub fn get_user_id(&self) -> &str {
-        &self.user_id
+#[derive(PartialEq,Clone,Default)]
+pub struct RepartitionAction {
+    // message fields
+    pub partitions: i32,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl RepartitionAction {
+    pub fn new() -> RepartitionAction {
+        ::std::default::Default::default()
     }
 
-    // bool connection_accepted = 2;
+    // int32 partitions = 1;
 
-    pub fn clear_connection_accepted(&mut self) {
-        self.connection_accepted = false;
+    pub fn clear_partitions(&mut self) {
+        self.partitions = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_connection_accepted(&mut self, v: bool) {
-        self.connection_accepted = v;
+    pub fn set_partitions(&mut self, v: i32) {
+        self.partitions = v;
     }
 
-    pub fn get_connection_accepted(&self) -> bool {
-        self.connection_accepted
+    pub fn get_partitions(&self) -> i32 {
+        self.partitions
     }
 }
 
-impl ::protobuf::Message for CreateConnectionResponse {
+impl ::protobuf::Message for RepartitionAction {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1794,14 +2209,11 @@ impl ::protobuf::Message for CreateConnectionResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.connection_accepted = tmp;
+                    let tmp = is.read_int32()?;
+                    self.partitions = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1815,11 +2227,8 @@ impl ::protobuf::Message for CreateConnectionResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.user_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.user_id);
-        }
-        if self.connection_accepted != false {
-            my_size += 2;
+        if self.partitions != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.partitions, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1827,11 +2236,8 @@ impl ::protobuf::Message for CreateConnectionResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.user_id.is_empty() {
-            os.write_string(1, &self.user_id)?;
-        }
-        if self.connection_accepted != false {
-            os.write_bool(2, self.connection_accepted)?;
+        if self.partitions != 0 {
+            os.write_int32(1, self.partitions)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1863,8 +2269,8 @@ impl ::protobuf::Message for CreateConnectionResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> CreateConnectionResponse {
-        CreateConnectionResponse::new()
+    fn new() -> RepartitionAction {
+        RepartitionAction::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1875,18 +2281,13 @@ impl ::protobuf::Message for CreateConnectionResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "user_id",
-                    |m: &CreateConnectionResponse| { &m.user_id },
-                    |m: &mut CreateConnectionResponse| { &mut m.user_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "connection_accepted",
-                    |m: &CreateConnectionResponse| { &m.connection_accepted },
-                    |m: &mut CreateConnectionResponse| { &mut m.connection_accepted },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "partitions",
+                    |m: &RepartitionAction| { &m.partitions },
+                    |m: &mut RepartitionAction| { &mut m.partitions },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CreateConnectionResponse>(
-                    "CreateConnectionResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<RepartitionAction>(
+                    "RepartitionAction",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1894,107 +2295,69 @@ impl ::protobuf::Message for CreateConnectionResponse {
         }
     }
 
-    fn default_instance() -> &'static CreateConnectionResponse {
-        static mut instance: ::protobuf::lazy::Lazy<CreateConnectionResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static RepartitionAction {
+        static mut instance: ::protobuf::lazy::Lazy<RepartitionAction> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CreateConnectionResponse,
+            ptr: 0 as *const RepartitionAction,
         };
         unsafe {
-            instance.get(CreateConnectionResponse::new)
+            instance.get(RepartitionAction::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CreateConnectionResponse {
+impl ::protobuf::Clear for RepartitionAction {
     fn clear(&mut self) {
-        self.clear_user_id();
-        self.clear_connection_accepted();
+        self.clear_partitions();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CreateConnectionResponse {
+impl ::std::fmt::Debug for RepartitionAction {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CreateConnectionResponse {
+impl ::protobuf::reflect::ProtobufValue for RepartitionAction {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobStatusRequest {
+pub struct CoalesceAction {
     // message fields
-    pub user_id: ::std::string::String,
-    pub job_ids: ::std::vec::Vec<i32>,
+    pub partitions: i32,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl JobStatusRequest {
-    pub fn new() -> JobStatusRequest {
+impl CoalesceAction {
+    pub fn new() -> CoalesceAction {
         ::std::default::Default::default()
     }
 
-    // string user_id = 1;
+    // int32 partitions = 1;
 
-    pub fn clear_user_id(&mut self) {
-        self.user_id.clear();
+    pub fn clear_partitions(&mut self) {
+        self.partitions = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_user_id(&mut self, v: ::std::string::String) {
-        self.user_id = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
-        &mut self.user_id
-    }
-
-    // Take field
-    pub fn take_user_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
-    }
-
-    pub fn get_user_id(&self) -> &str {
-        &self.user_id
+    pub fn set_partitions(&mut self, v: i32) {
+        self.partitions = v;
     }
 
-    // repeated int32 job_ids = 2;
-
-    pub fn clear_job_ids(&mut self) {
-        self.job_ids.clear();
+    pub fn get_partitions(&self) -> i32 {
+        self.partitions
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_job_ids(&mut self, v: ::std::vec::Vec<i32>) {
-        self.job_ids = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_job_ids(&mut self) -> &mut ::std::vec::Vec<i32> {
-        &mut self.job_ids
-    }
-
-    // Take field
-    pub fn take_job_ids(&mut self) -> ::std::vec::Vec<i32> {
-        ::std::mem::replace(&mut self.job_ids, ::std::vec::Vec::new())
-    }
-
-    pub fn get_job_ids(&self) -> &[i32] {
-        &self.job_ids
-    }
-}
-
-impl ::protobuf::Message for JobStatusRequest {
-    fn is_initialized(&self) -> bool {
-        true
+impl ::protobuf::Message for CoalesceAction {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
@@ -2002,10 +2365,11 @@ impl ::protobuf::Message for JobStatusRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.job_ids)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.partitions = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2019,24 +2383,18 @@ impl ::protobuf::Message for JobStatusRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.user_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        if self.partitions != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.partitions, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.job_ids {
-            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.user_id.is_empty() {
-            os.write_string(1, &self.user_id)?;
+        if self.partitions != 0 {
+            os.write_int32(1, self.partitions)?;
         }
-        for v in &self.job_ids {
-            os.write_int32(2, *v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2067,8 +2425,8 @@ impl ::protobuf::Message for JobStatusRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> JobStatusRequest {
-        JobStatusRequest::new()
+    fn new() -> CoalesceAction {
+        CoalesceAction::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2079,18 +2437,13 @@ impl ::protobuf::Message for JobStatusRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "user_id",
-                    |m: &JobStatusRequest| { &m.user_id },
-                    |m: &mut JobStatusRequest| { &mut m.user_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "job_ids",
-                    |m: &JobStatusRequest| { &m.job_ids },
-                    |m: &mut JobStatusRequest| { &mut m.job_ids },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "partitions",
+                    |m: &CoalesceAction| { &m.partitions },
+                    |m: &mut CoalesceAction| { &mut m.partitions },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobStatusRequest>(
-                    "JobStatusRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<CoalesceAction>(
+                    "CoalesceAction",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2098,53 +2451,63 @@ impl ::protobuf::Message for JobStatusRequest {
         }
     }
 
-    fn default_instance() -> &'static JobStatusRequest {
-        static mut instance: ::protobuf::lazy::Lazy<JobStatusRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static CoalesceAction {
+        static mut instance: ::protobuf::lazy::Lazy<CoalesceAction> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobStatusRequest,
+            ptr: 0 as *const CoalesceAction,
         };
         unsafe {
-            instance.get(JobStatusRequest::new)
+            instance.get(CoalesceAction::new)
         }
     }
 }
 
-impl ::protobuf::Clear for JobStatusRequest {
+impl ::protobuf::Clear for CoalesceAction {
     fn clear(&mut self) {
-        self.clear_user_id();
-        self.clear_job_ids();
+        self.clear_partitions();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobStatusRequest {
+impl ::std::fmt::Debug for CoalesceAction {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobStatusRequest {
+impl ::protobuf::reflect::ProtobufValue for CoalesceAction {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobStatus {
+pub struct Job {
     // message fields
     pub job_id: i32,
-    pub status: JobStatus_Status,
+    // message oneof groups
+    pub action: ::std::option::Option<Job_oneof_action>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl JobStatus {
-    pub fn new() -> JobStatus {
+#[derive(Clone,PartialEq)]
+pub enum Job_oneof_action {
+    input(InputAction),
+    map(MapAction),
+    previous_output(PreviousOutputAction),
+    file_input(FileInputAction),
+    repartition(RepartitionAction),
+    coalesce(CoalesceAction),
+}
+
+impl Job {
+    pub fn new() -> Job {
         ::std::default::Default::default()
     }
 
-    // int32 job_id = 2;
+    // int32 job_id = 1;
 
     pub fn clear_job_id(&mut self) {
         self.job_id = 0;
@@ -2159,125 +2522,5530 @@ impl JobStatus {
         self.job_id
     }
 
-    // .JobStatus.Status status = 3;
+    // .InputAction input = 4;
 
-    pub fn clear_status(&mut self) {
-        self.status = JobStatus_Status::BLOCKED;
+    pub fn clear_input(&mut self) {
+        self.action = ::std::option::Option::None;
+    }
+
+    pub fn has_input(&self) -> bool {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::input(..)) => true,
+            _ => false,
+        }
     }
 
     // Param is passed by value, moved
-    pub fn set_status(&mut self, v: JobStatus_Status) {
-        self.status = v;
+    pub fn set_input(&mut self, v: InputAction) {
+        self.action = ::std::option::Option::Some(Job_oneof_action::input(v))
     }
 
-    pub fn get_status(&self) -> JobStatus_Status {
-        self.status
+    // Mutable pointer to the field.
+    pub fn mut_input(&mut self) -> &mut InputAction {
+        if let ::std::option::Option::Some(Job_oneof_action::input(_)) = self.action {
+        } else {
+            self.action = ::std::option::Option::Some(Job_oneof_action::input(InputAction::new()));
+        }
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::input(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
-}
 
-impl ::protobuf::Message for JobStatus {
-    fn is_initialized(&self) -> bool {
-        true
+    // Take field
+    pub fn take_input(&mut self) -> InputAction {
+        if self.has_input() {
+            match self.action.take() {
+                ::std::option::Option::Some(Job_oneof_action::input(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            InputAction::new()
+        }
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int32()?;
-                    self.job_id = tmp;
-                },
-                3 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 3, &mut self.unknown_fields)?
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
+    pub fn get_input(&self) -> &InputAction {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::input(ref v)) => v,
+            _ => InputAction::default_instance(),
         }
-        ::std::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if self.job_id != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.job_id, ::protobuf::wire_format::WireTypeVarint);
+    // .MapAction map = 5;
+
+    pub fn clear_map(&mut self) {
+        self.action = ::std::option::Option::None;
+    }
+
+    pub fn has_map(&self) -> bool {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::map(..)) => true,
+            _ => false,
         }
-        if self.status != JobStatus_Status::BLOCKED {
-            my_size += ::protobuf::rt::enum_size(3, self.status);
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map(&mut self, v: MapAction) {
+        self.action = ::std::option::Option::Some(Job_oneof_action::map(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_map(&mut self) -> &mut MapAction {
+        if let ::std::option::Option::Some(Job_oneof_action::map(_)) = self.action {
+        } else {
+            self.action = ::std::option::Option::Some(Job_oneof_action::map(MapAction::new()));
+        }
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::map(ref mut v)) => v,
+            _ => panic!(),
         }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.job_id != 0 {
-            os.write_int32(2, self.job_id)?;
+    // Take field
+    pub fn take_map(&mut self) -> MapAction {
+        if self.has_map() {
+            match self.action.take() {
+                ::std::option::Option::Some(Job_oneof_action::map(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MapAction::new()
         }
-        if self.status != JobStatus_Status::BLOCKED {
-            os.write_enum(3, self.status.value())?;
+    }
+
+    pub fn get_map(&self) -> &MapAction {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::map(ref v)) => v,
+            _ => MapAction::default_instance(),
         }
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // .PreviousOutputAction previous_output = 6;
+
+    pub fn clear_previous_output(&mut self) {
+        self.action = ::std::option::Option::None;
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    pub fn has_previous_output(&self) -> bool {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::previous_output(..)) => true,
+            _ => false,
+        }
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    // Param is passed by value, moved
+    pub fn set_previous_output(&mut self, v: PreviousOutputAction) {
+        self.action = ::std::option::Option::Some(Job_oneof_action::previous_output(v))
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    // Mutable pointer to the field.
+    pub fn mut_previous_output(&mut self) -> &mut PreviousOutputAction {
+        if let ::std::option::Option::Some(Job_oneof_action::previous_output(_)) = self.action {
+        } else {
+            self.action = ::std::option::Option::Some(Job_oneof_action::previous_output(PreviousOutputAction::new()));
+        }
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::previous_output(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+
+    // Take field
+    pub fn take_previous_output(&mut self) -> PreviousOutputAction {
+        if self.has_previous_output() {
+            match self.action.take() {
+                ::std::option::Option::Some(Job_oneof_action::previous_output(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PreviousOutputAction::new()
+        }
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
-        self
+
+    pub fn get_previous_output(&self) -> &PreviousOutputAction {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::previous_output(ref v)) => v,
+            _ => PreviousOutputAction::default_instance(),
+        }
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
+    // .FileInputAction file_input = 7;
+
+    pub fn clear_file_input(&mut self) {
+        self.action = ::std::option::Option::None;
     }
 
-    fn new() -> JobStatus {
-        JobStatus::new()
+    pub fn has_file_input(&self) -> bool {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::file_input(..)) => true,
+            _ => false,
+        }
     }
 
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "job_id",
-                    |m: &JobStatus| { &m.job_id },
+    // Param is passed by value, moved
+    pub fn set_file_input(&mut self, v: FileInputAction) {
+        self.action = ::std::option::Option::Some(Job_oneof_action::file_input(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_file_input(&mut self) -> &mut FileInputAction {
+        if let ::std::option::Option::Some(Job_oneof_action::file_input(_)) = self.action {
+        } else {
+            self.action = ::std::option::Option::Some(Job_oneof_action::file_input(FileInputAction::new()));
+        }
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::file_input(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_file_input(&mut self) -> FileInputAction {
+        if self.has_file_input() {
+            match self.action.take() {
+                ::std::option::Option::Some(Job_oneof_action::file_input(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileInputAction::new()
+        }
+    }
+
+    pub fn get_file_input(&self) -> &FileInputAction {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::file_input(ref v)) => v,
+            _ => FileInputAction::default_instance(),
+        }
+    }
+
+    // .RepartitionAction repartition = 8;
+
+    pub fn clear_repartition(&mut self) {
+        self.action = ::std::option::Option::None;
+    }
+
+    pub fn has_repartition(&self) -> bool {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::repartition(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_repartition(&mut self, v: RepartitionAction) {
+        self.action = ::std::option::Option::Some(Job_oneof_action::repartition(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_repartition(&mut self) -> &mut RepartitionAction {
+        if let ::std::option::Option::Some(Job_oneof_action::repartition(_)) = self.action {
+        } else {
+            self.action = ::std::option::Option::Some(Job_oneof_action::repartition(RepartitionAction::new()));
+        }
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::repartition(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_repartition(&mut self) -> RepartitionAction {
+        if self.has_repartition() {
+            match self.action.take() {
+                ::std::option::Option::Some(Job_oneof_action::repartition(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RepartitionAction::new()
+        }
+    }
+
+    pub fn get_repartition(&self) -> &RepartitionAction {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::repartition(ref v)) => v,
+            _ => RepartitionAction::default_instance(),
+        }
+    }
+
+    // .CoalesceAction coalesce = 9;
+
+    pub fn clear_coalesce(&mut self) {
+        self.action = ::std::option::Option::None;
+    }
+
+    pub fn has_coalesce(&self) -> bool {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::coalesce(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_coalesce(&mut self, v: CoalesceAction) {
+        self.action = ::std::option::Option::Some(Job_oneof_action::coalesce(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_coalesce(&mut self) -> &mut CoalesceAction {
+        if let ::std::option::Option::Some(Job_oneof_action::coalesce(_)) = self.action {
+        } else {
+            self.action = ::std::option::Option::Some(Job_oneof_action::coalesce(CoalesceAction::new()));
+        }
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::coalesce(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_coalesce(&mut self) -> CoalesceAction {
+        if self.has_coalesce() {
+            match self.action.take() {
+                ::std::option::Option::Some(Job_oneof_action::coalesce(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CoalesceAction::new()
+        }
+    }
+
+    pub fn get_coalesce(&self) -> &CoalesceAction {
+        match self.action {
+            ::std::option::Option::Some(Job_oneof_action::coalesce(ref v)) => v,
+            _ => CoalesceAction::default_instance(),
+        }
+    }
+}
+
+impl ::protobuf::Message for Job {
+    fn is_initialized(&self) -> bool {
+        if let Some(Job_oneof_action::input(ref v)) = self.action {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Job_oneof_action::map(ref v)) = self.action {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Job_oneof_action::previous_output(ref v)) = self.action {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Job_oneof_action::file_input(ref v)) = self.action {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Job_oneof_action::repartition(ref v)) = self.action {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Job_oneof_action::coalesce(ref v)) = self.action {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.job_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.action = ::std::option::Option::Some(Job_oneof_action::input(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.action = ::std::option::Option::Some(Job_oneof_action::map(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.action = ::std::option::Option::Some(Job_oneof_action::previous_output(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.action = ::std::option::Option::Some(Job_oneof_action::file_input(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.action = ::std::option::Option::Some(Job_oneof_action::repartition(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.action = ::std::option::Option::Some(Job_oneof_action::coalesce(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.job_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.job_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.action {
+            match v {
+                &Job_oneof_action::input(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Job_oneof_action::map(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Job_oneof_action::previous_output(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Job_oneof_action::file_input(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Job_oneof_action::repartition(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Job_oneof_action::coalesce(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.job_id != 0 {
+            os.write_int32(1, self.job_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.action {
+            match v {
+                &Job_oneof_action::input(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Job_oneof_action::map(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Job_oneof_action::previous_output(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Job_oneof_action::file_input(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Job_oneof_action::repartition(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Job_oneof_action::coalesce(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Job {
+        Job::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_id",
+                    |m: &Job| { &m.job_id },
+                    |m: &mut Job| { &mut m.job_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, InputAction>(
+                    "input",
+                    Job::has_input,
+                    Job::get_input,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, MapAction>(
+                    "map",
+                    Job::has_map,
+                    Job::get_map,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PreviousOutputAction>(
+                    "previous_output",
+                    Job::has_previous_output,
+                    Job::get_previous_output,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, FileInputAction>(
+                    "file_input",
+                    Job::has_file_input,
+                    Job::get_file_input,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, RepartitionAction>(
+                    "repartition",
+                    Job::has_repartition,
+                    Job::get_repartition,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CoalesceAction>(
+                    "coalesce",
+                    Job::has_coalesce,
+                    Job::get_coalesce,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Job>(
+                    "Job",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Job {
+        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Job,
+        };
+        unsafe {
+            instance.get(Job::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Job {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_input();
+        self.clear_map();
+        self.clear_previous_output();
+        self.clear_file_input();
+        self.clear_repartition();
+        self.clear_coalesce();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Job {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Job {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Broadcast {
+    // message fields
+    pub name: ::std::string::String,
+    pub value: ::std::vec::Vec<u8>,
+    pub digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Broadcast {
+    pub fn new() -> Broadcast {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // bytes value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+
+    // string digest = 3;
+
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
+    }
+
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
+    }
+
+    pub fn get_digest(&self) -> &str {
+        &self.digest
+    }
+}
+
+impl ::protobuf::Message for Broadcast {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        if !self.digest.is_empty() {
+            os.write_string(3, &self.digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Broadcast {
+        Broadcast::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &Broadcast| { &m.name },
+                    |m: &mut Broadcast| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &Broadcast| { &m.value },
+                    |m: &mut Broadcast| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "digest",
+                    |m: &Broadcast| { &m.digest },
+                    |m: &mut Broadcast| { &mut m.digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Broadcast>(
+                    "Broadcast",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Broadcast {
+        static mut instance: ::protobuf::lazy::Lazy<Broadcast> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Broadcast,
+        };
+        unsafe {
+            instance.get(Broadcast::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Broadcast {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_value();
+        self.clear_digest();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Broadcast {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Broadcast {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobSubmission {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub jobs: ::protobuf::RepeatedField<Job>,
+    pub broadcasts: ::protobuf::RepeatedField<Broadcast>,
+    pub idempotency_key: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl JobSubmission {
+    pub fn new() -> JobSubmission {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    // repeated .Job jobs = 2;
+
+    pub fn clear_jobs(&mut self) {
+        self.jobs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jobs(&mut self, v: ::protobuf::RepeatedField<Job>) {
+        self.jobs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_jobs(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
+        &mut self.jobs
+    }
+
+    // Take field
+    pub fn take_jobs(&mut self) -> ::protobuf::RepeatedField<Job> {
+        ::std::mem::replace(&mut self.jobs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    // repeated .Broadcast broadcasts = 3;
+
+    pub fn clear_broadcasts(&mut self) {
+        self.broadcasts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_broadcasts(&mut self, v: ::protobuf::RepeatedField<Broadcast>) {
+        self.broadcasts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_broadcasts(&mut self) -> &mut ::protobuf::RepeatedField<Broadcast> {
+        &mut self.broadcasts
+    }
+
+    // Take field
+    pub fn take_broadcasts(&mut self) -> ::protobuf::RepeatedField<Broadcast> {
+        ::std::mem::replace(&mut self.broadcasts, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_broadcasts(&self) -> &[Broadcast] {
+        &self.broadcasts
+    }
+
+    // string idempotency_key = 4;
+
+    pub fn clear_idempotency_key(&mut self) {
+        self.idempotency_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_idempotency_key(&mut self, v: ::std::string::String) {
+        self.idempotency_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_idempotency_key(&mut self) -> &mut ::std::string::String {
+        &mut self.idempotency_key
+    }
+
+    // Take field
+    pub fn take_idempotency_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.idempotency_key, ::std::string::String::new())
+    }
+
+    pub fn get_idempotency_key(&self) -> &str {
+        &self.idempotency_key
+    }
+}
+
+impl ::protobuf::Message for JobSubmission {
+    fn is_initialized(&self) -> bool {
+        for v in &self.jobs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.broadcasts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.jobs)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.broadcasts)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.idempotency_key)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        for value in &self.jobs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.broadcasts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.idempotency_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.idempotency_key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        for v in &self.jobs {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.broadcasts {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.idempotency_key.is_empty() {
+            os.write_string(4, &self.idempotency_key)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JobSubmission {
+        JobSubmission::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "user_id",
+                    |m: &JobSubmission| { &m.user_id },
+                    |m: &mut JobSubmission| { &mut m.user_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Job>>(
+                    "jobs",
+                    |m: &JobSubmission| { &m.jobs },
+                    |m: &mut JobSubmission| { &mut m.jobs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Broadcast>>(
+                    "broadcasts",
+                    |m: &JobSubmission| { &m.broadcasts },
+                    |m: &mut JobSubmission| { &mut m.broadcasts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "idempotency_key",
+                    |m: &JobSubmission| { &m.idempotency_key },
+                    |m: &mut JobSubmission| { &mut m.idempotency_key },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobSubmission>(
+                    "JobSubmission",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static JobSubmission {
+        static mut instance: ::protobuf::lazy::Lazy<JobSubmission> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobSubmission,
+        };
+        unsafe {
+            instance.get(JobSubmission::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobSubmission {
+    fn clear(&mut self) {
+        self.clear_user_id();
+        self.clear_jobs();
+        self.clear_broadcasts();
+        self.clear_idempotency_key();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobSubmission {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobSubmission {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobSubmissionResponse {
+    // message fields
+    pub job_accepted: bool,
+    pub error: ::std::string::String,
+    pub conflict: bool,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl JobSubmissionResponse {
+    pub fn new() -> JobSubmissionResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool job_accepted = 1;
+
+    pub fn clear_job_accepted(&mut self) {
+        self.job_accepted = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_accepted(&mut self, v: bool) {
+        self.job_accepted = v;
+    }
+
+    pub fn get_job_accepted(&self) -> bool {
+        self.job_accepted
+    }
+
+    // string error = 2;
+
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+
+    // bool conflict = 3;
+
+    pub fn clear_conflict(&mut self) {
+        self.conflict = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_conflict(&mut self, v: bool) {
+        self.conflict = v;
+    }
+
+    pub fn get_conflict(&self) -> bool {
+        self.conflict
+    }
+}
+
+impl ::protobuf::Message for JobSubmissionResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.job_accepted = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.conflict = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.job_accepted != false {
+            my_size += 2;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        if self.conflict != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.job_accepted != false {
+            os.write_bool(1, self.job_accepted)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        if self.conflict != false {
+            os.write_bool(3, self.conflict)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JobSubmissionResponse {
+        JobSubmissionResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "job_accepted",
+                    |m: &JobSubmissionResponse| { &m.job_accepted },
+                    |m: &mut JobSubmissionResponse| { &mut m.job_accepted },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &JobSubmissionResponse| { &m.error },
+                    |m: &mut JobSubmissionResponse| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "conflict",
+                    |m: &JobSubmissionResponse| { &m.conflict },
+                    |m: &mut JobSubmissionResponse| { &mut m.conflict },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobSubmissionResponse>(
+                    "JobSubmissionResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static JobSubmissionResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobSubmissionResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobSubmissionResponse,
+        };
+        unsafe {
+            instance.get(JobSubmissionResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobSubmissionResponse {
+    fn clear(&mut self) {
+        self.clear_job_accepted();
+        self.clear_error();
+        self.clear_conflict();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobSubmissionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobSubmissionResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EnvironmentVariable {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl EnvironmentVariable {
+    pub fn new() -> EnvironmentVariable {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    // string value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl ::protobuf::Message for EnvironmentVariable {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EnvironmentVariable {
+        EnvironmentVariable::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &EnvironmentVariable| { &m.key },
+                    |m: &mut EnvironmentVariable| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &EnvironmentVariable| { &m.value },
+                    |m: &mut EnvironmentVariable| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<EnvironmentVariable>(
+                    "EnvironmentVariable",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static EnvironmentVariable {
+        static mut instance: ::protobuf::lazy::Lazy<EnvironmentVariable> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const EnvironmentVariable,
+        };
+        unsafe {
+            instance.get(EnvironmentVariable::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for EnvironmentVariable {
+    fn clear(&mut self) {
+        self.clear_key();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EnvironmentVariable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EnvironmentVariable {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Executable {
+    // message fields
+    pub name: ::std::string::String,
+    pub args: ::protobuf::RepeatedField<::std::string::String>,
+    pub env: ::protobuf::RepeatedField<EnvironmentVariable>,
+    pub artifact: ::std::vec::Vec<u8>,
+    pub artifact_digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Executable {
+    pub fn new() -> Executable {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // repeated string args = 2;
+
+    pub fn clear_args(&mut self) {
+        self.args.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_args(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.args = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_args(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.args
+    }
+
+    // Take field
+    pub fn take_args(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.args, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_args(&self) -> &[::std::string::String] {
+        &self.args
+    }
+
+    // repeated .EnvironmentVariable env = 3;
+
+    pub fn clear_env(&mut self) {
+        self.env.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_env(&mut self, v: ::protobuf::RepeatedField<EnvironmentVariable>) {
+        self.env = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_env(&mut self) -> &mut ::protobuf::RepeatedField<EnvironmentVariable> {
+        &mut self.env
+    }
+
+    // Take field
+    pub fn take_env(&mut self) -> ::protobuf::RepeatedField<EnvironmentVariable> {
+        ::std::mem::replace(&mut self.env, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_env(&self) -> &[EnvironmentVariable] {
+        &self.env
+    }
+
+    // bytes artifact = 4;
+
+    pub fn clear_artifact(&mut self) {
+        self.artifact.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact(&mut self, v: ::std::vec::Vec<u8>) {
+        self.artifact = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_artifact(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.artifact
+    }
+
+    // Take field
+    pub fn take_artifact(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.artifact, ::std::vec::Vec::new())
+    }
+
+    pub fn get_artifact(&self) -> &[u8] {
+        &self.artifact
+    }
+
+    // string artifact_digest = 5;
+
+    pub fn clear_artifact_digest(&mut self) {
+        self.artifact_digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact_digest(&mut self, v: ::std::string::String) {
+        self.artifact_digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_artifact_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.artifact_digest
+    }
+
+    // Take field
+    pub fn take_artifact_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.artifact_digest, ::std::string::String::new())
+    }
+
+    pub fn get_artifact_digest(&self) -> &str {
+        &self.artifact_digest
+    }
+}
+
+impl ::protobuf::Message for Executable {
+    fn is_initialized(&self) -> bool {
+        for v in &self.env {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.args)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.env)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.artifact)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.artifact_digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.args {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.env {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.artifact.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.artifact);
+        }
+        if !self.artifact_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.artifact_digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.args {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.env {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.artifact.is_empty() {
+            os.write_bytes(4, &self.artifact)?;
+        }
+        if !self.artifact_digest.is_empty() {
+            os.write_string(5, &self.artifact_digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Executable {
+        Executable::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &Executable| { &m.name },
+                    |m: &mut Executable| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "args",
+                    |m: &Executable| { &m.args },
+                    |m: &mut Executable| { &mut m.args },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnvironmentVariable>>(
+                    "env",
+                    |m: &Executable| { &m.env },
+                    |m: &mut Executable| { &mut m.env },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "artifact",
+                    |m: &Executable| { &m.artifact },
+                    |m: &mut Executable| { &mut m.artifact },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "artifact_digest",
+                    |m: &Executable| { &m.artifact_digest },
+                    |m: &mut Executable| { &mut m.artifact_digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Executable>(
+                    "Executable",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Executable {
+        static mut instance: ::protobuf::lazy::Lazy<Executable> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Executable,
+        };
+        unsafe {
+            instance.get(Executable::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Executable {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_args();
+        self.clear_env();
+        self.clear_artifact();
+        self.clear_artifact_digest();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Executable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Executable {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateConnectionRequest {
+    // message fields
+    pub authentication: ::std::string::String,
+    pub docker_name: ::std::string::String,
+    pub executable: ::protobuf::SingularPtrField<Executable>,
+    pub session_name: ::std::string::String,
+    pub session_secret: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl CreateConnectionRequest {
+    pub fn new() -> CreateConnectionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string authentication = 1;
+
+    pub fn clear_authentication(&mut self) {
+        self.authentication.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_authentication(&mut self, v: ::std::string::String) {
+        self.authentication = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_authentication(&mut self) -> &mut ::std::string::String {
+        &mut self.authentication
+    }
+
+    // Take field
+    pub fn take_authentication(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.authentication, ::std::string::String::new())
+    }
+
+    pub fn get_authentication(&self) -> &str {
+        &self.authentication
+    }
+
+    // string docker_name = 2;
+
+    pub fn clear_docker_name(&mut self) {
+        self.docker_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_docker_name(&mut self, v: ::std::string::String) {
+        self.docker_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_docker_name(&mut self) -> &mut ::std::string::String {
+        &mut self.docker_name
+    }
+
+    // Take field
+    pub fn take_docker_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.docker_name, ::std::string::String::new())
+    }
+
+    pub fn get_docker_name(&self) -> &str {
+        &self.docker_name
+    }
+
+    // .Executable executable = 3;
+
+    pub fn clear_executable(&mut self) {
+        self.executable.clear();
+    }
+
+    pub fn has_executable(&self) -> bool {
+        self.executable.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executable(&mut self, v: Executable) {
+        self.executable = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_executable(&mut self) -> &mut Executable {
+        if self.executable.is_none() {
+            self.executable.set_default();
+        }
+        self.executable.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_executable(&mut self) -> Executable {
+        self.executable.take().unwrap_or_else(|| Executable::new())
+    }
+
+    pub fn get_executable(&self) -> &Executable {
+        self.executable.as_ref().unwrap_or_else(|| Executable::default_instance())
+    }
+
+    // string session_name = 4;
+
+    pub fn clear_session_name(&mut self) {
+        self.session_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_name(&mut self, v: ::std::string::String) {
+        self.session_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_name(&mut self) -> &mut ::std::string::String {
+        &mut self.session_name
+    }
+
+    // Take field
+    pub fn take_session_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_name, ::std::string::String::new())
+    }
+
+    pub fn get_session_name(&self) -> &str {
+        &self.session_name
+    }
+
+    // string session_secret = 5;
+
+    pub fn clear_session_secret(&mut self) {
+        self.session_secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_secret(&mut self, v: ::std::string::String) {
+        self.session_secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.session_secret
+    }
+
+    // Take field
+    pub fn take_session_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_secret, ::std::string::String::new())
+    }
+
+    pub fn get_session_secret(&self) -> &str {
+        &self.session_secret
+    }
+}
+
+impl ::protobuf::Message for CreateConnectionRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.executable {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.authentication)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.docker_name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.executable)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.authentication.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.authentication);
+        }
+        if !self.docker_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.docker_name);
+        }
+        if let Some(ref v) = self.executable.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.session_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.session_name);
+        }
+        if !self.session_secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.session_secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.authentication.is_empty() {
+            os.write_string(1, &self.authentication)?;
+        }
+        if !self.docker_name.is_empty() {
+            os.write_string(2, &self.docker_name)?;
+        }
+        if let Some(ref v) = self.executable.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.session_name.is_empty() {
+            os.write_string(4, &self.session_name)?;
+        }
+        if !self.session_secret.is_empty() {
+            os.write_string(5, &self.session_secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateConnectionRequest {
+        CreateConnectionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "authentication",
+                    |m: &CreateConnectionRequest| { &m.authentication },
+                    |m: &mut CreateConnectionRequest| { &mut m.authentication },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "docker_name",
+                    |m: &CreateConnectionRequest| { &m.docker_name },
+                    |m: &mut CreateConnectionRequest| { &mut m.docker_name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Executable>>(
+                    "executable",
+                    |m: &CreateConnectionRequest| { &m.executable },
+                    |m: &mut CreateConnectionRequest| { &mut m.executable },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "session_name",
+                    |m: &CreateConnectionRequest| { &m.session_name },
+                    |m: &mut CreateConnectionRequest| { &mut m.session_name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "session_secret",
+                    |m: &CreateConnectionRequest| { &m.session_secret },
+                    |m: &mut CreateConnectionRequest| { &mut m.session_secret },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CreateConnectionRequest>(
+                    "CreateConnectionRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CreateConnectionRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CreateConnectionRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CreateConnectionRequest,
+        };
+        unsafe {
+            instance.get(CreateConnectionRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CreateConnectionRequest {
+    fn clear(&mut self) {
+        self.clear_authentication();
+        self.clear_docker_name();
+        self.clear_executable();
+        self.clear_session_name();
+        self.clear_session_secret();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateConnectionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateConnectionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateConnectionResponse {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub connection_accepted: bool,
+    pub artifact_digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl CreateConnectionResponse {
+    pub fn new() -> CreateConnectionResponse {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    // bool connection_accepted = 2;
+
+    pub fn clear_connection_accepted(&mut self) {
+        self.connection_accepted = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_connection_accepted(&mut self, v: bool) {
+        self.connection_accepted = v;
+    }
+
+    pub fn get_connection_accepted(&self) -> bool {
+        self.connection_accepted
+    }
+
+    // string artifact_digest = 3;
+
+    pub fn clear_artifact_digest(&mut self) {
+        self.artifact_digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact_digest(&mut self, v: ::std::string::String) {
+        self.artifact_digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_artifact_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.artifact_digest
+    }
+
+    // Take field
+    pub fn take_artifact_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.artifact_digest, ::std::string::String::new())
+    }
+
+    pub fn get_artifact_digest(&self) -> &str {
+        &self.artifact_digest
+    }
+}
+
+impl ::protobuf::Message for CreateConnectionResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.connection_accepted = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.artifact_digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        if self.connection_accepted != false {
+            my_size += 2;
+        }
+        if !self.artifact_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.artifact_digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        if self.connection_accepted != false {
+            os.write_bool(2, self.connection_accepted)?;
+        }
+        if !self.artifact_digest.is_empty() {
+            os.write_string(3, &self.artifact_digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateConnectionResponse {
+        CreateConnectionResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "user_id",
+                    |m: &CreateConnectionResponse| { &m.user_id },
+                    |m: &mut CreateConnectionResponse| { &mut m.user_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "connection_accepted",
+                    |m: &CreateConnectionResponse| { &m.connection_accepted },
+                    |m: &mut CreateConnectionResponse| { &mut m.connection_accepted },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "artifact_digest",
+                    |m: &CreateConnectionResponse| { &m.artifact_digest },
+                    |m: &mut CreateConnectionResponse| { &mut m.artifact_digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CreateConnectionResponse>(
+                    "CreateConnectionResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CreateConnectionResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CreateConnectionResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CreateConnectionResponse,
+        };
+        unsafe {
+            instance.get(CreateConnectionResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CreateConnectionResponse {
+    fn clear(&mut self) {
+        self.clear_user_id();
+        self.clear_connection_accepted();
+        self.clear_artifact_digest();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateConnectionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateConnectionResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResumeSessionRequest {
+    // message fields
+    pub session_name: ::std::string::String,
+    pub session_secret: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ResumeSessionRequest {
+    pub fn new() -> ResumeSessionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string session_name = 1;
+
+    pub fn clear_session_name(&mut self) {
+        self.session_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_name(&mut self, v: ::std::string::String) {
+        self.session_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_name(&mut self) -> &mut ::std::string::String {
+        &mut self.session_name
+    }
+
+    // Take field
+    pub fn take_session_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_name, ::std::string::String::new())
+    }
+
+    pub fn get_session_name(&self) -> &str {
+        &self.session_name
+    }
+
+    // string session_secret = 2;
+
+    pub fn clear_session_secret(&mut self) {
+        self.session_secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_secret(&mut self, v: ::std::string::String) {
+        self.session_secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.session_secret
+    }
+
+    // Take field
+    pub fn take_session_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_secret, ::std::string::String::new())
+    }
+
+    pub fn get_session_secret(&self) -> &str {
+        &self.session_secret
+    }
+}
+
+impl ::protobuf::Message for ResumeSessionRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.session_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_name);
+        }
+        if !self.session_secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.session_secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.session_name.is_empty() {
+            os.write_string(1, &self.session_name)?;
+        }
+        if !self.session_secret.is_empty() {
+            os.write_string(2, &self.session_secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResumeSessionRequest {
+        ResumeSessionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "session_name",
+                    |m: &ResumeSessionRequest| { &m.session_name },
+                    |m: &mut ResumeSessionRequest| { &mut m.session_name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "session_secret",
+                    |m: &ResumeSessionRequest| { &m.session_secret },
+                    |m: &mut ResumeSessionRequest| { &mut m.session_secret },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResumeSessionRequest>(
+                    "ResumeSessionRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ResumeSessionRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ResumeSessionRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ResumeSessionRequest,
+        };
+        unsafe {
+            instance.get(ResumeSessionRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ResumeSessionRequest {
+    fn clear(&mut self) {
+        self.clear_session_name();
+        self.clear_session_secret();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResumeSessionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResumeSessionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ResumeSessionResponse {
+    // message fields
+    pub session_resumed: bool,
+    pub user_id: ::std::string::String,
+    pub job_ids: ::std::vec::Vec<i32>,
+    pub next_job_id: i32,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl ResumeSessionResponse {
+    pub fn new() -> ResumeSessionResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool session_resumed = 1;
+
+    pub fn clear_session_resumed(&mut self) {
+        self.session_resumed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_resumed(&mut self, v: bool) {
+        self.session_resumed = v;
+    }
+
+    pub fn get_session_resumed(&self) -> bool {
+        self.session_resumed
+    }
+
+    // string user_id = 2;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    // repeated int32 job_ids = 3;
+
+    pub fn clear_job_ids(&mut self) {
+        self.job_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_ids(&mut self, v: ::std::vec::Vec<i32>) {
+        self.job_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_ids(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.job_ids
+    }
+
+    // Take field
+    pub fn take_job_ids(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.job_ids, ::std::vec::Vec::new())
+    }
+
+    pub fn get_job_ids(&self) -> &[i32] {
+        &self.job_ids
+    }
+
+    // int32 next_job_id = 4;
+
+    pub fn clear_next_job_id(&mut self) {
+        self.next_job_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_job_id(&mut self, v: i32) {
+        self.next_job_id = v;
+    }
+
+    pub fn get_next_job_id(&self) -> i32 {
+        self.next_job_id
+    }
+}
+
+impl ::protobuf::Message for ResumeSessionResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.session_resumed = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.job_ids)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.next_job_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.session_resumed != false {
+            my_size += 2;
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        for value in &self.job_ids {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.next_job_id != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.next_job_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.session_resumed != false {
+            os.write_bool(1, self.session_resumed)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        for v in &self.job_ids {
+            os.write_int32(3, *v)?;
+        };
+        if self.next_job_id != 0 {
+            os.write_int32(4, self.next_job_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ResumeSessionResponse {
+        ResumeSessionResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "session_resumed",
+                    |m: &ResumeSessionResponse| { &m.session_resumed },
+                    |m: &mut ResumeSessionResponse| { &mut m.session_resumed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "user_id",
+                    |m: &ResumeSessionResponse| { &m.user_id },
+                    |m: &mut ResumeSessionResponse| { &mut m.user_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_ids",
+                    |m: &ResumeSessionResponse| { &m.job_ids },
+                    |m: &mut ResumeSessionResponse| { &mut m.job_ids },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "next_job_id",
+                    |m: &ResumeSessionResponse| { &m.next_job_id },
+                    |m: &mut ResumeSessionResponse| { &mut m.next_job_id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ResumeSessionResponse>(
+                    "ResumeSessionResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ResumeSessionResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ResumeSessionResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ResumeSessionResponse,
+        };
+        unsafe {
+            instance.get(ResumeSessionResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ResumeSessionResponse {
+    fn clear(&mut self) {
+        self.clear_session_resumed();
+        self.clear_user_id();
+        self.clear_job_ids();
+        self.clear_next_job_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ResumeSessionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ResumeSessionResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobStatusRequest {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub job_ids: ::std::vec::Vec<i32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl JobStatusRequest {
+    pub fn new() -> JobStatusRequest {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    // repeated int32 job_ids = 2;
+
+    pub fn clear_job_ids(&mut self) {
+        self.job_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_ids(&mut self, v: ::std::vec::Vec<i32>) {
+        self.job_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_ids(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.job_ids
+    }
+
+    // Take field
+    pub fn take_job_ids(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.job_ids, ::std::vec::Vec::new())
+    }
+
+    pub fn get_job_ids(&self) -> &[i32] {
+        &self.job_ids
+    }
+}
+
+impl ::protobuf::Message for JobStatusRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.job_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        for value in &self.job_ids {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        for v in &self.job_ids {
+            os.write_int32(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JobStatusRequest {
+        JobStatusRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "user_id",
+                    |m: &JobStatusRequest| { &m.user_id },
+                    |m: &mut JobStatusRequest| { &mut m.user_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_ids",
+                    |m: &JobStatusRequest| { &m.job_ids },
+                    |m: &mut JobStatusRequest| { &mut m.job_ids },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobStatusRequest>(
+                    "JobStatusRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static JobStatusRequest {
+        static mut instance: ::protobuf::lazy::Lazy<JobStatusRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobStatusRequest,
+        };
+        unsafe {
+            instance.get(JobStatusRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobStatusRequest {
+    fn clear(&mut self) {
+        self.clear_user_id();
+        self.clear_job_ids();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobStatusRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobStatusRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Accumulator {
+    // message fields
+    pub name: ::std::string::String,
+    pub value: f64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl Accumulator {
+    pub fn new() -> Accumulator {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // double value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: f64) {
+        self.value = v;
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl ::protobuf::Message for Accumulator {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.value = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.value != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.value != 0. {
+            os.write_double(2, self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Accumulator {
+        Accumulator::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &Accumulator| { &m.name },
+                    |m: &mut Accumulator| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "value",
+                    |m: &Accumulator| { &m.value },
+                    |m: &mut Accumulator| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Accumulator>(
+                    "Accumulator",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Accumulator {
+        static mut instance: ::protobuf::lazy::Lazy<Accumulator> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Accumulator,
+        };
+        unsafe {
+            instance.get(Accumulator::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Accumulator {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Accumulator {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Accumulator {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobStatus {
+    // message fields
+    pub job_id: i32,
+    pub status: JobStatus_Status,
+    pub completed_tasks: i32,
+    pub total_tasks: i32,
+    pub progress: f32,
+    pub message: ::std::string::String,
+    pub accumulators: ::protobuf::RepeatedField<Accumulator>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl JobStatus {
+    pub fn new() -> JobStatus {
+        ::std::default::Default::default()
+    }
+
+    // int32 job_id = 2;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: i32) {
+        self.job_id = v;
+    }
+
+    pub fn get_job_id(&self) -> i32 {
+        self.job_id
+    }
+
+    // .JobStatus.Status status = 3;
+
+    pub fn clear_status(&mut self) {
+        self.status = JobStatus_Status::BLOCKED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: JobStatus_Status) {
+        self.status = v;
+    }
+
+    pub fn get_status(&self) -> JobStatus_Status {
+        self.status
+    }
+
+    // int32 completed_tasks = 4;
+
+    pub fn clear_completed_tasks(&mut self) {
+        self.completed_tasks = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_completed_tasks(&mut self, v: i32) {
+        self.completed_tasks = v;
+    }
+
+    pub fn get_completed_tasks(&self) -> i32 {
+        self.completed_tasks
+    }
+
+    // int32 total_tasks = 5;
+
+    pub fn clear_total_tasks(&mut self) {
+        self.total_tasks = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_total_tasks(&mut self, v: i32) {
+        self.total_tasks = v;
+    }
+
+    pub fn get_total_tasks(&self) -> i32 {
+        self.total_tasks
+    }
+
+    // float progress = 6;
+
+    pub fn clear_progress(&mut self) {
+        self.progress = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress(&mut self, v: f32) {
+        self.progress = v;
+    }
+
+    pub fn get_progress(&self) -> f32 {
+        self.progress
+    }
+
+    // string message = 7;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    // repeated .Accumulator accumulators = 8;
+
+    pub fn clear_accumulators(&mut self) {
+        self.accumulators.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accumulators(&mut self, v: ::protobuf::RepeatedField<Accumulator>) {
+        self.accumulators = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accumulators(&mut self) -> &mut ::protobuf::RepeatedField<Accumulator> {
+        &mut self.accumulators
+    }
+
+    // Take field
+    pub fn take_accumulators(&mut self) -> ::protobuf::RepeatedField<Accumulator> {
+        ::std::mem::replace(&mut self.accumulators, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_accumulators(&self) -> &[Accumulator] {
+        &self.accumulators
+    }
+}
+
+impl ::protobuf::Message for JobStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.accumulators {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.job_id = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.completed_tasks = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.total_tasks = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.progress = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accumulators)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.job_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.job_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.status != JobStatus_Status::BLOCKED {
+            my_size += ::protobuf::rt::enum_size(3, self.status);
+        }
+        if self.completed_tasks != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.completed_tasks, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.total_tasks != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.total_tasks, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.progress != 0. {
+            my_size += 5;
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.message);
+        }
+        for value in &self.accumulators {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.job_id != 0 {
+            os.write_int32(2, self.job_id)?;
+        }
+        if self.status != JobStatus_Status::BLOCKED {
+            os.write_enum(3, self.status.value())?;
+        }
+        if self.completed_tasks != 0 {
+            os.write_int32(4, self.completed_tasks)?;
+        }
+        if self.total_tasks != 0 {
+            os.write_int32(5, self.total_tasks)?;
+        }
+        if self.progress != 0. {
+            os.write_float(6, self.progress)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(7, &self.message)?;
+        }
+        for v in &self.accumulators {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JobStatus {
+        JobStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_id",
+                    |m: &JobStatus| { &m.job_id },
                     |m: &mut JobStatus| { &mut m.job_id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobStatus_Status>>(
-                    "status",
-                    |m: &JobStatus| { &m.status },
-                    |m: &mut JobStatus| { &mut m.status },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobStatus_Status>>(
+                    "status",
+                    |m: &JobStatus| { &m.status },
+                    |m: &mut JobStatus| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "completed_tasks",
+                    |m: &JobStatus| { &m.completed_tasks },
+                    |m: &mut JobStatus| { &mut m.completed_tasks },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "total_tasks",
+                    |m: &JobStatus| { &m.total_tasks },
+                    |m: &mut JobStatus| { &mut m.total_tasks },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                    "progress",
+                    |m: &JobStatus| { &m.progress },
+                    |m: &mut JobStatus| { &mut m.progress },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "message",
+                    |m: &JobStatus| { &m.message },
+                    |m: &mut JobStatus| { &mut m.message },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Accumulator>>(
+                    "accumulators",
+                    |m: &JobStatus| { &m.accumulators },
+                    |m: &mut JobStatus| { &mut m.accumulators },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobStatus>(
+                    "JobStatus",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static JobStatus {
+        static mut instance: ::protobuf::lazy::Lazy<JobStatus> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobStatus,
+        };
+        unsafe {
+            instance.get(JobStatus::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobStatus {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_status();
+        self.clear_completed_tasks();
+        self.clear_total_tasks();
+        self.clear_progress();
+        self.clear_message();
+        self.clear_accumulators();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobStatus_Status {
+    BLOCKED = 0,
+    QUEUED = 1,
+    RUNNING = 2,
+    COMPLETED = 4,
+    HALTED = 5,
+    CANCELLED = 6,
+    UNSCHEDULABLE = 7,
+    TIMED_OUT = 8,
+    OUT_OF_MEMORY = 9,
+}
+
+impl ::protobuf::ProtobufEnum for JobStatus_Status {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobStatus_Status> {
+        match value {
+            0 => ::std::option::Option::Some(JobStatus_Status::BLOCKED),
+            1 => ::std::option::Option::Some(JobStatus_Status::QUEUED),
+            2 => ::std::option::Option::Some(JobStatus_Status::RUNNING),
+            4 => ::std::option::Option::Some(JobStatus_Status::COMPLETED),
+            5 => ::std::option::Option::Some(JobStatus_Status::HALTED),
+            6 => ::std::option::Option::Some(JobStatus_Status::CANCELLED),
+            7 => ::std::option::Option::Some(JobStatus_Status::UNSCHEDULABLE),
+            8 => ::std::option::Option::Some(JobStatus_Status::TIMED_OUT),
+            9 => ::std::option::Option::Some(JobStatus_Status::OUT_OF_MEMORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobStatus_Status] = &[
+            JobStatus_Status::BLOCKED,
+            JobStatus_Status::QUEUED,
+            JobStatus_Status::RUNNING,
+            JobStatus_Status::COMPLETED,
+            JobStatus_Status::HALTED,
+            JobStatus_Status::CANCELLED,
+            JobStatus_Status::UNSCHEDULABLE,
+            JobStatus_Status::TIMED_OUT,
+            JobStatus_Status::OUT_OF_MEMORY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobStatus_Status", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobStatus_Status {
+}
+
+impl ::std::default::Default for JobStatus_Status {
+    fn default() -> Self {
+        JobStatus_Status::BLOCKED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobStatus_Status {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobStatusResponse {
+    // message fields
+    pub job_statuses: ::protobuf::RepeatedField<JobStatus>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl JobStatusResponse {
+    pub fn new() -> JobStatusResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .JobStatus job_statuses = 1;
+
+    pub fn clear_job_statuses(&mut self) {
+        self.job_statuses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_statuses(&mut self, v: ::protobuf::RepeatedField<JobStatus>) {
+        self.job_statuses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_statuses(&mut self) -> &mut ::protobuf::RepeatedField<JobStatus> {
+        &mut self.job_statuses
+    }
+
+    // Take field
+    pub fn take_job_statuses(&mut self) -> ::protobuf::RepeatedField<JobStatus> {
+        ::std::mem::replace(&mut self.job_statuses, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_job_statuses(&self) -> &[JobStatus] {
+        &self.job_statuses
+    }
+}
+
+impl ::protobuf::Message for JobStatusResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.job_statuses {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.job_statuses)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.job_statuses {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.job_statuses {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> JobStatusResponse {
+        JobStatusResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobStatus>>(
+                    "job_statuses",
+                    |m: &JobStatusResponse| { &m.job_statuses },
+                    |m: &mut JobStatusResponse| { &mut m.job_statuses },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobStatusResponse>(
+                    "JobStatusResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static JobStatusResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobStatusResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobStatusResponse,
+        };
+        unsafe {
+            instance.get(JobStatusResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobStatusResponse {
+    fn clear(&mut self) {
+        self.clear_job_statuses();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobStatusResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobStatusResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WatchJobsRequest {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub job_ids: ::std::vec::Vec<i32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WatchJobsRequest {
+    pub fn new() -> WatchJobsRequest {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    // repeated int32 job_ids = 2;
+
+    pub fn clear_job_ids(&mut self) {
+        self.job_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_ids(&mut self, v: ::std::vec::Vec<i32>) {
+        self.job_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_ids(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.job_ids
+    }
+
+    // Take field
+    pub fn take_job_ids(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.job_ids, ::std::vec::Vec::new())
+    }
+
+    pub fn get_job_ids(&self) -> &[i32] {
+        &self.job_ids
+    }
+}
+
+impl ::protobuf::Message for WatchJobsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.job_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        for value in &self.job_ids {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        for v in &self.job_ids {
+            os.write_int32(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WatchJobsRequest {
+        WatchJobsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "user_id",
+                    |m: &WatchJobsRequest| { &m.user_id },
+                    |m: &mut WatchJobsRequest| { &mut m.user_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_ids",
+                    |m: &WatchJobsRequest| { &m.job_ids },
+                    |m: &mut WatchJobsRequest| { &mut m.job_ids },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WatchJobsRequest>(
+                    "WatchJobsRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WatchJobsRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WatchJobsRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WatchJobsRequest,
+        };
+        unsafe {
+            instance.get(WatchJobsRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WatchJobsRequest {
+    fn clear(&mut self) {
+        self.clear_user_id();
+        self.clear_job_ids();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WatchJobsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WatchJobsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WatchJobsResponse {
+    // message fields
+    pub job_statuses: ::protobuf::RepeatedField<JobStatus>,
+    pub finished: bool,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WatchJobsResponse {
+    pub fn new() -> WatchJobsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .JobStatus job_statuses = 1;
+
+    pub fn clear_job_statuses(&mut self) {
+        self.job_statuses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_statuses(&mut self, v: ::protobuf::RepeatedField<JobStatus>) {
+        self.job_statuses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_statuses(&mut self) -> &mut ::protobuf::RepeatedField<JobStatus> {
+        &mut self.job_statuses
+    }
+
+    // Take field
+    pub fn take_job_statuses(&mut self) -> ::protobuf::RepeatedField<JobStatus> {
+        ::std::mem::replace(&mut self.job_statuses, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_job_statuses(&self) -> &[JobStatus] {
+        &self.job_statuses
+    }
+
+    // bool finished = 2;
+
+    pub fn clear_finished(&mut self) {
+        self.finished = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_finished(&mut self, v: bool) {
+        self.finished = v;
+    }
+
+    pub fn get_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl ::protobuf::Message for WatchJobsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.job_statuses {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.job_statuses)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.finished = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.job_statuses {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.finished != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.job_statuses {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.finished != false {
+            os.write_bool(2, self.finished)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WatchJobsResponse {
+        WatchJobsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobStatus>>(
+                    "job_statuses",
+                    |m: &WatchJobsResponse| { &m.job_statuses },
+                    |m: &mut WatchJobsResponse| { &mut m.job_statuses },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "finished",
+                    |m: &WatchJobsResponse| { &m.finished },
+                    |m: &mut WatchJobsResponse| { &mut m.finished },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WatchJobsResponse>(
+                    "WatchJobsResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WatchJobsResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WatchJobsResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WatchJobsResponse,
+        };
+        unsafe {
+            instance.get(WatchJobsResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WatchJobsResponse {
+    fn clear(&mut self) {
+        self.clear_job_statuses();
+        self.clear_finished();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WatchJobsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WatchJobsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DescribeJobRequest {
+    // message fields
+    pub user_id: ::std::string::String,
+    pub job_id: i32,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl DescribeJobRequest {
+    pub fn new() -> DescribeJobRequest {
+        ::std::default::Default::default()
+    }
+
+    // string user_id = 1;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    // int32 job_id = 2;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: i32) {
+        self.job_id = v;
+    }
+
+    pub fn get_job_id(&self) -> i32 {
+        self.job_id
+    }
+}
+
+impl ::protobuf::Message for DescribeJobRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.job_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.user_id);
+        }
+        if self.job_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.job_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.user_id.is_empty() {
+            os.write_string(1, &self.user_id)?;
+        }
+        if self.job_id != 0 {
+            os.write_int32(2, self.job_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DescribeJobRequest {
+        DescribeJobRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "user_id",
+                    |m: &DescribeJobRequest| { &m.user_id },
+                    |m: &mut DescribeJobRequest| { &mut m.user_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "job_id",
+                    |m: &DescribeJobRequest| { &m.job_id },
+                    |m: &mut DescribeJobRequest| { &mut m.job_id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DescribeJobRequest>(
+                    "DescribeJobRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DescribeJobRequest {
+        static mut instance: ::protobuf::lazy::Lazy<DescribeJobRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DescribeJobRequest,
+        };
+        unsafe {
+            instance.get(DescribeJobRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DescribeJobRequest {
+    fn clear(&mut self) {
+        self.clear_user_id();
+        self.clear_job_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DescribeJobRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DescribeJobRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TaskDescription {
+    // message fields
+    pub task_id: ::std::string::String,
+    pub status: TaskDescription_Status,
+    pub worker_id: ::std::string::String,
+    pub attempts: i32,
+    pub started_at: u64,
+    pub finished_at: u64,
+    pub input_size: u64,
+    pub output_size: u64,
+    pub failure_reason: ::std::string::String,
+    pub progress: f32,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl TaskDescription {
+    pub fn new() -> TaskDescription {
+        ::std::default::Default::default()
+    }
+
+    // string task_id = 1;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: ::std::string::String) {
+        self.task_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_task_id(&mut self) -> &mut ::std::string::String {
+        &mut self.task_id
+    }
+
+    // Take field
+    pub fn take_task_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.task_id, ::std::string::String::new())
+    }
+
+    pub fn get_task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    // .TaskDescription.Status status = 2;
+
+    pub fn clear_status(&mut self) {
+        self.status = TaskDescription_Status::AWAITING;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: TaskDescription_Status) {
+        self.status = v;
+    }
+
+    pub fn get_status(&self) -> TaskDescription_Status {
+        self.status
+    }
+
+    // string worker_id = 3;
+
+    pub fn clear_worker_id(&mut self) {
+        self.worker_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker_id(&mut self, v: ::std::string::String) {
+        self.worker_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
+        &mut self.worker_id
+    }
+
+    // Take field
+    pub fn take_worker_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    }
+
+    pub fn get_worker_id(&self) -> &str {
+        &self.worker_id
+    }
+
+    // int32 attempts = 4;
+
+    pub fn clear_attempts(&mut self) {
+        self.attempts = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_attempts(&mut self, v: i32) {
+        self.attempts = v;
+    }
+
+    pub fn get_attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    // uint64 started_at = 5;
+
+    pub fn clear_started_at(&mut self) {
+        self.started_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_started_at(&mut self, v: u64) {
+        self.started_at = v;
+    }
+
+    pub fn get_started_at(&self) -> u64 {
+        self.started_at
+    }
+
+    // uint64 finished_at = 6;
+
+    pub fn clear_finished_at(&mut self) {
+        self.finished_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_finished_at(&mut self, v: u64) {
+        self.finished_at = v;
+    }
+
+    pub fn get_finished_at(&self) -> u64 {
+        self.finished_at
+    }
+
+    // uint64 input_size = 7;
+
+    pub fn clear_input_size(&mut self) {
+        self.input_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input_size(&mut self, v: u64) {
+        self.input_size = v;
+    }
+
+    pub fn get_input_size(&self) -> u64 {
+        self.input_size
+    }
+
+    // uint64 output_size = 8;
+
+    pub fn clear_output_size(&mut self) {
+        self.output_size = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_output_size(&mut self, v: u64) {
+        self.output_size = v;
+    }
+
+    pub fn get_output_size(&self) -> u64 {
+        self.output_size
+    }
+
+    // string failure_reason = 9;
+
+    pub fn clear_failure_reason(&mut self) {
+        self.failure_reason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failure_reason(&mut self, v: ::std::string::String) {
+        self.failure_reason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_failure_reason(&mut self) -> &mut ::std::string::String {
+        &mut self.failure_reason
+    }
+
+    // Take field
+    pub fn take_failure_reason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.failure_reason, ::std::string::String::new())
+    }
+
+    pub fn get_failure_reason(&self) -> &str {
+        &self.failure_reason
+    }
+
+    // float progress = 10;
+
+    pub fn clear_progress(&mut self) {
+        self.progress = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress(&mut self, v: f32) {
+        self.progress = v;
+    }
+
+    pub fn get_progress(&self) -> f32 {
+        self.progress
+    }
+}
+
+impl ::protobuf::Message for TaskDescription {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.task_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.attempts = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.started_at = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.finished_at = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.input_size = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.output_size = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.failure_reason)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.progress = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.task_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.task_id);
+        }
+        if self.status != TaskDescription_Status::AWAITING {
+            my_size += ::protobuf::rt::enum_size(2, self.status);
+        }
+        if !self.worker_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.worker_id);
+        }
+        if self.attempts != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.attempts, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.started_at != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.started_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.finished_at != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.finished_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.input_size != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.input_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.output_size != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.output_size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.failure_reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.failure_reason);
+        }
+        if self.progress != 0. {
+            my_size += 5;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.task_id.is_empty() {
+            os.write_string(1, &self.task_id)?;
+        }
+        if self.status != TaskDescription_Status::AWAITING {
+            os.write_enum(2, self.status.value())?;
+        }
+        if !self.worker_id.is_empty() {
+            os.write_string(3, &self.worker_id)?;
+        }
+        if self.attempts != 0 {
+            os.write_int32(4, self.attempts)?;
+        }
+        if self.started_at != 0 {
+            os.write_uint64(5, self.started_at)?;
+        }
+        if self.finished_at != 0 {
+            os.write_uint64(6, self.finished_at)?;
+        }
+        if self.input_size != 0 {
+            os.write_uint64(7, self.input_size)?;
+        }
+        if self.output_size != 0 {
+            os.write_uint64(8, self.output_size)?;
+        }
+        if !self.failure_reason.is_empty() {
+            os.write_string(9, &self.failure_reason)?;
+        }
+        if self.progress != 0. {
+            os.write_float(10, self.progress)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TaskDescription {
+        TaskDescription::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "task_id",
+                    |m: &TaskDescription| { &m.task_id },
+                    |m: &mut TaskDescription| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<TaskDescription_Status>>(
+                    "status",
+                    |m: &TaskDescription| { &m.status },
+                    |m: &mut TaskDescription| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_id",
+                    |m: &TaskDescription| { &m.worker_id },
+                    |m: &mut TaskDescription| { &mut m.worker_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "attempts",
+                    |m: &TaskDescription| { &m.attempts },
+                    |m: &mut TaskDescription| { &mut m.attempts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "started_at",
+                    |m: &TaskDescription| { &m.started_at },
+                    |m: &mut TaskDescription| { &mut m.started_at },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "finished_at",
+                    |m: &TaskDescription| { &m.finished_at },
+                    |m: &mut TaskDescription| { &mut m.finished_at },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "input_size",
+                    |m: &TaskDescription| { &m.input_size },
+                    |m: &mut TaskDescription| { &mut m.input_size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "output_size",
+                    |m: &TaskDescription| { &m.output_size },
+                    |m: &mut TaskDescription| { &mut m.output_size },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "failure_reason",
+                    |m: &TaskDescription| { &m.failure_reason },
+                    |m: &mut TaskDescription| { &mut m.failure_reason },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                    "progress",
+                    |m: &TaskDescription| { &m.progress },
+                    |m: &mut TaskDescription| { &mut m.progress },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TaskDescription>(
+                    "TaskDescription",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TaskDescription {
+        static mut instance: ::protobuf::lazy::Lazy<TaskDescription> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TaskDescription,
+        };
+        unsafe {
+            instance.get(TaskDescription::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TaskDescription {
+    fn clear(&mut self) {
+        self.clear_task_id();
+        self.clear_status();
+        self.clear_worker_id();
+        self.clear_attempts();
+        self.clear_started_at();
+        self.clear_finished_at();
+        self.clear_input_size();
+        self.clear_output_size();
+        self.clear_failure_reason();
+        self.clear_progress();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TaskDescription {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TaskDescription {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum TaskDescription_Status {
+    AWAITING = 0,
+    RUNNING = 1,
+    COMPLETED = 2,
+    HALTED = 3,
+    CANCELLED = 4,
+    TIMED_OUT = 5,
+    OUT_OF_MEMORY = 6,
+}
+
+impl ::protobuf::ProtobufEnum for TaskDescription_Status {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<TaskDescription_Status> {
+        match value {
+            0 => ::std::option::Option::Some(TaskDescription_Status::AWAITING),
+            1 => ::std::option::Option::Some(TaskDescription_Status::RUNNING),
+            2 => ::std::option::Option::Some(TaskDescription_Status::COMPLETED),
+            3 => ::std::option::Option::Some(TaskDescription_Status::HALTED),
+            4 => ::std::option::Option::Some(TaskDescription_Status::CANCELLED),
+            5 => ::std::option::Option::Some(TaskDescription_Status::TIMED_OUT),
+            6 => ::std::option::Option::Some(TaskDescription_Status::OUT_OF_MEMORY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [TaskDescription_Status] = &[
+            TaskDescription_Status::AWAITING,
+            TaskDescription_Status::RUNNING,
+            TaskDescription_Status::COMPLETED,
+            TaskDescription_Status::HALTED,
+            TaskDescription_Status::CANCELLED,
+            TaskDescription_Status::TIMED_OUT,
+            TaskDescription_Status::OUT_OF_MEMORY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("TaskDescription_Status", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for TaskDescription_Status {
+}
+
+impl ::std::default::Default for TaskDescription_Status {
+    fn default() -> Self {
+        TaskDescription_Status::AWAITING
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TaskDescription_Status {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DescribeJobResponse {
+    // message fields
+    pub job_status: ::protobuf::SingularPtrField<JobStatus>,
+    pub tasks: ::protobuf::RepeatedField<TaskDescription>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl DescribeJobResponse {
+    pub fn new() -> DescribeJobResponse {
+        ::std::default::Default::default()
+    }
+
+    // .JobStatus job_status = 1;
+
+    pub fn clear_job_status(&mut self) {
+        self.job_status.clear();
+    }
+
+    pub fn has_job_status(&self) -> bool {
+        self.job_status.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_status(&mut self, v: JobStatus) {
+        self.job_status = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_job_status(&mut self) -> &mut JobStatus {
+        if self.job_status.is_none() {
+            self.job_status.set_default();
+        }
+        self.job_status.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_job_status(&mut self) -> JobStatus {
+        self.job_status.take().unwrap_or_else(|| JobStatus::new())
+    }
+
+    pub fn get_job_status(&self) -> &JobStatus {
+        self.job_status.as_ref().unwrap_or_else(|| JobStatus::default_instance())
+    }
+
+    // repeated .TaskDescription tasks = 2;
+
+    pub fn clear_tasks(&mut self) {
+        self.tasks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tasks(&mut self, v: ::protobuf::RepeatedField<TaskDescription>) {
+        self.tasks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tasks(&mut self) -> &mut ::protobuf::RepeatedField<TaskDescription> {
+        &mut self.tasks
+    }
+
+    // Take field
+    pub fn take_tasks(&mut self) -> ::protobuf::RepeatedField<TaskDescription> {
+        ::std::mem::replace(&mut self.tasks, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tasks(&self) -> &[TaskDescription] {
+        &self.tasks
+    }
+}
+
+impl ::protobuf::Message for DescribeJobResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.job_status {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.tasks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.job_status)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tasks)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.job_status.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.tasks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.job_status.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.tasks {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DescribeJobResponse {
+        DescribeJobResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobStatus>>(
+                    "job_status",
+                    |m: &DescribeJobResponse| { &m.job_status },
+                    |m: &mut DescribeJobResponse| { &mut m.job_status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TaskDescription>>(
+                    "tasks",
+                    |m: &DescribeJobResponse| { &m.tasks },
+                    |m: &mut DescribeJobResponse| { &mut m.tasks },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobStatus>(
-                    "JobStatus",
+                ::protobuf::reflect::MessageDescriptor::new::<DescribeJobResponse>(
+                    "DescribeJobResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DescribeJobResponse {
+        static mut instance: ::protobuf::lazy::Lazy<DescribeJobResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DescribeJobResponse,
+        };
+        unsafe {
+            instance.get(DescribeJobResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DescribeJobResponse {
+    fn clear(&mut self) {
+        self.clear_job_status();
+        self.clear_tasks();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DescribeJobResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DescribeJobResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerInputRequest {
+    // message fields
+    pub token: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerInputRequest {
+    pub fn new() -> WorkerInputRequest {
+        ::std::default::Default::default()
+    }
+
+    // string token = 1;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl ::protobuf::Message for WorkerInputRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.token.is_empty() {
+            os.write_string(1, &self.token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerInputRequest {
+        WorkerInputRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    |m: &WorkerInputRequest| { &m.token },
+                    |m: &mut WorkerInputRequest| { &mut m.token },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerInputRequest>(
+                    "WorkerInputRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2285,147 +8053,191 @@ impl ::protobuf::Message for JobStatus {
         }
     }
 
-    fn default_instance() -> &'static JobStatus {
-        static mut instance: ::protobuf::lazy::Lazy<JobStatus> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobStatus,
-        };
-        unsafe {
-            instance.get(JobStatus::new)
-        }
+    fn default_instance() -> &'static WorkerInputRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerInputRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerInputRequest,
+        };
+        unsafe {
+            instance.get(WorkerInputRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerInputRequest {
+    fn clear(&mut self) {
+        self.clear_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerInputRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerInputRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerInputResponse {
+    // message fields
+    pub function_closure: ::std::vec::Vec<u8>,
+    pub map_type: WorkerInputResponse_MapType,
+    pub datapacks: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub shutdown: bool,
+    pub token: ::std::string::String,
+    pub broadcasts: ::protobuf::RepeatedField<Broadcast>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerInputResponse {
+    pub fn new() -> WorkerInputResponse {
+        ::std::default::Default::default()
+    }
+
+    // bytes function_closure = 1;
+
+    pub fn clear_function_closure(&mut self) {
+        self.function_closure.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_function_closure(&mut self, v: ::std::vec::Vec<u8>) {
+        self.function_closure = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_function_closure(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.function_closure
+    }
+
+    // Take field
+    pub fn take_function_closure(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.function_closure, ::std::vec::Vec::new())
+    }
+
+    pub fn get_function_closure(&self) -> &[u8] {
+        &self.function_closure
+    }
+
+    // .WorkerInputResponse.MapType map_type = 2;
+
+    pub fn clear_map_type(&mut self) {
+        self.map_type = WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_type(&mut self, v: WorkerInputResponse_MapType) {
+        self.map_type = v;
+    }
+
+    pub fn get_map_type(&self) -> WorkerInputResponse_MapType {
+        self.map_type
     }
-}
 
-impl ::protobuf::Clear for JobStatus {
-    fn clear(&mut self) {
-        self.clear_job_id();
-        self.clear_status();
-        self.unknown_fields.clear();
+    // repeated bytes datapacks = 3;
+
+    pub fn clear_datapacks(&mut self) {
+        self.datapacks.clear();
     }
-}
 
-impl ::std::fmt::Debug for JobStatus {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    // Param is passed by value, moved
+    pub fn set_datapacks(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.datapacks = v;
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for JobStatus {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    // Mutable pointer to the field.
+    pub fn mut_datapacks(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.datapacks
     }
-}
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum JobStatus_Status {
-    BLOCKED = 0,
-    QUEUED = 1,
-    RUNNING = 2,
-    COMPLETED = 4,
-    HALTED = 5,
-    CANCELLED = 6,
-}
+    // Take field
+    pub fn take_datapacks(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.datapacks, ::protobuf::RepeatedField::new())
+    }
 
-impl ::protobuf::ProtobufEnum for JobStatus_Status {
-    fn value(&self) -> i32 {
-        *self as i32
+    pub fn get_datapacks(&self) -> &[::std::vec::Vec<u8>] {
+        &self.datapacks
     }
 
-    fn from_i32(value: i32) -> ::std::option::Option<JobStatus_Status> {
-        match value {
-            0 => ::std::option::Option::Some(JobStatus_Status::BLOCKED),
-            1 => ::std::option::Option::Some(JobStatus_Status::QUEUED),
-            2 => ::std::option::Option::Some(JobStatus_Status::RUNNING),
-            4 => ::std::option::Option::Some(JobStatus_Status::COMPLETED),
-            5 => ::std::option::Option::Some(JobStatus_Status::HALTED),
-            6 => ::std::option::Option::Some(JobStatus_Status::CANCELLED),
-            _ => ::std::option::Option::None
-        }
+    // bool shutdown = 4;
+
+    pub fn clear_shutdown(&mut self) {
+        self.shutdown = false;
     }
 
-    fn values() -> &'static [Self] {
-        static values: &'static [JobStatus_Status] = &[
-            JobStatus_Status::BLOCKED,
-            JobStatus_Status::QUEUED,
-            JobStatus_Status::RUNNING,
-            JobStatus_Status::COMPLETED,
-            JobStatus_Status::HALTED,
-            JobStatus_Status::CANCELLED,
-        ];
-        values
+    // Param is passed by value, moved
+    pub fn set_shutdown(&mut self, v: bool) {
+        self.shutdown = v;
     }
 
-    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("JobStatus_Status", file_descriptor_proto())
-            })
-        }
+    pub fn get_shutdown(&self) -> bool {
+        self.shutdown
     }
-}
 
-impl ::std::marker::Copy for JobStatus_Status {
-}
+    // string token = 5;
 
-impl ::std::default::Default for JobStatus_Status {
-    fn default() -> Self {
-        JobStatus_Status::BLOCKED
+    pub fn clear_token(&mut self) {
+        self.token.clear();
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for JobStatus_Status {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct JobStatusResponse {
-    // message fields
-    pub job_statuses: ::protobuf::RepeatedField<JobStatus>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
+    }
 
-impl JobStatusResponse {
-    pub fn new() -> JobStatusResponse {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
     }
 
-    // repeated .JobStatus job_statuses = 1;
+    pub fn get_token(&self) -> &str {
+        &self.token
+    }
 
-    pub fn clear_job_statuses(&mut self) {
-        self.job_statuses.clear();
+    // repeated .Broadcast broadcasts = 6;
+
+    pub fn clear_broadcasts(&mut self) {
+        self.broadcasts.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_job_statuses(&mut self, v: ::protobuf::RepeatedField<JobStatus>) {
-        self.job_statuses = v;
+    pub fn set_broadcasts(&mut self, v: ::protobuf::RepeatedField<Broadcast>) {
+        self.broadcasts = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_job_statuses(&mut self) -> &mut ::protobuf::RepeatedField<JobStatus> {
-        &mut self.job_statuses
+    pub fn mut_broadcasts(&mut self) -> &mut ::protobuf::RepeatedField<Broadcast> {
+        &mut self.broadcasts
     }
 
     // Take field
-    pub fn take_job_statuses(&mut self) -> ::protobuf::RepeatedField<JobStatus> {
-        ::std::mem::replace(&mut self.job_statuses, ::protobuf::RepeatedField::new())
+    pub fn take_broadcasts(&mut self) -> ::protobuf::RepeatedField<Broadcast> {
+        ::std::mem::replace(&mut self.broadcasts, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_job_statuses(&self) -> &[JobStatus] {
-        &self.job_statuses
+    pub fn get_broadcasts(&self) -> &[Broadcast] {
+        &self.broadcasts
     }
 }
 
-impl ::protobuf::Message for JobStatusResponse {
+impl ::protobuf::Message for WorkerInputResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.job_statuses {
+        for v in &self.broadcasts {
             if !v.is_initialized() {
                 return false;
             }
@@ -2438,7 +8250,26 @@ impl ::protobuf::Message for JobStatusResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.job_statuses)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.function_closure)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.map_type, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.datapacks)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.shutdown = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.broadcasts)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2452,7 +8283,22 @@ impl ::protobuf::Message for JobStatusResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.job_statuses {
+        if !self.function_closure.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.function_closure);
+        }
+        if self.map_type != WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT {
+            my_size += ::protobuf::rt::enum_size(2, self.map_type);
+        }
+        for value in &self.datapacks {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        if self.shutdown != false {
+            my_size += 2;
+        }
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.token);
+        }
+        for value in &self.broadcasts {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
@@ -2462,8 +8308,23 @@ impl ::protobuf::Message for JobStatusResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.job_statuses {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if !self.function_closure.is_empty() {
+            os.write_bytes(1, &self.function_closure)?;
+        }
+        if self.map_type != WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT {
+            os.write_enum(2, self.map_type.value())?;
+        }
+        for v in &self.datapacks {
+            os.write_bytes(3, &v)?;
+        };
+        if self.shutdown != false {
+            os.write_bool(4, self.shutdown)?;
+        }
+        if !self.token.is_empty() {
+            os.write_string(5, &self.token)?;
+        }
+        for v in &self.broadcasts {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
@@ -2493,81 +8354,254 @@ impl ::protobuf::Message for JobStatusResponse {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerInputResponse {
+        WorkerInputResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "function_closure",
+                    |m: &WorkerInputResponse| { &m.function_closure },
+                    |m: &mut WorkerInputResponse| { &mut m.function_closure },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerInputResponse_MapType>>(
+                    "map_type",
+                    |m: &WorkerInputResponse| { &m.map_type },
+                    |m: &mut WorkerInputResponse| { &mut m.map_type },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "datapacks",
+                    |m: &WorkerInputResponse| { &m.datapacks },
+                    |m: &mut WorkerInputResponse| { &mut m.datapacks },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "shutdown",
+                    |m: &WorkerInputResponse| { &m.shutdown },
+                    |m: &mut WorkerInputResponse| { &mut m.shutdown },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    |m: &WorkerInputResponse| { &m.token },
+                    |m: &mut WorkerInputResponse| { &mut m.token },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Broadcast>>(
+                    "broadcasts",
+                    |m: &WorkerInputResponse| { &m.broadcasts },
+                    |m: &mut WorkerInputResponse| { &mut m.broadcasts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerInputResponse>(
+                    "WorkerInputResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerInputResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerInputResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerInputResponse,
+        };
+        unsafe {
+            instance.get(WorkerInputResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerInputResponse {
+    fn clear(&mut self) {
+        self.clear_function_closure();
+        self.clear_map_type();
+        self.clear_datapacks();
+        self.clear_shutdown();
+        self.clear_token();
+        self.clear_broadcasts();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerInputResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerInputResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WorkerInputResponse_MapType {
+    SINGLE_IN_MULTI_OUT = 0,
+    SINGLE_IN_SINGLE_OUT = 1,
+    MULTI_IN_SINGLE_OUT = 2,
+}
+
+impl ::protobuf::ProtobufEnum for WorkerInputResponse_MapType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WorkerInputResponse_MapType> {
+        match value {
+            0 => ::std::option::Option::Some(WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT),
+            1 => ::std::option::Option::Some(WorkerInputResponse_MapType::SINGLE_IN_SINGLE_OUT),
+            2 => ::std::option::Option::Some(WorkerInputResponse_MapType::MULTI_IN_SINGLE_OUT),
+            _ => ::std::option::Option::None
+        }
     }
 
-    fn new() -> JobStatusResponse {
-        JobStatusResponse::new()
+    fn values() -> &'static [Self] {
+        static values: &'static [WorkerInputResponse_MapType] = &[
+            WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT,
+            WorkerInputResponse_MapType::SINGLE_IN_SINGLE_OUT,
+            WorkerInputResponse_MapType::MULTI_IN_SINGLE_OUT,
+        ];
+        values
     }
 
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
         };
         unsafe {
             descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobStatus>>(
-                    "job_statuses",
-                    |m: &JobStatusResponse| { &m.job_statuses },
-                    |m: &mut JobStatusResponse| { &mut m.job_statuses },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobStatusResponse>(
-                    "JobStatusResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
+                ::protobuf::reflect::EnumDescriptor::new("WorkerInputResponse_MapType", file_descriptor_proto())
             })
         }
     }
-
-    fn default_instance() -> &'static JobStatusResponse {
-        static mut instance: ::protobuf::lazy::Lazy<JobStatusResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobStatusResponse,
-        };
-        unsafe {
-            instance.get(JobStatusResponse::new)
-        }
-    }
 }
 
-impl ::protobuf::Clear for JobStatusResponse {
-    fn clear(&mut self) {
-        self.clear_job_statuses();
-        self.unknown_fields.clear();
-    }
+impl ::std::marker::Copy for WorkerInputResponse_MapType {
 }
 
-impl ::std::fmt::Debug for JobStatusResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::std::default::Default for WorkerInputResponse_MapType {
+    fn default() -> Self {
+        WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobStatusResponse {
+impl ::protobuf::reflect::ProtobufValue for WorkerInputResponse_MapType {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct WorkerInputRequest {
+pub struct WorkerOutputRequest {
+    // message fields
+    pub datapacks: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub token: ::std::string::String,
+    pub accumulators: ::protobuf::RepeatedField<Accumulator>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl WorkerInputRequest {
-    pub fn new() -> WorkerInputRequest {
+impl WorkerOutputRequest {
+    pub fn new() -> WorkerOutputRequest {
         ::std::default::Default::default()
     }
+
+    // repeated bytes datapacks = 1;
+
+    pub fn clear_datapacks(&mut self) {
+        self.datapacks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_datapacks(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.datapacks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_datapacks(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.datapacks
+    }
+
+    // Take field
+    pub fn take_datapacks(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.datapacks, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_datapacks(&self) -> &[::std::vec::Vec<u8>] {
+        &self.datapacks
+    }
+
+    // string token = 2;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        &self.token
+    }
+
+    // repeated .Accumulator accumulators = 3;
+
+    pub fn clear_accumulators(&mut self) {
+        self.accumulators.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accumulators(&mut self, v: ::protobuf::RepeatedField<Accumulator>) {
+        self.accumulators = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accumulators(&mut self) -> &mut ::protobuf::RepeatedField<Accumulator> {
+        &mut self.accumulators
+    }
+
+    // Take field
+    pub fn take_accumulators(&mut self) -> ::protobuf::RepeatedField<Accumulator> {
+        ::std::mem::replace(&mut self.accumulators, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_accumulators(&self) -> &[Accumulator] {
+        &self.accumulators
+    }
 }
 
-impl ::protobuf::Message for WorkerInputRequest {
+impl ::protobuf::Message for WorkerOutputRequest {
     fn is_initialized(&self) -> bool {
+        for v in &self.accumulators {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -2575,6 +8609,15 @@ impl ::protobuf::Message for WorkerInputRequest {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.datapacks)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accumulators)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2587,12 +8630,33 @@ impl ::protobuf::Message for WorkerInputRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        for value in &self.datapacks {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.token);
+        }
+        for value in &self.accumulators {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.datapacks {
+            os.write_bytes(1, &v)?;
+        };
+        if !self.token.is_empty() {
+            os.write_string(2, &self.token)?;
+        }
+        for v in &self.accumulators {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2623,8 +8687,8 @@ impl ::protobuf::Message for WorkerInputRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerInputRequest {
-        WorkerInputRequest::new()
+    fn new() -> WorkerOutputRequest {
+        WorkerOutputRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2634,129 +8698,77 @@ impl ::protobuf::Message for WorkerInputRequest {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerInputRequest>(
-                    "WorkerInputRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
-    }
-
-    fn default_instance() -> &'static WorkerInputRequest {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerInputRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerInputRequest,
-        };
-        unsafe {
-            instance.get(WorkerInputRequest::new)
-        }
-    }
-}
-
-impl ::protobuf::Clear for WorkerInputRequest {
-    fn clear(&mut self) {
-        self.unknown_fields.clear();
-    }
-}
-
-impl ::std::fmt::Debug for WorkerInputRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
-    }
-}
-
-impl ::protobuf::reflect::ProtobufValue for WorkerInputRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
-    }
-}
-
-#[derive(PartialEq,Clone,Default)]
-pub struct WorkerInputResponse {
-    // message fields
-    pub function_closure: ::std::vec::Vec<u8>,
-    pub map_type: WorkerInputResponse_MapType,
-    pub datapacks: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
-
-impl WorkerInputResponse {
-    pub fn new() -> WorkerInputResponse {
-        ::std::default::Default::default()
-    }
-
-    // bytes function_closure = 1;
-
-    pub fn clear_function_closure(&mut self) {
-        self.function_closure.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_function_closure(&mut self, v: ::std::vec::Vec<u8>) {
-        self.function_closure = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_function_closure(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.function_closure
-    }
-
-    // Take field
-    pub fn take_function_closure(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.function_closure, ::std::vec::Vec::new())
-    }
-
-    pub fn get_function_closure(&self) -> &[u8] {
-        &self.function_closure
-    }
-
-    // .WorkerInputResponse.MapType map_type = 2;
-
-    pub fn clear_map_type(&mut self) {
-        self.map_type = WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_map_type(&mut self, v: WorkerInputResponse_MapType) {
-        self.map_type = v;
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "datapacks",
+                    |m: &WorkerOutputRequest| { &m.datapacks },
+                    |m: &mut WorkerOutputRequest| { &mut m.datapacks },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    |m: &WorkerOutputRequest| { &m.token },
+                    |m: &mut WorkerOutputRequest| { &mut m.token },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Accumulator>>(
+                    "accumulators",
+                    |m: &WorkerOutputRequest| { &m.accumulators },
+                    |m: &mut WorkerOutputRequest| { &mut m.accumulators },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerOutputRequest>(
+                    "WorkerOutputRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
 
-    pub fn get_map_type(&self) -> WorkerInputResponse_MapType {
-        self.map_type
+    fn default_instance() -> &'static WorkerOutputRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerOutputRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerOutputRequest,
+        };
+        unsafe {
+            instance.get(WorkerOutputRequest::new)
+        }
     }
+}
 
-    // repeated bytes datapacks = 3;
-
-    pub fn clear_datapacks(&mut self) {
-        self.datapacks.clear();
+impl ::protobuf::Clear for WorkerOutputRequest {
+    fn clear(&mut self) {
+        self.clear_datapacks();
+        self.clear_token();
+        self.clear_accumulators();
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_datapacks(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
-        self.datapacks = v;
+impl ::std::fmt::Debug for WorkerOutputRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_datapacks(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        &mut self.datapacks
+impl ::protobuf::reflect::ProtobufValue for WorkerOutputRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    // Take field
-    pub fn take_datapacks(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        ::std::mem::replace(&mut self.datapacks, ::protobuf::RepeatedField::new())
-    }
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerOutputResponse {
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_datapacks(&self) -> &[::std::vec::Vec<u8>] {
-        &self.datapacks
+impl WorkerOutputResponse {
+    pub fn new() -> WorkerOutputResponse {
+        ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for WorkerInputResponse {
+impl ::protobuf::Message for WorkerOutputResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2765,15 +8777,6 @@ impl ::protobuf::Message for WorkerInputResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.function_closure)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.map_type, 2, &mut self.unknown_fields)?
-                },
-                3 => {
-                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.datapacks)?;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2786,30 +8789,12 @@ impl ::protobuf::Message for WorkerInputResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.function_closure.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.function_closure);
-        }
-        if self.map_type != WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT {
-            my_size += ::protobuf::rt::enum_size(2, self.map_type);
-        }
-        for value in &self.datapacks {
-            my_size += ::protobuf::rt::bytes_size(3, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.function_closure.is_empty() {
-            os.write_bytes(1, &self.function_closure)?;
-        }
-        if self.map_type != WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT {
-            os.write_enum(2, self.map_type.value())?;
-        }
-        for v in &self.datapacks {
-            os.write_bytes(3, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2840,8 +8825,8 @@ impl ::protobuf::Message for WorkerInputResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerInputResponse {
-        WorkerInputResponse::new()
+    fn new() -> WorkerOutputResponse {
+        WorkerOutputResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2851,24 +8836,9 @@ impl ::protobuf::Message for WorkerInputResponse {
         };
         unsafe {
             descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "function_closure",
-                    |m: &WorkerInputResponse| { &m.function_closure },
-                    |m: &mut WorkerInputResponse| { &mut m.function_closure },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerInputResponse_MapType>>(
-                    "map_type",
-                    |m: &WorkerInputResponse| { &m.map_type },
-                    |m: &mut WorkerInputResponse| { &mut m.map_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "datapacks",
-                    |m: &WorkerInputResponse| { &m.datapacks },
-                    |m: &mut WorkerInputResponse| { &mut m.datapacks },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerInputResponse>(
-                    "WorkerInputResponse",
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerOutputResponse>(
+                    "WorkerOutputResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2876,137 +8846,120 @@ impl ::protobuf::Message for WorkerInputResponse {
         }
     }
 
-    fn default_instance() -> &'static WorkerInputResponse {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerInputResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerOutputResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerOutputResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerInputResponse,
+            ptr: 0 as *const WorkerOutputResponse,
         };
         unsafe {
-            instance.get(WorkerInputResponse::new)
+            instance.get(WorkerOutputResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for WorkerInputResponse {
+impl ::protobuf::Clear for WorkerOutputResponse {
     fn clear(&mut self) {
-        self.clear_function_closure();
-        self.clear_map_type();
-        self.clear_datapacks();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorkerInputResponse {
+impl ::std::fmt::Debug for WorkerOutputResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerInputResponse {
+impl ::protobuf::reflect::ProtobufValue for WorkerOutputResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum WorkerInputResponse_MapType {
-    SINGLE_IN_MULTI_OUT = 0,
-    SINGLE_IN_SINGLE_OUT = 1,
-    MULTI_IN_SINGLE_OUT = 2,
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerProgressRequest {
+    // message fields
+    pub progress: f32,
+    pub message: ::std::string::String,
+    pub token: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
 }
 
-impl ::protobuf::ProtobufEnum for WorkerInputResponse_MapType {
-    fn value(&self) -> i32 {
-        *self as i32
+impl WorkerProgressRequest {
+    pub fn new() -> WorkerProgressRequest {
+        ::std::default::Default::default()
     }
 
-    fn from_i32(value: i32) -> ::std::option::Option<WorkerInputResponse_MapType> {
-        match value {
-            0 => ::std::option::Option::Some(WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT),
-            1 => ::std::option::Option::Some(WorkerInputResponse_MapType::SINGLE_IN_SINGLE_OUT),
-            2 => ::std::option::Option::Some(WorkerInputResponse_MapType::MULTI_IN_SINGLE_OUT),
-            _ => ::std::option::Option::None
-        }
+    // float progress = 1;
+
+    pub fn clear_progress(&mut self) {
+        self.progress = 0.;
     }
 
-    fn values() -> &'static [Self] {
-        static values: &'static [WorkerInputResponse_MapType] = &[
-            WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT,
-            WorkerInputResponse_MapType::SINGLE_IN_SINGLE_OUT,
-            WorkerInputResponse_MapType::MULTI_IN_SINGLE_OUT,
-        ];
-        values
+    // Param is passed by value, moved
+    pub fn set_progress(&mut self, v: f32) {
+        self.progress = v;
     }
 
-    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("WorkerInputResponse_MapType", file_descriptor_proto())
-            })
-        }
+    pub fn get_progress(&self) -> f32 {
+        self.progress
     }
-}
 
-impl ::std::marker::Copy for WorkerInputResponse_MapType {
-}
+    // string message = 2;
 
-impl ::std::default::Default for WorkerInputResponse_MapType {
-    fn default() -> Self {
-        WorkerInputResponse_MapType::SINGLE_IN_MULTI_OUT
+    pub fn clear_message(&mut self) {
+        self.message.clear();
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for WorkerInputResponse_MapType {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct WorkerOutputRequest {
-    // message fields
-    pub datapacks: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
+    }
 
-impl WorkerOutputRequest {
-    pub fn new() -> WorkerOutputRequest {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
     }
 
-    // repeated bytes datapacks = 1;
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
 
-    pub fn clear_datapacks(&mut self) {
-        self.datapacks.clear();
+    // string token = 3;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_datapacks(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
-        self.datapacks = v;
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_datapacks(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        &mut self.datapacks
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
     }
 
     // Take field
-    pub fn take_datapacks(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        ::std::mem::replace(&mut self.datapacks, ::protobuf::RepeatedField::new())
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
     }
 
-    pub fn get_datapacks(&self) -> &[::std::vec::Vec<u8>] {
-        &self.datapacks
+    pub fn get_token(&self) -> &str {
+        &self.token
     }
 }
 
-impl ::protobuf::Message for WorkerOutputRequest {
+impl ::protobuf::Message for WorkerProgressRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3016,7 +8969,17 @@ impl ::protobuf::Message for WorkerOutputRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.datapacks)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.progress = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3030,18 +8993,30 @@ impl ::protobuf::Message for WorkerOutputRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.datapacks {
-            my_size += ::protobuf::rt::bytes_size(1, &value);
-        };
+        if self.progress != 0. {
+            my_size += 5;
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.message);
+        }
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.token);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.datapacks {
-            os.write_bytes(1, &v)?;
-        };
+        if self.progress != 0. {
+            os.write_float(1, self.progress)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(2, &self.message)?;
+        }
+        if !self.token.is_empty() {
+            os.write_string(3, &self.token)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3072,8 +9047,8 @@ impl ::protobuf::Message for WorkerOutputRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerOutputRequest {
-        WorkerOutputRequest::new()
+    fn new() -> WorkerProgressRequest {
+        WorkerProgressRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3084,13 +9059,23 @@ impl ::protobuf::Message for WorkerOutputRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "datapacks",
-                    |m: &WorkerOutputRequest| { &m.datapacks },
-                    |m: &mut WorkerOutputRequest| { &mut m.datapacks },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                    "progress",
+                    |m: &WorkerProgressRequest| { &m.progress },
+                    |m: &mut WorkerProgressRequest| { &mut m.progress },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerOutputRequest>(
-                    "WorkerOutputRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "message",
+                    |m: &WorkerProgressRequest| { &m.message },
+                    |m: &mut WorkerProgressRequest| { &mut m.message },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    |m: &WorkerProgressRequest| { &m.token },
+                    |m: &mut WorkerProgressRequest| { &mut m.token },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerProgressRequest>(
+                    "WorkerProgressRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3098,50 +9083,52 @@ impl ::protobuf::Message for WorkerOutputRequest {
         }
     }
 
-    fn default_instance() -> &'static WorkerOutputRequest {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerOutputRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerProgressRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerProgressRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerOutputRequest,
+            ptr: 0 as *const WorkerProgressRequest,
         };
         unsafe {
-            instance.get(WorkerOutputRequest::new)
+            instance.get(WorkerProgressRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for WorkerOutputRequest {
+impl ::protobuf::Clear for WorkerProgressRequest {
     fn clear(&mut self) {
-        self.clear_datapacks();
+        self.clear_progress();
+        self.clear_message();
+        self.clear_token();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorkerOutputRequest {
+impl ::std::fmt::Debug for WorkerProgressRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerOutputRequest {
+impl ::protobuf::reflect::ProtobufValue for WorkerProgressRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct WorkerOutputResponse {
+pub struct WorkerProgressResponse {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl WorkerOutputResponse {
-    pub fn new() -> WorkerOutputResponse {
+impl WorkerProgressResponse {
+    pub fn new() -> WorkerProgressResponse {
         ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for WorkerOutputResponse {
+impl ::protobuf::Message for WorkerProgressResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3198,8 +9185,8 @@ impl ::protobuf::Message for WorkerOutputResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerOutputResponse {
-        WorkerOutputResponse::new()
+    fn new() -> WorkerProgressResponse {
+        WorkerProgressResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3210,8 +9197,8 @@ impl ::protobuf::Message for WorkerOutputResponse {
         unsafe {
             descriptor.get(|| {
                 let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerOutputResponse>(
-                    "WorkerOutputResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerProgressResponse>(
+                    "WorkerProgressResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3219,30 +9206,30 @@ impl ::protobuf::Message for WorkerOutputResponse {
         }
     }
 
-    fn default_instance() -> &'static WorkerOutputResponse {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerOutputResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerProgressResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerProgressResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerOutputResponse,
+            ptr: 0 as *const WorkerProgressResponse,
         };
         unsafe {
-            instance.get(WorkerOutputResponse::new)
+            instance.get(WorkerProgressResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for WorkerOutputResponse {
+impl ::protobuf::Clear for WorkerProgressResponse {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorkerOutputResponse {
+impl ::std::fmt::Debug for WorkerProgressResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerOutputResponse {
+impl ::protobuf::reflect::ProtobufValue for WorkerProgressResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -3261,6 +9248,7 @@ pub struct SingleWorkerRequest {
 pub enum SingleWorkerRequest_oneof_request {
     input_request(WorkerInputRequest),
     output_request(WorkerOutputRequest),
+    progress_request(WorkerProgressRequest),
 }
 
 impl SingleWorkerRequest {
@@ -3365,6 +9353,55 @@ impl SingleWorkerRequest {
             _ => WorkerOutputRequest::default_instance(),
         }
     }
+
+    // .WorkerProgressRequest progress_request = 3;
+
+    pub fn clear_progress_request(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_progress_request(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress_request(&mut self, v: WorkerProgressRequest) {
+        self.request = ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_progress_request(&mut self) -> &mut WorkerProgressRequest {
+        if let ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(WorkerProgressRequest::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_progress_request(&mut self) -> WorkerProgressRequest {
+        if self.has_progress_request() {
+            match self.request.take() {
+                ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerProgressRequest::new()
+        }
+    }
+
+    pub fn get_progress_request(&self) -> &WorkerProgressRequest {
+        match self.request {
+            ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(ref v)) => v,
+            _ => WorkerProgressRequest::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for SingleWorkerRequest {
@@ -3379,6 +9416,11 @@ impl ::protobuf::Message for SingleWorkerRequest {
                 return false;
             }
         }
+        if let Some(SingleWorkerRequest_oneof_request::progress_request(ref v)) = self.request {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -3398,6 +9440,12 @@ impl ::protobuf::Message for SingleWorkerRequest {
                     }
                     self.request = ::std::option::Option::Some(SingleWorkerRequest_oneof_request::output_request(is.read_message()?));
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.request = ::std::option::Option::Some(SingleWorkerRequest_oneof_request::progress_request(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3420,6 +9468,10 @@ impl ::protobuf::Message for SingleWorkerRequest {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &SingleWorkerRequest_oneof_request::progress_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -3440,6 +9492,11 @@ impl ::protobuf::Message for SingleWorkerRequest {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &SingleWorkerRequest_oneof_request::progress_request(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -3494,6 +9551,11 @@ impl ::protobuf::Message for SingleWorkerRequest {
                     SingleWorkerRequest::has_output_request,
                     SingleWorkerRequest::get_output_request,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerProgressRequest>(
+                    "progress_request",
+                    SingleWorkerRequest::has_progress_request,
+                    SingleWorkerRequest::get_progress_request,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SingleWorkerRequest>(
                     "SingleWorkerRequest",
                     fields,
@@ -3518,6 +9580,7 @@ impl ::protobuf::Clear for SingleWorkerRequest {
     fn clear(&mut self) {
         self.clear_input_request();
         self.clear_output_request();
+        self.clear_progress_request();
         self.unknown_fields.clear();
     }
 }
@@ -3547,6 +9610,7 @@ pub struct SingleWorkerResponse {
 pub enum SingleWorkerResponse_oneof_response {
     input_response(WorkerInputResponse),
     output_response(WorkerOutputResponse),
+    progress_response(WorkerProgressResponse),
 }
 
 impl SingleWorkerResponse {
@@ -3651,6 +9715,55 @@ impl SingleWorkerResponse {
             _ => WorkerOutputResponse::default_instance(),
         }
     }
+
+    // .WorkerProgressResponse progress_response = 3;
+
+    pub fn clear_progress_response(&mut self) {
+        self.response = ::std::option::Option::None;
+    }
+
+    pub fn has_progress_response(&self) -> bool {
+        match self.response {
+            ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress_response(&mut self, v: WorkerProgressResponse) {
+        self.response = ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_progress_response(&mut self) -> &mut WorkerProgressResponse {
+        if let ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(_)) = self.response {
+        } else {
+            self.response = ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(WorkerProgressResponse::new()));
+        }
+        match self.response {
+            ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_progress_response(&mut self) -> WorkerProgressResponse {
+        if self.has_progress_response() {
+            match self.response.take() {
+                ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerProgressResponse::new()
+        }
+    }
+
+    pub fn get_progress_response(&self) -> &WorkerProgressResponse {
+        match self.response {
+            ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(ref v)) => v,
+            _ => WorkerProgressResponse::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for SingleWorkerResponse {
@@ -3665,6 +9778,11 @@ impl ::protobuf::Message for SingleWorkerResponse {
                 return false;
             }
         }
+        if let Some(SingleWorkerResponse_oneof_response::progress_response(ref v)) = self.response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -3684,6 +9802,12 @@ impl ::protobuf::Message for SingleWorkerResponse {
                     }
                     self.response = ::std::option::Option::Some(SingleWorkerResponse_oneof_response::output_response(is.read_message()?));
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.response = ::std::option::Option::Some(SingleWorkerResponse_oneof_response::progress_response(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3706,6 +9830,10 @@ impl ::protobuf::Message for SingleWorkerResponse {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &SingleWorkerResponse_oneof_response::progress_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -3726,6 +9854,11 @@ impl ::protobuf::Message for SingleWorkerResponse {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &SingleWorkerResponse_oneof_response::progress_response(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -3780,6 +9913,11 @@ impl ::protobuf::Message for SingleWorkerResponse {
                     SingleWorkerResponse::has_output_response,
                     SingleWorkerResponse::get_output_response,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerProgressResponse>(
+                    "progress_response",
+                    SingleWorkerResponse::has_progress_response,
+                    SingleWorkerResponse::get_progress_response,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SingleWorkerResponse>(
                     "SingleWorkerResponse",
                     fields,
@@ -3804,6 +9942,7 @@ impl ::protobuf::Clear for SingleWorkerResponse {
     fn clear(&mut self) {
         self.clear_input_response();
         self.clear_output_response();
+        self.clear_progress_response();
         self.unknown_fields.clear();
     }
 }
@@ -4451,6 +10590,9 @@ pub enum SingleUserRequest_oneof_request {
     job_submission(JobSubmission),
     data_retrieval_request(DataRetrievalRequest),
     job_status_request(JobStatusRequest),
+    watch_jobs_request(WatchJobsRequest),
+    describe_job_request(DescribeJobRequest),
+    resume_session_request(ResumeSessionRequest),
 }
 
 impl SingleUserRequest {
@@ -4529,177 +10671,324 @@ impl SingleUserRequest {
     pub fn mut_connection_request(&mut self) -> &mut ConnectionRequest {
         if let ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(_)) = self.request {
         } else {
-            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(ConnectionRequest::new()));
+            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(ConnectionRequest::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_connection_request(&mut self) -> ConnectionRequest {
+        if self.has_connection_request() {
+            match self.request.take() {
+                ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ConnectionRequest::new()
+        }
+    }
+
+    pub fn get_connection_request(&self) -> &ConnectionRequest {
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(ref v)) => v,
+            _ => ConnectionRequest::default_instance(),
+        }
+    }
+
+    // .JobSubmission job_submission = 3;
+
+    pub fn clear_job_submission(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_job_submission(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_submission(&mut self, v: JobSubmission) {
+        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_submission(&mut self) -> &mut JobSubmission {
+        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(JobSubmission::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_job_submission(&mut self) -> JobSubmission {
+        if self.has_job_submission() {
+            match self.request.take() {
+                ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            JobSubmission::new()
+        }
+    }
+
+    pub fn get_job_submission(&self) -> &JobSubmission {
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(ref v)) => v,
+            _ => JobSubmission::default_instance(),
+        }
+    }
+
+    // .DataRetrievalRequest data_retrieval_request = 4;
+
+    pub fn clear_data_retrieval_request(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_data_retrieval_request(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_retrieval_request(&mut self, v: DataRetrievalRequest) {
+        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data_retrieval_request(&mut self) -> &mut DataRetrievalRequest {
+        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(DataRetrievalRequest::new()));
+        }
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_data_retrieval_request(&mut self) -> DataRetrievalRequest {
+        if self.has_data_retrieval_request() {
+            match self.request.take() {
+                ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DataRetrievalRequest::new()
+        }
+    }
+
+    pub fn get_data_retrieval_request(&self) -> &DataRetrievalRequest {
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(ref v)) => v,
+            _ => DataRetrievalRequest::default_instance(),
+        }
+    }
+
+    // .JobStatusRequest job_status_request = 5;
+
+    pub fn clear_job_status_request(&mut self) {
+        self.request = ::std::option::Option::None;
+    }
+
+    pub fn has_job_status_request(&self) -> bool {
+        match self.request {
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_status_request(&mut self, v: JobStatusRequest) {
+        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_status_request(&mut self) -> &mut JobStatusRequest {
+        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(_)) = self.request {
+        } else {
+            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(JobStatusRequest::new()));
         }
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(ref mut v)) => v,
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_connection_request(&mut self) -> ConnectionRequest {
-        if self.has_connection_request() {
+    pub fn take_job_status_request(&mut self) -> JobStatusRequest {
+        if self.has_job_status_request() {
             match self.request.take() {
-                ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(v)) => v,
+                ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            ConnectionRequest::new()
+            JobStatusRequest::new()
         }
     }
 
-    pub fn get_connection_request(&self) -> &ConnectionRequest {
+    pub fn get_job_status_request(&self) -> &JobStatusRequest {
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::connection_request(ref v)) => v,
-            _ => ConnectionRequest::default_instance(),
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(ref v)) => v,
+            _ => JobStatusRequest::default_instance(),
         }
     }
 
-    // .JobSubmission job_submission = 3;
+    // .WatchJobsRequest watch_jobs_request = 7;
 
-    pub fn clear_job_submission(&mut self) {
+    pub fn clear_watch_jobs_request(&mut self) {
         self.request = ::std::option::Option::None;
     }
 
-    pub fn has_job_submission(&self) -> bool {
+    pub fn has_watch_jobs_request(&self) -> bool {
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(..)) => true,
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_job_submission(&mut self, v: JobSubmission) {
-        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(v))
+    pub fn set_watch_jobs_request(&mut self, v: WatchJobsRequest) {
+        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_job_submission(&mut self) -> &mut JobSubmission {
-        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(_)) = self.request {
+    pub fn mut_watch_jobs_request(&mut self) -> &mut WatchJobsRequest {
+        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(_)) = self.request {
         } else {
-            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(JobSubmission::new()));
+            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(WatchJobsRequest::new()));
         }
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(ref mut v)) => v,
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_job_submission(&mut self) -> JobSubmission {
-        if self.has_job_submission() {
+    pub fn take_watch_jobs_request(&mut self) -> WatchJobsRequest {
+        if self.has_watch_jobs_request() {
             match self.request.take() {
-                ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(v)) => v,
+                ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            JobSubmission::new()
+            WatchJobsRequest::new()
         }
     }
 
-    pub fn get_job_submission(&self) -> &JobSubmission {
+    pub fn get_watch_jobs_request(&self) -> &WatchJobsRequest {
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_submission(ref v)) => v,
-            _ => JobSubmission::default_instance(),
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(ref v)) => v,
+            _ => WatchJobsRequest::default_instance(),
         }
     }
 
-    // .DataRetrievalRequest data_retrieval_request = 4;
+    // .DescribeJobRequest describe_job_request = 8;
 
-    pub fn clear_data_retrieval_request(&mut self) {
+    pub fn clear_describe_job_request(&mut self) {
         self.request = ::std::option::Option::None;
     }
 
-    pub fn has_data_retrieval_request(&self) -> bool {
+    pub fn has_describe_job_request(&self) -> bool {
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(..)) => true,
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_data_retrieval_request(&mut self, v: DataRetrievalRequest) {
-        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(v))
+    pub fn set_describe_job_request(&mut self, v: DescribeJobRequest) {
+        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_data_retrieval_request(&mut self) -> &mut DataRetrievalRequest {
-        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(_)) = self.request {
+    pub fn mut_describe_job_request(&mut self) -> &mut DescribeJobRequest {
+        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(_)) = self.request {
         } else {
-            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(DataRetrievalRequest::new()));
+            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(DescribeJobRequest::new()));
         }
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(ref mut v)) => v,
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_data_retrieval_request(&mut self) -> DataRetrievalRequest {
-        if self.has_data_retrieval_request() {
+    pub fn take_describe_job_request(&mut self) -> DescribeJobRequest {
+        if self.has_describe_job_request() {
             match self.request.take() {
-                ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(v)) => v,
+                ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            DataRetrievalRequest::new()
+            DescribeJobRequest::new()
         }
     }
 
-    pub fn get_data_retrieval_request(&self) -> &DataRetrievalRequest {
+    pub fn get_describe_job_request(&self) -> &DescribeJobRequest {
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::data_retrieval_request(ref v)) => v,
-            _ => DataRetrievalRequest::default_instance(),
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(ref v)) => v,
+            _ => DescribeJobRequest::default_instance(),
         }
     }
 
-    // .JobStatusRequest job_status_request = 5;
+    // .ResumeSessionRequest resume_session_request = 9;
 
-    pub fn clear_job_status_request(&mut self) {
+    pub fn clear_resume_session_request(&mut self) {
         self.request = ::std::option::Option::None;
     }
 
-    pub fn has_job_status_request(&self) -> bool {
+    pub fn has_resume_session_request(&self) -> bool {
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(..)) => true,
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_job_status_request(&mut self, v: JobStatusRequest) {
-        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(v))
+    pub fn set_resume_session_request(&mut self, v: ResumeSessionRequest) {
+        self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_job_status_request(&mut self) -> &mut JobStatusRequest {
-        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(_)) = self.request {
+    pub fn mut_resume_session_request(&mut self) -> &mut ResumeSessionRequest {
+        if let ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(_)) = self.request {
         } else {
-            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(JobStatusRequest::new()));
+            self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(ResumeSessionRequest::new()));
         }
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(ref mut v)) => v,
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_job_status_request(&mut self) -> JobStatusRequest {
-        if self.has_job_status_request() {
+    pub fn take_resume_session_request(&mut self) -> ResumeSessionRequest {
+        if self.has_resume_session_request() {
             match self.request.take() {
-                ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(v)) => v,
+                ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            JobStatusRequest::new()
+            ResumeSessionRequest::new()
         }
     }
 
-    pub fn get_job_status_request(&self) -> &JobStatusRequest {
+    pub fn get_resume_session_request(&self) -> &ResumeSessionRequest {
         match self.request {
-            ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(ref v)) => v,
-            _ => JobStatusRequest::default_instance(),
+            ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(ref v)) => v,
+            _ => ResumeSessionRequest::default_instance(),
         }
     }
 }
@@ -4731,6 +11020,21 @@ impl ::protobuf::Message for SingleUserRequest {
                 return false;
             }
         }
+        if let Some(SingleUserRequest_oneof_request::watch_jobs_request(ref v)) = self.request {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleUserRequest_oneof_request::describe_job_request(ref v)) = self.request {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleUserRequest_oneof_request::resume_session_request(ref v)) = self.request {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -4768,6 +11072,24 @@ impl ::protobuf::Message for SingleUserRequest {
                     }
                     self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::job_status_request(is.read_message()?));
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::watch_jobs_request(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::describe_job_request(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.request = ::std::option::Option::Some(SingleUserRequest_oneof_request::resume_session_request(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4802,6 +11124,18 @@ impl ::protobuf::Message for SingleUserRequest {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &SingleUserRequest_oneof_request::watch_jobs_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleUserRequest_oneof_request::describe_job_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleUserRequest_oneof_request::resume_session_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -4837,6 +11171,21 @@ impl ::protobuf::Message for SingleUserRequest {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &SingleUserRequest_oneof_request::watch_jobs_request(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleUserRequest_oneof_request::describe_job_request(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleUserRequest_oneof_request::resume_session_request(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -4906,6 +11255,21 @@ impl ::protobuf::Message for SingleUserRequest {
                     SingleUserRequest::has_job_status_request,
                     SingleUserRequest::get_job_status_request,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WatchJobsRequest>(
+                    "watch_jobs_request",
+                    SingleUserRequest::has_watch_jobs_request,
+                    SingleUserRequest::get_watch_jobs_request,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DescribeJobRequest>(
+                    "describe_job_request",
+                    SingleUserRequest::has_describe_job_request,
+                    SingleUserRequest::get_describe_job_request,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ResumeSessionRequest>(
+                    "resume_session_request",
+                    SingleUserRequest::has_resume_session_request,
+                    SingleUserRequest::get_resume_session_request,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SingleUserRequest>(
                     "SingleUserRequest",
                     fields,
@@ -4933,6 +11297,9 @@ impl ::protobuf::Clear for SingleUserRequest {
         self.clear_job_submission();
         self.clear_data_retrieval_request();
         self.clear_job_status_request();
+        self.clear_watch_jobs_request();
+        self.clear_describe_job_request();
+        self.clear_resume_session_request();
         self.unknown_fields.clear();
     }
 }
@@ -4966,6 +11333,9 @@ pub enum SingleUserResponse_oneof_response {
     job_status_response(JobStatusResponse),
     connection_response(ConnectionResponse),
     server_message(ServerMessage),
+    watch_jobs_response(WatchJobsResponse),
+    describe_job_response(DescribeJobResponse),
+    resume_session_response(ResumeSessionResponse),
 }
 
 impl SingleUserResponse {
@@ -5266,6 +11636,153 @@ impl SingleUserResponse {
             _ => ServerMessage::default_instance(),
         }
     }
+
+    // .WatchJobsResponse watch_jobs_response = 7;
+
+    pub fn clear_watch_jobs_response(&mut self) {
+        self.response = ::std::option::Option::None;
+    }
+
+    pub fn has_watch_jobs_response(&self) -> bool {
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_watch_jobs_response(&mut self, v: WatchJobsResponse) {
+        self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_watch_jobs_response(&mut self) -> &mut WatchJobsResponse {
+        if let ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(_)) = self.response {
+        } else {
+            self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(WatchJobsResponse::new()));
+        }
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_watch_jobs_response(&mut self) -> WatchJobsResponse {
+        if self.has_watch_jobs_response() {
+            match self.response.take() {
+                ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WatchJobsResponse::new()
+        }
+    }
+
+    pub fn get_watch_jobs_response(&self) -> &WatchJobsResponse {
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(ref v)) => v,
+            _ => WatchJobsResponse::default_instance(),
+        }
+    }
+
+    // .DescribeJobResponse describe_job_response = 8;
+
+    pub fn clear_describe_job_response(&mut self) {
+        self.response = ::std::option::Option::None;
+    }
+
+    pub fn has_describe_job_response(&self) -> bool {
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_describe_job_response(&mut self, v: DescribeJobResponse) {
+        self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_describe_job_response(&mut self) -> &mut DescribeJobResponse {
+        if let ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(_)) = self.response {
+        } else {
+            self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(DescribeJobResponse::new()));
+        }
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_describe_job_response(&mut self) -> DescribeJobResponse {
+        if self.has_describe_job_response() {
+            match self.response.take() {
+                ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DescribeJobResponse::new()
+        }
+    }
+
+    pub fn get_describe_job_response(&self) -> &DescribeJobResponse {
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(ref v)) => v,
+            _ => DescribeJobResponse::default_instance(),
+        }
+    }
+
+    // .ResumeSessionResponse resume_session_response = 9;
+
+    pub fn clear_resume_session_response(&mut self) {
+        self.response = ::std::option::Option::None;
+    }
+
+    pub fn has_resume_session_response(&self) -> bool {
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resume_session_response(&mut self, v: ResumeSessionResponse) {
+        self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_resume_session_response(&mut self) -> &mut ResumeSessionResponse {
+        if let ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(_)) = self.response {
+        } else {
+            self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(ResumeSessionResponse::new()));
+        }
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_resume_session_response(&mut self) -> ResumeSessionResponse {
+        if self.has_resume_session_response() {
+            match self.response.take() {
+                ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ResumeSessionResponse::new()
+        }
+    }
+
+    pub fn get_resume_session_response(&self) -> &ResumeSessionResponse {
+        match self.response {
+            ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(ref v)) => v,
+            _ => ResumeSessionResponse::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for SingleUserResponse {
@@ -5300,6 +11817,21 @@ impl ::protobuf::Message for SingleUserResponse {
                 return false;
             }
         }
+        if let Some(SingleUserResponse_oneof_response::watch_jobs_response(ref v)) = self.response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleUserResponse_oneof_response::describe_job_response(ref v)) = self.response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleUserResponse_oneof_response::resume_session_response(ref v)) = self.response {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -5343,6 +11875,24 @@ impl ::protobuf::Message for SingleUserResponse {
                     }
                     self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::server_message(is.read_message()?));
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::watch_jobs_response(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::describe_job_response(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.response = ::std::option::Option::Some(SingleUserResponse_oneof_response::resume_session_response(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5381,6 +11931,18 @@ impl ::protobuf::Message for SingleUserResponse {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &SingleUserResponse_oneof_response::watch_jobs_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleUserResponse_oneof_response::describe_job_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleUserResponse_oneof_response::resume_session_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -5421,6 +11983,21 @@ impl ::protobuf::Message for SingleUserResponse {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &SingleUserResponse_oneof_response::watch_jobs_response(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleUserResponse_oneof_response::describe_job_response(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleUserResponse_oneof_response::resume_session_response(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -5495,6 +12072,21 @@ impl ::protobuf::Message for SingleUserResponse {
                     SingleUserResponse::has_server_message,
                     SingleUserResponse::get_server_message,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WatchJobsResponse>(
+                    "watch_jobs_response",
+                    SingleUserResponse::has_watch_jobs_response,
+                    SingleUserResponse::get_watch_jobs_response,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DescribeJobResponse>(
+                    "describe_job_response",
+                    SingleUserResponse::has_describe_job_response,
+                    SingleUserResponse::get_describe_job_response,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ResumeSessionResponse>(
+                    "resume_session_response",
+                    SingleUserResponse::has_resume_session_response,
+                    SingleUserResponse::get_resume_session_response,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SingleUserResponse>(
                     "SingleUserResponse",
                     fields,
@@ -5523,6 +12115,9 @@ impl ::protobuf::Clear for SingleUserResponse {
         self.clear_job_status_response();
         self.clear_connection_response();
         self.clear_server_message();
+        self.clear_watch_jobs_response();
+        self.clear_describe_job_response();
+        self.clear_resume_session_response();
         self.unknown_fields.clear();
     }
 }
@@ -5544,69 +12139,160 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     id\x18\x01\x20\x01(\tR\x06userId\x12\x15\n\x06job_id\x18\x02\x20\x01(\
     \x05R\x05jobId\"-\n\x15DataRetrievalResponse\x12\x14\n\x05bytes\x18\x01\
     \x20\x01(\x0cR\x05bytes\"-\n\x0bInputAction\x12\x1e\n\x0bdata_loc_in\x18\
-    \x01\x20\x01(\x0cR\tdataLocIn\"\xd7\x01\n\tMapAction\x12,\n\x07mapType\
-    \x18\x01\x20\x01(\x0e2\x12.MapAction.MapTypeR\x07mapType\x12\x1a\n\tjob_\
-    id_in\x18\x02\x20\x01(\x05R\x07jobIdIn\x12)\n\x10function_closure\x18\
-    \x03\x20\x01(\x0cR\x0ffunctionClosure\"U\n\x07MapType\x12\x17\n\x13SINGL\
-    E_IN_MULTI_OUT\x10\0\x12\x18\n\x14SINGLE_IN_SINGLE_OUT\x10\x01\x12\x17\n\
-    \x13MULTI_IN_SINGLE_OUT\x10\x02\"l\n\x03Job\x12\x15\n\x06job_id\x18\x01\
-    \x20\x01(\x05R\x05jobId\x12$\n\x05input\x18\x04\x20\x01(\x0b2\x0c.InputA\
-    ctionH\0R\x05input\x12\x1e\n\x03map\x18\x05\x20\x01(\x0b2\n.MapActionH\0\
-    R\x03mapB\x08\n\x06action\"B\n\rJobSubmission\x12\x17\n\x07user_id\x18\
-    \x01\x20\x01(\tR\x06userId\x12\x18\n\x04jobs\x18\x02\x20\x03(\x0b2\x04.J\
-    obR\x04jobs\":\n\x15JobSubmissionResponse\x12!\n\x0cjob_accepted\x18\x01\
-    \x20\x01(\x08R\x0bjobAccepted\"b\n\x17CreateConnectionRequest\x12&\n\x0e\
-    authentication\x18\x01\x20\x01(\tR\x0eauthentication\x12\x1f\n\x0bdocker\
-    _name\x18\x02\x20\x01(\tR\ndockerName\"d\n\x18CreateConnectionResponse\
-    \x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12/\n\x13connection\
-    _accepted\x18\x02\x20\x01(\x08R\x12connectionAccepted\"D\n\x10JobStatusR\
-    equest\x12\x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x17\n\x07j\
-    ob_ids\x18\x02\x20\x03(\x05R\x06jobIds\"\xa7\x01\n\tJobStatus\x12\x15\n\
-    \x06job_id\x18\x02\x20\x01(\x05R\x05jobId\x12)\n\x06status\x18\x03\x20\
-    \x01(\x0e2\x11.JobStatus.StatusR\x06status\"X\n\x06Status\x12\x0b\n\x07B\
-    LOCKED\x10\0\x12\n\n\x06QUEUED\x10\x01\x12\x0b\n\x07RUNNING\x10\x02\x12\
-    \r\n\tCOMPLETED\x10\x04\x12\n\n\x06HALTED\x10\x05\x12\r\n\tCANCELLED\x10\
-    \x06\"B\n\x11JobStatusResponse\x12-\n\x0cjob_statuses\x18\x01\x20\x03(\
-    \x0b2\n.JobStatusR\x0bjobStatuses\"\x14\n\x12WorkerInputRequest\"\xee\
-    \x01\n\x13WorkerInputResponse\x12)\n\x10function_closure\x18\x01\x20\x01\
+    \x01\x20\x01(\x0cR\tdataLocIn\"-\n\x14PreviousOutputAction\x12\x15\n\x06\
+    job_id\x18\x01\x20\x01(\x05R\x05jobId\"\xad\x01\n\x0fFileInputAction\x12\
+    \x12\n\x04path\x18\x01\x20\x01(\tR\x04path\x129\n\nsplit_mode\x18\x02\
+    \x20\x01(\x0e2\x1a.FileInputAction.SplitModeR\tsplitMode\x12\x1d\n\nspli\
+    t_size\x18\x03\x20\x01(\x05R\tsplitSize\",\n\tSplitMode\x12\t\n\x05LINES\
+    \x10\0\x12\t\n\x05BYTES\x10\x01\x12\t\n\x05FILES\x10\x02\"/\n\x05Label\
+    \x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\
+    \x20\x01(\tR\x05value\"m\n\x0eResourceLimits\x12\x1b\n\tmemory_mb\x18\
+    \x01\x20\x01(\x05R\x08memoryMb\x12\x1f\n\x0bcpu_percent\x18\x02\x20\x01(\
+    \x05R\ncpuPercent\x12\x1d\n\nopen_files\x18\x03\x20\x01(\x05R\topenFiles\
+    \"\x20\n\nOutputSink\x12\x12\n\x04path\x18\x01\x20\x01(\tR\x04path\"\xd1\
+    \x03\n\tMapAction\x12,\n\x07mapType\x18\x01\x20\x01(\x0e2\x12.MapAction.\
+    MapTypeR\x07mapType\x12\x1a\n\tjob_id_in\x18\x02\x20\x01(\x05R\x07jobIdI\
+    n\x12)\n\x10function_closure\x18\x03\x20\x01(\x0cR\x0ffunctionClosure\
+    \x12/\n\x0frequired_labels\x18\x04\x20\x03(\x0b2\x06.LabelR\x0erequiredL\
+    abels\x12!\n\x0ctask_timeout\x18\x05\x20\x01(\x05R\x0btaskTimeout\x12'\n\
+    \x06limits\x18\x06\x20\x01(\x0b2\x0f.ResourceLimitsR\x06limits\x12\x1f\n\
+    \x04sink\x18\x07\x20\x01(\x0b2\x0b.OutputSinkR\x04sink\x12\x1f\n\x0bdock\
+    er_name\x18\x08\x20\x01(\tR\ndockerName\x12+\n\nexecutable\x18\t\x20\x01\
+    (\x0b2\x0b.ExecutableR\nexecutable\"c\n\x07MapType\x12\x17\n\x13SINGLE_I\
+    N_MULTI_OUT\x10\0\x12\x18\n\x14SINGLE_IN_SINGLE_OUT\x10\x01\x12\x17\n\
+    \x13MULTI_IN_SINGLE_OUT\x10\x02\x12\x0c\n\x08FLAT_MAP\x10\x03\"3\n\x11Re\
+    partitionAction\x12\x1e\n\npartitions\x18\x01\x20\x01(\x05R\npartitions\
+    \"0\n\x0eCoalesceAction\x12\x1e\n\npartitions\x18\x01\x20\x01(\x05R\npar\
+    titions\"\xc8\x02\n\x03Job\x12\x15\n\x06job_id\x18\x01\x20\x01(\x05R\x05\
+    jobId\x12$\n\x05input\x18\x04\x20\x01(\x0b2\x0c.InputActionH\0R\x05input\
+    \x12\x1e\n\x03map\x18\x05\x20\x01(\x0b2\n.MapActionH\0R\x03map\x12@\n\
+    \x0fprevious_output\x18\x06\x20\x01(\x0b2\x15.PreviousOutputActionH\0R\
+    \x0epreviousOutput\x121\n\nfile_input\x18\x07\x20\x01(\x0b2\x10.FileInpu\
+    tActionH\0R\tfileInput\x126\n\x0brepartition\x18\x08\x20\x01(\x0b2\x12.R\
+    epartitionActionH\0R\x0brepartition\x12-\n\x08coalesce\x18\t\x20\x01(\
+    \x0b2\x0f.CoalesceActionH\0R\x08coalesceB\x08\n\x06action\"M\n\tBroadcas\
+    t\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05value\x18\
+    \x02\x20\x01(\x0cR\x05value\x12\x16\n\x06digest\x18\x03\x20\x01(\tR\x06d\
+    igest\"\x97\x01\n\rJobSubmission\x12\x17\n\x07user_id\x18\x01\x20\x01(\t\
+    R\x06userId\x12\x18\n\x04jobs\x18\x02\x20\x03(\x0b2\x04.JobR\x04jobs\x12\
+    *\n\nbroadcasts\x18\x03\x20\x03(\x0b2\n.BroadcastR\nbroadcasts\x12'\n\
+    \x0fidempotency_key\x18\x04\x20\x01(\tR\x0eidempotencyKey\"l\n\x15JobSub\
+    missionResponse\x12!\n\x0cjob_accepted\x18\x01\x20\x01(\x08R\x0bjobAccep\
+    ted\x12\x14\n\x05error\x18\x02\x20\x01(\tR\x05error\x12\x1a\n\x08conflic\
+    t\x18\x03\x20\x01(\x08R\x08conflict\"=\n\x13EnvironmentVariable\x12\x10\
+    \n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\
+    \tR\x05value\"\xa1\x01\n\nExecutable\x12\x12\n\x04name\x18\x01\x20\x01(\
+    \tR\x04name\x12\x12\n\x04args\x18\x02\x20\x03(\tR\x04args\x12&\n\x03env\
+    \x18\x03\x20\x03(\x0b2\x14.EnvironmentVariableR\x03env\x12\x1a\n\x08arti\
+    fact\x18\x04\x20\x01(\x0cR\x08artifact\x12'\n\x0fartifact_digest\x18\x05\
+    \x20\x01(\tR\x0eartifactDigest\"\xd9\x01\n\x17CreateConnectionRequest\
+    \x12&\n\x0eauthentication\x18\x01\x20\x01(\tR\x0eauthentication\x12\x1f\
+    \n\x0bdocker_name\x18\x02\x20\x01(\tR\ndockerName\x12+\n\nexecutable\x18\
+    \x03\x20\x01(\x0b2\x0b.ExecutableR\nexecutable\x12!\n\x0csession_name\
+    \x18\x04\x20\x01(\tR\x0bsessionName\x12%\n\x0esession_secret\x18\x05\x20\
+    \x01(\tR\rsessionSecret\"\x8d\x01\n\x18CreateConnectionResponse\x12\x17\
+    \n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12/\n\x13connection_accepte\
+    d\x18\x02\x20\x01(\x08R\x12connectionAccepted\x12'\n\x0fartifact_digest\
+    \x18\x03\x20\x01(\tR\x0eartifactDigest\"`\n\x14ResumeSessionRequest\x12!\
+    \n\x0csession_name\x18\x01\x20\x01(\tR\x0bsessionName\x12%\n\x0esession_\
+    secret\x18\x02\x20\x01(\tR\rsessionSecret\"\x92\x01\n\x15ResumeSessionRe\
+    sponse\x12'\n\x0fsession_resumed\x18\x01\x20\x01(\x08R\x0esessionResumed\
+    \x12\x17\n\x07user_id\x18\x02\x20\x01(\tR\x06userId\x12\x17\n\x07job_ids\
+    \x18\x03\x20\x03(\x05R\x06jobIds\x12\x1e\n\x0bnext_job_id\x18\x04\x20\
+    \x01(\x05R\tnextJobId\"D\n\x10JobStatusRequest\x12\x17\n\x07user_id\x18\
+    \x01\x20\x01(\tR\x06userId\x12\x17\n\x07job_ids\x18\x02\x20\x03(\x05R\
+    \x06jobIds\"7\n\x0bAccumulator\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04\
+    name\x12\x14\n\x05value\x18\x02\x20\x01(\x01R\x05value\"\x8f\x03\n\tJobS\
+    tatus\x12\x15\n\x06job_id\x18\x02\x20\x01(\x05R\x05jobId\x12)\n\x06statu\
+    s\x18\x03\x20\x01(\x0e2\x11.JobStatus.StatusR\x06status\x12'\n\x0fcomple\
+    ted_tasks\x18\x04\x20\x01(\x05R\x0ecompletedTasks\x12\x1f\n\x0btotal_tas\
+    ks\x18\x05\x20\x01(\x05R\ntotalTasks\x12\x1a\n\x08progress\x18\x06\x20\
+    \x01(\x02R\x08progress\x12\x18\n\x07message\x18\x07\x20\x01(\tR\x07messa\
+    ge\x120\n\x0caccumulators\x18\x08\x20\x03(\x0b2\x0c.AccumulatorR\x0caccu\
+    mulators\"\x8d\x01\n\x06Status\x12\x0b\n\x07BLOCKED\x10\0\x12\n\n\x06QUE\
+    UED\x10\x01\x12\x0b\n\x07RUNNING\x10\x02\x12\r\n\tCOMPLETED\x10\x04\x12\
+    \n\n\x06HALTED\x10\x05\x12\r\n\tCANCELLED\x10\x06\x12\x11\n\rUNSCHEDULAB\
+    LE\x10\x07\x12\r\n\tTIMED_OUT\x10\x08\x12\x11\n\rOUT_OF_MEMORY\x10\t\"B\
+    \n\x11JobStatusResponse\x12-\n\x0cjob_statuses\x18\x01\x20\x03(\x0b2\n.J\
+    obStatusR\x0bjobStatuses\"D\n\x10WatchJobsRequest\x12\x17\n\x07user_id\
+    \x18\x01\x20\x01(\tR\x06userId\x12\x17\n\x07job_ids\x18\x02\x20\x03(\x05\
+    R\x06jobIds\"^\n\x11WatchJobsResponse\x12-\n\x0cjob_statuses\x18\x01\x20\
+    \x03(\x0b2\n.JobStatusR\x0bjobStatuses\x12\x1a\n\x08finished\x18\x02\x20\
+    \x01(\x08R\x08finished\"D\n\x12DescribeJobRequest\x12\x17\n\x07user_id\
+    \x18\x01\x20\x01(\tR\x06userId\x12\x15\n\x06job_id\x18\x02\x20\x01(\x05R\
+    \x05jobId\"\xc8\x03\n\x0fTaskDescription\x12\x17\n\x07task_id\x18\x01\
+    \x20\x01(\tR\x06taskId\x12/\n\x06status\x18\x02\x20\x01(\x0e2\x17.TaskDe\
+    scription.StatusR\x06status\x12\x1b\n\tworker_id\x18\x03\x20\x01(\tR\x08\
+    workerId\x12\x1a\n\x08attempts\x18\x04\x20\x01(\x05R\x08attempts\x12\x1d\
+    \n\nstarted_at\x18\x05\x20\x01(\x04R\tstartedAt\x12\x1f\n\x0bfinished_at\
+    \x18\x06\x20\x01(\x04R\nfinishedAt\x12\x1d\n\ninput_size\x18\x07\x20\x01\
+    (\x04R\tinputSize\x12\x1f\n\x0boutput_size\x18\x08\x20\x01(\x04R\noutput\
+    Size\x12%\n\x0efailure_reason\x18\t\x20\x01(\tR\rfailureReason\x12\x1a\n\
+    \x08progress\x18\n\x20\x01(\x02R\x08progress\"o\n\x06Status\x12\x0c\n\
+    \x08AWAITING\x10\0\x12\x0b\n\x07RUNNING\x10\x01\x12\r\n\tCOMPLETED\x10\
+    \x02\x12\n\n\x06HALTED\x10\x03\x12\r\n\tCANCELLED\x10\x04\x12\r\n\tTIMED\
+    _OUT\x10\x05\x12\x11\n\rOUT_OF_MEMORY\x10\x06\"h\n\x13DescribeJobRespons\
+    e\x12)\n\njob_status\x18\x01\x20\x01(\x0b2\n.JobStatusR\tjobStatus\x12&\
+    \n\x05tasks\x18\x02\x20\x03(\x0b2\x10.TaskDescriptionR\x05tasks\"*\n\x12\
+    WorkerInputRequest\x12\x14\n\x05token\x18\x01\x20\x01(\tR\x05token\"\xcc\
+    \x02\n\x13WorkerInputResponse\x12)\n\x10function_closure\x18\x01\x20\x01\
     (\x0cR\x0ffunctionClosure\x127\n\x08map_type\x18\x02\x20\x01(\x0e2\x1c.W\
     orkerInputResponse.MapTypeR\x07mapType\x12\x1c\n\tdatapacks\x18\x03\x20\
-    \x03(\x0cR\tdatapacks\"U\n\x07MapType\x12\x17\n\x13SINGLE_IN_MULTI_OUT\
-    \x10\0\x12\x18\n\x14SINGLE_IN_SINGLE_OUT\x10\x01\x12\x17\n\x13MULTI_IN_S\
-    INGLE_OUT\x10\x02\"3\n\x13WorkerOutputRequest\x12\x1c\n\tdatapacks\x18\
-    \x01\x20\x03(\x0cR\tdatapacks\"\x16\n\x14WorkerOutputResponse\"\x9b\x01\
-    \n\x13SingleWorkerRequest\x12:\n\rinput_request\x18\x01\x20\x01(\x0b2\
-    \x13.WorkerInputRequestH\0R\x0cinputRequest\x12=\n\x0eoutput_request\x18\
-    \x02\x20\x01(\x0b2\x14.WorkerOutputRequestH\0R\routputRequestB\t\n\x07re\
-    quest\"\xa3\x01\n\x14SingleWorkerResponse\x12=\n\x0einput_response\x18\
-    \x01\x20\x01(\x0b2\x14.WorkerInputResponseH\0R\rinputResponse\x12@\n\x0f\
-    output_response\x18\x02\x20\x01(\x0b2\x15.WorkerOutputResponseH\0R\x0eou\
-    tputResponseB\n\n\x08response\"\x8e\x01\n\x11ConnectionRequest\x12\x17\n\
-    \x07user_id\x18\x01\x20\x01(\tR\x06userId\x121\n\x06action\x18\x02\x20\
-    \x01(\x0e2\x19.ConnectionRequest.ActionR\x06action\"-\n\x06Action\x12\r\
-    \n\tHEARTBEAT\x10\0\x12\x14\n\x10CLOSE_CONNECTION\x10\x01\"?\n\x12Connec\
-    tionResponse\x12)\n\x10request_accepted\x18\x01\x20\x01(\x08R\x0frequest\
-    Accepted\"\x87\x01\n\rServerMessage\x12-\n\x06action\x18\x01\x20\x01(\
-    \x0e2\x15.ServerMessage.ActionR\x06action\"G\n\x06Action\x12\x10\n\x0cUS\
-    ER_TIMEOUT\x10\0\x12\x10\n\x0cMISSING_JOBS\x10\x01\x12\x19\n\x15INTERNAL\
-    _SERVER_ERROR\x10\x02\"\x86\x03\n\x11SingleUserRequest\x12V\n\x19create_\
-    connection_request\x18\x01\x20\x01(\x0b2\x18.CreateConnectionRequestH\0R\
-    \x17createConnectionRequest\x12C\n\x12connection_request\x18\x02\x20\x01\
-    (\x0b2\x12.ConnectionRequestH\0R\x11connectionRequest\x127\n\x0ejob_subm\
-    ission\x18\x03\x20\x01(\x0b2\x0e.JobSubmissionH\0R\rjobSubmission\x12M\n\
-    \x16data_retrieval_request\x18\x04\x20\x01(\x0b2\x15.DataRetrievalReques\
-    tH\0R\x14dataRetrievalRequest\x12A\n\x12job_status_request\x18\x05\x20\
-    \x01(\x0b2\x11.JobStatusRequestH\0R\x10jobStatusRequestB\t\n\x07request\
-    \"\xe6\x03\n\x12SingleUserResponse\x12Y\n\x1acreate_connection_response\
-    \x18\x01\x20\x01(\x0b2\x19.CreateConnectionResponseH\0R\x18createConnect\
-    ionResponse\x12P\n\x17job_submission_response\x18\x02\x20\x01(\x0b2\x16.\
-    JobSubmissionResponseH\0R\x15jobSubmissionResponse\x12P\n\x17data_retrie\
-    val_response\x18\x03\x20\x01(\x0b2\x16.DataRetrievalResponseH\0R\x15data\
-    RetrievalResponse\x12D\n\x13job_status_response\x18\x04\x20\x01(\x0b2\
-    \x12.JobStatusResponseH\0R\x11jobStatusResponse\x12F\n\x13connection_res\
-    ponse\x18\x05\x20\x01(\x0b2\x13.ConnectionResponseH\0R\x12connectionResp\
-    onse\x127\n\x0eserver_message\x18\x06\x20\x01(\x0b2\x0e.ServerMessageH\0\
-    R\rserverMessageB\n\n\x08responseb\x06proto3\
+    \x03(\x0cR\tdatapacks\x12\x1a\n\x08shutdown\x18\x04\x20\x01(\x08R\x08shu\
+    tdown\x12\x14\n\x05token\x18\x05\x20\x01(\tR\x05token\x12*\n\nbroadcasts\
+    \x18\x06\x20\x03(\x0b2\n.BroadcastR\nbroadcasts\"U\n\x07MapType\x12\x17\
+    \n\x13SINGLE_IN_MULTI_OUT\x10\0\x12\x18\n\x14SINGLE_IN_SINGLE_OUT\x10\
+    \x01\x12\x17\n\x13MULTI_IN_SINGLE_OUT\x10\x02\"{\n\x13WorkerOutputReques\
+    t\x12\x1c\n\tdatapacks\x18\x01\x20\x03(\x0cR\tdatapacks\x12\x14\n\x05tok\
+    en\x18\x02\x20\x01(\tR\x05token\x120\n\x0caccumulators\x18\x03\x20\x03(\
+    \x0b2\x0c.AccumulatorR\x0caccumulators\"\x16\n\x14WorkerOutputResponse\"\
+    c\n\x15WorkerProgressRequest\x12\x1a\n\x08progress\x18\x01\x20\x01(\x02R\
+    \x08progress\x12\x18\n\x07message\x18\x02\x20\x01(\tR\x07message\x12\x14\
+    \n\x05token\x18\x03\x20\x01(\tR\x05token\"\x18\n\x16WorkerProgressRespon\
+    se\"\xe0\x01\n\x13SingleWorkerRequest\x12:\n\rinput_request\x18\x01\x20\
+    \x01(\x0b2\x13.WorkerInputRequestH\0R\x0cinputRequest\x12=\n\x0eoutput_r\
+    equest\x18\x02\x20\x01(\x0b2\x14.WorkerOutputRequestH\0R\routputRequest\
+    \x12C\n\x10progress_request\x18\x03\x20\x01(\x0b2\x16.WorkerProgressRequ\
+    estH\0R\x0fprogressRequestB\t\n\x07request\"\xeb\x01\n\x14SingleWorkerRe\
+    sponse\x12=\n\x0einput_response\x18\x01\x20\x01(\x0b2\x14.WorkerInputRes\
+    ponseH\0R\rinputResponse\x12@\n\x0foutput_response\x18\x02\x20\x01(\x0b2\
+    \x15.WorkerOutputResponseH\0R\x0eoutputResponse\x12F\n\x11progress_respo\
+    nse\x18\x03\x20\x01(\x0b2\x17.WorkerProgressResponseH\0R\x10progressResp\
+    onseB\n\n\x08response\"\x8e\x01\n\x11ConnectionRequest\x12\x17\n\x07user\
+    _id\x18\x01\x20\x01(\tR\x06userId\x121\n\x06action\x18\x02\x20\x01(\x0e2\
+    \x19.ConnectionRequest.ActionR\x06action\"-\n\x06Action\x12\r\n\tHEARTBE\
+    AT\x10\0\x12\x14\n\x10CLOSE_CONNECTION\x10\x01\"?\n\x12ConnectionRespons\
+    e\x12)\n\x10request_accepted\x18\x01\x20\x01(\x08R\x0frequestAccepted\"\
+    \x87\x01\n\rServerMessage\x12-\n\x06action\x18\x01\x20\x01(\x0e2\x15.Ser\
+    verMessage.ActionR\x06action\"G\n\x06Action\x12\x10\n\x0cUSER_TIMEOUT\
+    \x10\0\x12\x10\n\x0cMISSING_JOBS\x10\x01\x12\x19\n\x15INTERNAL_SERVER_ER\
+    ROR\x10\x02\"\xe1\x04\n\x11SingleUserRequest\x12V\n\x19create_connection\
+    _request\x18\x01\x20\x01(\x0b2\x18.CreateConnectionRequestH\0R\x17create\
+    ConnectionRequest\x12C\n\x12connection_request\x18\x02\x20\x01(\x0b2\x12\
+    .ConnectionRequestH\0R\x11connectionRequest\x127\n\x0ejob_submission\x18\
+    \x03\x20\x01(\x0b2\x0e.JobSubmissionH\0R\rjobSubmission\x12M\n\x16data_r\
+    etrieval_request\x18\x04\x20\x01(\x0b2\x15.DataRetrievalRequestH\0R\x14d\
+    ataRetrievalRequest\x12A\n\x12job_status_request\x18\x05\x20\x01(\x0b2\
+    \x11.JobStatusRequestH\0R\x10jobStatusRequest\x12A\n\x12watch_jobs_reque\
+    st\x18\x07\x20\x01(\x0b2\x11.WatchJobsRequestH\0R\x10watchJobsRequest\
+    \x12G\n\x14describe_job_request\x18\x08\x20\x01(\x0b2\x13.DescribeJobReq\
+    uestH\0R\x12describeJobRequest\x12M\n\x16resume_session_request\x18\t\
+    \x20\x01(\x0b2\x15.ResumeSessionRequestH\0R\x14resumeSessionRequestB\t\n\
+    \x07request\"\xca\x05\n\x12SingleUserResponse\x12Y\n\x1acreate_connectio\
+    n_response\x18\x01\x20\x01(\x0b2\x19.CreateConnectionResponseH\0R\x18cre\
+    ateConnectionResponse\x12P\n\x17job_submission_response\x18\x02\x20\x01(\
+    \x0b2\x16.JobSubmissionResponseH\0R\x15jobSubmissionResponse\x12P\n\x17d\
+    ata_retrieval_response\x18\x03\x20\x01(\x0b2\x16.DataRetrievalResponseH\
+    \0R\x15dataRetrievalResponse\x12D\n\x13job_status_response\x18\x04\x20\
+    \x01(\x0b2\x12.JobStatusResponseH\0R\x11jobStatusResponse\x12F\n\x13conn\
+    ection_response\x18\x05\x20\x01(\x0b2\x13.ConnectionResponseH\0R\x12conn\
+    ectionResponse\x127\n\x0eserver_message\x18\x06\x20\x01(\x0b2\x0e.Server\
+    MessageH\0R\rserverMessage\x12D\n\x13watch_jobs_response\x18\x07\x20\x01\
+    (\x0b2\x12.WatchJobsResponseH\0R\x11watchJobsResponse\x12J\n\x15describe\
+    _job_response\x18\x08\x20\x01(\x0b2\x14.DescribeJobResponseH\0R\x13descr\
+    ibeJobResponse\x12P\n\x17resume_session_response\x18\t\x20\x01(\x0b2\x16\
+    .ResumeSessionResponseH\0R\x15resumeSessionResponseB\n\n\x08responseb\
+    \x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -5,6 +5,10 @@
     util.rs - Helpful functions used everywhere
 */
 
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -13,8 +17,9 @@ use crossbeam_channel::Sender;
 use log::{info, warn};
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
+use sha2::{Digest, Sha256};
 
-use config::{Config, ConfigServer, WorkerState, WorkerStatus};
+use config::{Config, ConfigServer, PullPolicy, WorkerState, WorkerStatus};
 use executor::TaskCommand;
 use executor::{ServerMessage, ServerMessageType, TaskType};
 use protos::intra_cluster::WorkerTaskSubmissionRequest_MapType;
@@ -35,6 +40,7 @@ pub fn restart_worker_no_send(
         worker_state.task_type = None;
         worker_state.data_in = None;
         worker_state.closure = None;
+        worker_state.broadcasts = Vec::new();
         worker_state.status = WorkerStatus::Awaiting;
 
         // Place connection req in master
@@ -89,6 +95,7 @@ pub fn restart_worker(
         worker_state.task_type = None;
         worker_state.data_in = None;
         worker_state.closure = None;
+        worker_state.broadcasts = Vec::new();
         worker_state.status = WorkerStatus::Awaiting;
 
         executor_sender
@@ -108,6 +115,116 @@ pub fn restart_worker(
     }
 }
 
+pub fn split_label(input: &str) -> Option<(String, String)> {
+    let parts: Vec<&str> = input.splitn(2, "=").collect();
+
+    if parts.len() != 2 || parts[0].len() == 0 {
+        warn!("{} is not a valid key=value label!", &input);
+        return None;
+    }
+
+    return Some((parts[0].to_string(), parts[1].to_string()));
+}
+
+fn total_memory_mb() -> Option<u64> {
+    let meminfo = fs::read_to_string("/proc/meminfo").ok()?;
+    let line = meminfo.lines().find(|x| x.starts_with("MemTotal:"))?;
+    let kb = line.split_whitespace().nth(1)?.parse::<u64>().ok()?;
+    return Some(kb / 1024);
+}
+
+// Docker hosts given as a path or unix:// URI are local sockets, anything else is a URL
+pub fn docker_socket_path(docker_host: &str) -> Option<&str> {
+    if docker_host.starts_with("unix://") {
+        return Some(&docker_host["unix://".len()..]);
+    } else if docker_host.starts_with("/") {
+        return Some(docker_host);
+    }
+    return None;
+}
+
+pub fn detect_labels(docker_host: &str) -> HashMap<String, String> {
+    let mut labels = HashMap::new();
+    labels.insert("arch".to_string(), env::consts::ARCH.to_string());
+    labels.insert(
+        "docker".to_string(),
+        match docker_socket_path(docker_host) {
+            Some(path) => Path::new(path).exists(),
+            None => true,
+        }
+        .to_string(),
+    );
+    if let Some(memory) = total_memory_mb() {
+        labels.insert("memory_mb".to_string(), memory.to_string());
+    }
+    return labels;
+}
+
+// Compares every byte whatever the first difference, so secrets cannot be guessed one byte at a time.
+// The master has its own copy in PrimeMinister/shared/src/util.rs
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    return a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0;
+}
+
+// Only plain relative names are accepted, so users cannot leave the executables directory
+pub fn valid_executable_name(name: &str) -> bool {
+    return name.len() > 0
+        && Path::new(name).components().all(|x| match x {
+            Component::Normal(_) => true,
+            _ => false,
+        });
+}
+
+// Never passed on from the user, the worker sets the PARLIAMENT_ variables itself.
+// The master already rejects them, keep in sync with reserved_env_key in PrimeMinister/minister/src/util.rs
+pub fn reserved_env_key(key: &str) -> bool {
+    return key.starts_with("LD_") || key.starts_with("PARLIAMENT_");
+}
+
+pub fn resolve_executable(dir: &String, name: &String) -> Option<PathBuf> {
+    if !valid_executable_name(name) {
+        warn!("{} is not a valid executable name!", &name);
+        return None;
+    }
+
+    let root = fs::canonicalize(dir).ok()?;
+    let path = fs::canonicalize(root.join(name)).ok()?;
+
+    // Symlinks inside the directory could still point elsewhere
+    if !path.starts_with(&root) || !path.is_file() {
+        warn!("{} does not resolve to a file in {}!", &name, &dir);
+        return None;
+    }
+    return Some(path);
+}
+
+pub fn sha256_digest(bytes: &[u8]) -> String {
+    return format!("{:x}", Sha256::digest(bytes));
+}
+
+// Exactly what sha256_digest produces, digests double as file names in the caches
+pub fn valid_digest(digest: &str) -> bool {
+    return digest.len() == 64 && digest.bytes().all(|x| match x {
+        b'0'..=b'9' | b'a'..=b'f' => true,
+        _ => false,
+    });
+}
+
+pub fn parse_pull_policy(input: &str) -> Option<PullPolicy> {
+    return match input {
+        "always" => Some(PullPolicy::Always),
+        "missing" => Some(PullPolicy::IfNotPresent),
+        "never" => Some(PullPolicy::Never),
+        _ => {
+            warn!("{} is not a valid pull policy! Expected always, missing or never", &input);
+            None
+        }
+    };
+}
+
 pub fn int_to_bool(value: i32) -> bool {
     if value == 1 {
         return true;
@@ -167,6 +284,71 @@ mod tests {
         assert_eq!(int_to_bool(0), false);
     }
 
+    #[test]
+    fn test_sanity_split_label() {
+        assert_eq!(
+            split_label("gpu=true"),
+            Some(("gpu".to_string(), "true".to_string()))
+        );
+        assert_eq!(
+            split_label("zone=a=b"),
+            Some(("zone".to_string(), "a=b".to_string()))
+        );
+        assert_eq!(split_label("gpu"), None);
+        assert_eq!(split_label("=true"), None);
+    }
+
+    #[test]
+    fn test_sanity_valid_executable_name() {
+        assert_eq!(valid_executable_name("parallel.exe"), true);
+        assert_eq!(valid_executable_name("wordcount/1.2.0"), true);
+        assert_eq!(valid_executable_name(""), false);
+        assert_eq!(valid_executable_name("../parallel.exe"), false);
+        assert_eq!(valid_executable_name("wordcount/../../bin/sh"), false);
+        assert_eq!(valid_executable_name("/bin/sh"), false);
+    }
+
+    #[test]
+    fn test_sanity_reserved_env_key() {
+        assert_eq!(reserved_env_key("LD_LIBRARY_PATH"), true);
+        assert_eq!(reserved_env_key("PARLIAMENT_SOCKET"), true);
+        assert_eq!(reserved_env_key("HOME"), false);
+        assert_eq!(reserved_env_key("ld_preload"), false);
+    }
+
+    #[test]
+    fn test_sanity_valid_digest() {
+        assert_eq!(valid_digest(&sha256_digest(b"abc")), true);
+        assert_eq!(valid_digest(&sha256_digest(b"abc").to_uppercase()), false);
+        assert_eq!(valid_digest(&sha256_digest(b"abc")[1..]), false);
+        assert_eq!(valid_digest(&format!("{}0", sha256_digest(b"abc"))), false);
+        assert_eq!(valid_digest("parallel.exe"), false);
+        assert_eq!(valid_digest(""), false);
+    }
+
+    #[test]
+    fn test_sanity_constant_time_eq() {
+        assert_eq!(constant_time_eq(b"token", b"token"), true);
+        assert_eq!(constant_time_eq(b"token", b"tokem"), false);
+        assert_eq!(constant_time_eq(b"token", b"toke"), false);
+        assert_eq!(constant_time_eq(b"", b""), true);
+    }
+
+    #[test]
+    fn test_sanity_docker_socket_path() {
+        assert_eq!(docker_socket_path("unix:///var/run/docker.sock"), Some("/var/run/docker.sock"));
+        assert_eq!(docker_socket_path("/var/run/docker.sock"), Some("/var/run/docker.sock"));
+        assert_eq!(docker_socket_path("http://127.0.0.1:2375"), None);
+    }
+
+    #[test]
+    fn test_sanity_parse_pull_policy() {
+        assert_eq!(parse_pull_policy("always"), Some(PullPolicy::Always));
+        assert_eq!(parse_pull_policy("missing"), Some(PullPolicy::IfNotPresent));
+        assert_eq!(parse_pull_policy("never"), Some(PullPolicy::Never));
+        assert_eq!(parse_pull_policy("sometimes"), None);
+    }
+
     #[test]
     fn test_sanity_convert_map_type() {
         let type1 = WorkerTaskSubmissionRequest_MapType::SINGLE_IN_SINGLE_OUT;
@@ -9,7 +9,7 @@ exception ConnectionError of string
 
 (* Helper functions *)
 let open_connection (sockaddr:sockaddr) =
-  let sock = Unix.socket Unix.PF_INET Unix.SOCK_STREAM 0 
+  let sock = Unix.socket (Unix.domain_of_sockaddr sockaddr) Unix.SOCK_STREAM 0 
   in try Unix.connect sock sockaddr ;
     (Unix.in_channel_of_descr sock , Unix.out_channel_of_descr sock)
   with exn -> Unix.close sock ; raise exn
@@ -32,6 +32,14 @@ let send_to_master client_fun (server: string) (port:int) =
   with Failure(_) -> raise (ConnectionError "Bad port number")
      | Unix_error(e, _, _) -> raise (ConnectionError ("Could not connect to cluster! " ^ (error_message e) ^ "\nPlease check hostname and port again!" ))
 
+let send_to_socket client_fun (path: string) =
+  try
+    let ic, oc = open_connection (Unix.ADDR_UNIX path) in
+    let result = client_fun ic oc in
+    shutdown_connection ic;
+    result
+  with Unix_error(e, _, _) -> raise (ConnectionError ("Could not connect to worker socket " ^ path ^ "! " ^ (error_message e)))
+
 let request_response request response ic oc  =
   let encoder = Pbrt.Encoder.create () in
   request encoder;
@@ -54,6 +62,24 @@ let request_response request response ic oc  =
   else 
     raise (ConnectionError "Did not recieve anything back from the server!")
 
+(* Keeps reading responses off the connection until [handler] returns false *)
+let rec read_responses response handler ic =
+  let len = input_binary_int ic in
+  let bytes = Bytes.create len in
+  really_input ic bytes 0 len;
+  if handler (response (Pbrt.Decoder.of_bytes bytes)) then
+    read_responses response handler ic
+
+let request_stream request response handler ic oc =
+  let encoder = Pbrt.Encoder.create () in
+  request encoder;
+  let bytes_out = Pbrt.Encoder.to_bytes encoder in
+  output_binary_int oc (Bytes.length bytes_out);
+  output_bytes oc bytes_out;
+  flush oc;
+  try read_responses response handler ic
+  with End_of_file -> raise (ConnectionError "Connection was closed by the server!")
+
 let rec retry_handler (func: unit -> 'a) (count:int) = 
   match count with
   0 -> raise (ConnectionError "Run out of retries.")
@@ -76,3 +102,14 @@ let send_worker_request hostname port request_obj =
   let func () = send_to_master (request_response request response) (hostname) (port) in 
   retry_handler func 3
 
+let send_worker_socket_request path request_obj = 
+  let request = Parliament_proto.Worker_pb.encode_single_worker_request request_obj in 
+  let response = Parliament_proto.Worker_pb.decode_single_worker_response in
+  let func () = send_to_socket (request_response request response) path in 
+  retry_handler func 3
+
+(* Not retried, as the handler may already have seen some of the responses *)
+let send_streaming_request hostname port request_obj handler = 
+  let request = Parliament_proto.Connection_pb.encode_single_user_request request_obj in 
+  let response = Parliament_proto.Connection_pb.decode_single_user_response in
+  send_to_master (request_stream request response handler) (hostname) (port)
@@ -12,4 +12,10 @@ val send_single_request : string -> int -> Parliament_proto.Connection_types.sin
 (** [send_single_request hostname port request] sends a Protobuf Single Request object using TCP sockets to the server given by the hostname and the port number *)
 
 val send_worker_request : string -> int -> Parliament_proto.Worker_types.single_worker_request -> Parliament_proto.Worker_types.single_worker_response
-(** [send_worker_request hostname port request] sends a Protobuf Single Request object using TCP sockets to the server given by the hostname and the port number *)
\ No newline at end of file
+(** [send_worker_request hostname port request] sends a Protobuf Single Request object using TCP sockets to the server given by the hostname and the port number *)
+
+val send_worker_socket_request : string -> Parliament_proto.Worker_types.single_worker_request -> Parliament_proto.Worker_types.single_worker_response
+(** [send_worker_socket_request path request] sends a Protobuf Single Request object over the Unix domain socket at the given path *)
+
+val send_streaming_request : string -> int -> Parliament_proto.Connection_types.single_user_request -> (Parliament_proto.Connection_types.single_user_response -> bool) -> unit
+(** [send_streaming_request hostname port request handler] sends a Protobuf Single Request object to the server and passes each response to [handler], until it returns false *)
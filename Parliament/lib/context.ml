@@ -33,6 +33,9 @@ type status =
   | Completed 
   | Halted
   | Cancelled
+  | Unschedulable
+  | Timed_out
+  | Out_of_memory
 
 type running_job = {
   job_id : int32 ;
@@ -48,10 +51,17 @@ type running_job = {
   else
     "" *)
 
-let connect hn pt docker =
+let connect ?session ?executable hn pt docker =
+  let session_name, session_secret = match session with
+      Some(name, secret) -> name, secret
+    | None -> "", ""
+  in
   let single_request = Create_connection_request(Parliament_proto.Create_connection_types.({ 
       authentication = "";
       docker_name = docker;
+      executable = executable;
+      session_name = session_name;
+      session_secret = session_secret;
     })) in
   let single_response = Connection.send_single_request hn pt single_request in
   match single_response with
@@ -86,6 +96,39 @@ let connect hn pt docker =
       next_job = Int32.one ;
     })
 
+(* Reattaches to a named session, returning its jobs the cluster still holds *)
+let resume hn pt name secret =
+  let unconnected = ref {
+      hostname = hn ;
+      port = pt;
+      connection_status = Unconnected ;
+      user_id = "" ;
+      next_job = Int32.one ;
+    } in
+  let single_request = Resume_session_request(Parliament_proto.Create_connection_types.({
+      session_name = name;
+      session_secret = secret;
+    })) in
+  let single_response = Connection.send_single_request hn pt single_request in
+  match single_response with
+    Resume_session_response(response) -> (
+      match response.session_resumed with
+        true -> Util.info_print("Resumed session " ^ name ^ " as user " ^ response.user_id);
+        (ref {
+            hostname = hn ;
+            port = pt;
+            connection_status = Connected ;
+            user_id = response.user_id ;
+            next_job = response.next_job_id ;
+          }, List.map (fun x -> {job_id = x ; status = Queued}) response.job_ids)
+      | false -> Util.error_print("Could not resume session " ^ name); (unconnected, [])
+    )
+  | Server_message({action = Internal_server_error }) -> (
+      Util.error_print("Recieved an internal server error!");
+      raise InternalServerError
+    )
+  | _ -> (Util.error_print("Recieved a response from server not of type ResumeSessionResponse"); (unconnected, []))
+
 let validate ctx =
   match !ctx.connection_status with 
     Connected -> ()
@@ -118,15 +161,22 @@ let heartbeat ctx =
     )
   | _ -> (Util.error_print("Recieved a response from server not of type ConnectionResponse"); false)
 
-let submit ctx workload = 
+let submit ?workload_id ?required_labels ?task_timeout ?limits ?sink ?docker_name ?executable ctx workload = 
   validate ctx;
   let job_count = Int32.of_int (List.length workload.job_list)in
   Util.info_print("Submitting " ^ (Int32.to_string job_count) ^ " jobs to the cluster");
 
-  let jobs = Workload.build workload !ctx.next_job in
+  (* Submitting the same workload again from the same context reuses the key, so the cluster only accepts it once *)
+  let idempotency_key = match workload_id with
+      Some(id) -> id
+    | None -> "workload-" ^ (Int32.to_string !ctx.next_job)
+  in
+  let jobs = Workload.build ?required_labels ?task_timeout ?limits ?sink ?docker_name ?executable workload !ctx.next_job in
   let single_request = Job_submission(Parliament_proto.Job_types.({
       user_id = !ctx.user_id;
       jobs = jobs;
+      broadcasts = Workload.build_broadcasts workload;
+      idempotency_key = idempotency_key;
     })
     ) in
   let running_jobs_list = List.tl (List.map (fun x -> {job_id = x ; status = Queued}) (Util.range(!ctx.next_job) (Int32.add job_count !ctx.next_job))) in
@@ -141,7 +191,13 @@ let submit ctx workload =
           user_id = !ctx.user_id ;
           next_job = Int32.succ (Int32.add job_count !ctx.next_job) ;
         }; Some(running_jobs_list))
-      else None
+      else (
+        if response.conflict then
+          Util.error_print("Workload " ^ idempotency_key ^ " was already submitted with different jobs")
+        else if String.length response.error > 0 then
+          Util.error_print("Jobs were rejected: " ^ response.error);
+        None
+      )
     )
   | Server_message({action = Internal_server_error }) -> (
       Util.error_print("Recieved an internal server error!");
@@ -164,6 +220,9 @@ let job_status ctx (jobs:running_job list) =
     | Completed -> Completed
     | Halted -> Halted
     | Cancelled -> Cancelled
+    | Unschedulable -> Unschedulable
+    | Timed_out -> Timed_out
+    | Out_of_memory -> Out_of_memory
   in
   let proto_to_running_job (proto: job_status) =
     {
@@ -183,6 +242,46 @@ let job_status ctx (jobs:running_job list) =
     )
   | _ -> (Util.error_print("Recieved a response from server not of type Job_status_response"); None)
 
+let describe_job ctx (job:running_job) =
+  validate ctx;
+  let single_request = Describe_job_request({
+      user_id = !ctx.user_id;
+      job_id = job.job_id
+    })
+  in
+  let single_response = Connection.send_single_request !ctx.hostname !ctx.port single_request in 
+  match single_response with
+    Describe_job_response(response) -> Some(response)
+  | Server_message({action = Internal_server_error }) -> (
+      Util.error_print("Recieved an internal server error!");
+      raise InternalServerError
+    )
+  | Server_message({action = Missing_jobs }) -> (
+      Util.error_print("Server cannot find the job, raising exception...");
+      raise JobSubmissionException
+    )
+  | _ -> (Util.error_print("Recieved a response from server not of type Describe_job_response"); None)
+
+let accumulators ctx (job:running_job) =
+  validate ctx;
+  let single_request = Job_status_request({
+      user_id = !ctx.user_id;
+      job_ids = [job.job_id]
+    })
+  in
+  let single_response = Connection.send_single_request !ctx.hostname !ctx.port single_request in 
+  match single_response with
+    Job_status_response({job_statuses = [status]}) -> Some(List.map (fun (x:accumulator) -> (x.name, x.value)) status.accumulators)
+  | Server_message({action = Internal_server_error }) -> (
+      Util.error_print("Recieved an internal server error!");
+      raise InternalServerError
+    )
+  | Server_message({action = Missing_jobs }) -> (
+      Util.error_print("Server cannot find the job, raising exception...");
+      raise JobSubmissionException
+    )
+  | _ -> (Util.error_print("Recieved a response from server not of type Job_status_response"); None)
+
 let rec all_completed = function
   | [] -> true
   | {status = Completed; job_id =  _}::tail -> all_completed tail
@@ -192,18 +291,35 @@ let rec cancelled_or_halted = function
   | [] -> false
   | {status = Cancelled; job_id =  _}::_ -> true
   | {status = Halted; job_id =  _}::_ -> true
+  | {status = Timed_out; job_id =  _}::_ -> true
+  | {status = Out_of_memory; job_id =  _}::_ -> true
   | _::tail -> cancelled_or_halted tail
 
-let rec wait_until_output ctx (jobs:running_job list) =
+let wait_until_output ctx (jobs:running_job list) =
   validate ctx;
-  let status_option = job_status ctx jobs in
-  let status = match status_option with
-    | Some(x) -> x
-    | None -> raise NotConnnectedException in
-  match (all_completed status, cancelled_or_halted status) with
-    true, _ -> ()
-  | false, true -> raise JobErroredException
-  | false, false -> (Util.minisleep 2.0; wait_until_output ctx jobs)
+  let single_request = Watch_jobs_request({
+      user_id = !ctx.user_id;
+      job_ids = List.map (fun (x:running_job) -> x.job_id) jobs
+    })
+  in
+  (* The cluster pushes each change, the last response has the status of every job *)
+  let final_statuses = ref [] in
+  let handle_response = function
+      Watch_jobs_response(response) when response.finished -> (final_statuses := response.job_statuses; false)
+    | Watch_jobs_response(_) -> true
+    | Server_message({action = Internal_server_error }) -> (
+        Util.error_print("Recieved an internal server error!");
+        raise InternalServerError
+      )
+    | Server_message({action = Missing_jobs }) -> (
+        Util.error_print("Server cannot find all of the jobs, raising exception...");
+        raise JobSubmissionException
+      )
+    | _ -> (Util.error_print("Recieved a response from server not of type Watch_jobs_response"); raise NotConnnectedException)
+  in
+  Connection.send_streaming_request !ctx.hostname !ctx.port single_request handle_response;
+  let completed (x:job_status) = x.status = Parliament_proto.Status_types.Completed in
+  if not (List.for_all completed !final_statuses) then raise JobErroredException
 
 let output ctx job_id = 
   validate ctx;
@@ -37,6 +37,9 @@ type status =
   | Completed
   | Halted
   | Cancelled
+  | Unschedulable
+  | Timed_out
+  | Out_of_memory
 
 (** A type for modeling all of the different statuses of a job *)
 
@@ -46,23 +49,32 @@ type running_job = {
 }
 (** A tuple for holding the ID of a job and the current status of a job*)
 
-val connect : string -> int -> string -> context Pervasives.ref
-(** [connect hostname port authentication_token docker_name] initialises a connection to a Parliament cluster and then returns a reference to a context cluster. Can return an unconnected context *)
+val connect : ?session:string * string -> ?executable:Parliament_proto.Job_types.executable -> string -> int -> string -> context Pervasives.ref
+(** [connect ?session ?executable hostname port docker_name] initialises a connection to a Parliament cluster and then returns a reference to a context cluster. Tasks run in the [docker_name] image, or with the [executable] from the workers' executables directory when [docker_name] is empty. Giving a session name and secret makes the session durable, so it can be resumed later. Only the session outlives a restart of the cluster, workloads running at the time are lost and have to be submitted again. Can return an unconnected context *)
+
+val resume : string -> int -> string -> string -> context Pervasives.ref * running_job list
+(** [resume hostname port session_name session_secret] reattaches to a named session after losing the connection or a restart of the cluster, returning a context and the session's jobs the cluster still holds. Jobs are kept in memory only, so a session resumed after a restart of the cluster has no jobs and its earlier submissions can be run again. Returns an unconnected context if the session can't be resumed *)
 
 val heartbeat : context Pervasives.ref -> bool
 (** [heartbeat context] sends a heartbeat request to the cluster *)
 
-val submit : context Pervasives.ref -> Workload.workload -> running_job list option
-(** [submit context workload] submits a workload to the Parliament cluster defined by the Context information *)
+val submit : ?workload_id:string -> ?required_labels:(string * string) list -> ?task_timeout:int -> ?limits:Parliament_proto.Job_types.resource_limits -> ?sink:string -> ?docker_name:string -> ?executable:Parliament_proto.Job_types.executable -> context Pervasives.ref -> Workload.workload -> running_job list option
+(** [submit ?workload_id ?required_labels ?task_timeout ?limits ?sink ?docker_name ?executable context workload] submits a workload to the Parliament cluster defined by the Context information. Submitting the same workload again with the same [workload_id], which defaults to one derived from the context, returns the original acceptance instead of adding its jobs twice. The tasks of its map jobs only run on workers advertising every one of the [required_labels], a job is unschedulable while no such worker is connected. Each of their tasks is killed once it has run for [task_timeout] seconds, and never when it is 0, the default. The [limits] on memory, CPU and open files apply to each task, capped by the limits of its worker. With a [sink], the output of the last job, which has to be a map job, is written to that directory of the cluster instead of being kept for [output]. A [docker_name] or [executable] runs the map jobs of this workload with a different image or executable than the one given to [connect], only an executable runs them without Docker *)
 
 val job_status : context Pervasives.ref -> running_job list -> running_job list option
 (** [job_status context jobs_list] sends a request to the Parliament cluster requesting an update on the jobs specified in the list *)
 
+val describe_job : context Pervasives.ref -> running_job -> Parliament_proto.Status_types.describe_job_response option
+(** [describe_job context job] sends a request to the Parliament cluster for the details of every task in the job, such as the worker it ran on, its attempts, timings and why it failed *)
+
+val accumulators : context Pervasives.ref -> running_job -> (string * float) list option
+(** [accumulators context job] sends a request to the Parliament cluster for the accumulators reported by the job's completed tasks, summed by name *)
+
 val all_completed : running_job list -> bool
 (** [all_completed] helper function to check if {i all} of the jobs defined in the list have completed *)
 
 val cancelled_or_halted : running_job list -> bool
-(** [cancelled_or_halted] helper function to check if {i any} of the jobs defined in list have been cancelled or errored *)
+(** [cancelled_or_halted] helper function to check if {i any} of the jobs defined in list have been cancelled, errored, timed out or run out of memory *)
 
 val wait_until_output : context Pervasives.ref -> running_job list -> unit
 (** [wait_until_output context jobs_lists] will block and return the output for a particular job once all of the jobs are succesful. Will raise exception if encounters any problem while processing *)
@@ -16,6 +16,7 @@ let docker = ref ""
 let args = ref ""
 
 exception IncorrectNumberOfOutputs
+exception UnknownBroadcast of string
 
 (* Command line spec *)
 let spec =
@@ -52,27 +53,82 @@ let validate_output map_type datapack =
   | (Single_in_multi_out, _) -> ()
   | _ -> raise IncorrectNumberOfOutputs
 
-let init_worker () = 
+(* Warm workers are kept alive by Member of Parliament and ask for another task after each one *)
+let is_warm () =
+  try getenv "PARLIAMENT_WARM" = "1"
+  with Not_found -> false
+
+(* Set once running as a worker, so tasks can report their progress *)
+let worker_send = ref None
+let worker_token = ref ""
+
+(* Broadcasts of the workload of the task being run *)
+let broadcasts = ref []
+
+let broadcast name =
+  match List.find_opt (fun (x:Parliament_proto.Job_types.broadcast) -> x.name = name) !broadcasts with
+    Some(x) -> Datapack.unmarshal x.value
+  | None -> raise (UnknownBroadcast name)
+
+(* Accumulated by the task being run and sent with its output *)
+let accumulators = Hashtbl.create 8
+
+let accumulate name value =
+  let current = try Hashtbl.find accumulators name with Not_found -> 0. in
+  Hashtbl.replace accumulators name (current +. value)
+
+let report_progress progress message =
+  match !worker_send with
+    Some(send) -> ignore(send (Progress_request({ progress = progress; message = message; token = !worker_token })))
+  | None -> ()
+
+let rec run_tasks send token warm =
+  let worker_input = send (Input_request({ token = token })) in
+  match worker_input with
+    Input_response(input_data) when input_data.shutdown -> exit 0
+  | Input_response(input_data) -> (
+      (* Every task comes with a new token, the one the executor started with only fetches the first *)
+      let token = if input_data.token = "" then token else input_data.token in
+      worker_token := token;
+      let datapack_in : datapack = create_direct input_data.datapacks in
+      Util.info_print ("No of inputs: " ^ (string_of_int (Array.length datapack_in.data)) ); 
+      broadcasts := input_data.broadcasts;
+      Hashtbl.reset accumulators;
+      let job_func : (datapack -> datapack) = Marshal.from_bytes input_data.function_closure 0 in
+      let datapack_out = job_func datapack_in in
+      validate_output input_data.map_type datapack_out;
+      Util.info_print ("No of outputs: " ^ (string_of_int (Array.length datapack_out.data)) ); 
+      let worker_output = Parliament_proto.Worker_types.({
+          datapacks = get_direct datapack_out;
+          token = token;
+          accumulators = Hashtbl.fold (fun name value acc -> Parliament_proto.Status_types.({ name = name; value = value })::acc) accumulators [];
+        }) in
+      ignore(send (Output_request(worker_output)));
+      if warm then run_tasks send token warm else exit 0
+    )
+  | _ -> Util.error_print "Recieved an incorrect response from server!"; exit 201
+
+(* Member of Parliament may hand out a Unix domain socket instead of a TCP port *)
+let worker_connection () =
   try (
+    let worker_socket = getenv "PARLIAMENT_SOCKET" in
+    Util.info_print ("Attempting to connect to worker @ " ^ worker_socket) ;
+    Connection.send_worker_socket_request worker_socket
+  )
+  with Not_found -> (
     let worker_hostname = getenv "PARLIAMENT_HOST" in
     let worker_port = int_of_string (getenv "PARLIAMENT_PORT") in
     Util.info_print ("Attempting to connect to worker @ " ^ worker_hostname ^ ":" ^ (string_of_int worker_port)) ;
-    let worker_input = (Connection.send_worker_request worker_hostname worker_port Input_request) in
-    match worker_input with
-      Input_response(input_data) -> (
-        let datapack_in : datapack = create_direct input_data.datapacks in
-        Util.info_print ("No of inputs: " ^ (string_of_int (Array.length datapack_in.data)) ); 
-        let job_func : (datapack -> datapack) = Marshal.from_bytes input_data.function_closure 0 in
-        let datapack_out = job_func datapack_in in
-        validate_output input_data.map_type datapack_out;
-        Util.info_print ("No of outputs: " ^ (string_of_int (Array.length datapack_out.data)) ); 
-        let worker_output = Parliament_proto.Worker_types.({
-            datapacks = get_direct datapack_out
-          }) in
-        ignore(Connection.send_worker_request worker_hostname worker_port (Output_request(worker_output)));
-        exit 0 
-      )
-    | _ -> Util.error_print "Recieved an incorrect response from server!"; exit 201
+    Connection.send_worker_request worker_hostname worker_port
+  )
+
+let init_worker () = 
+  try (
+    let token = try getenv "PARLIAMENT_TOKEN" with Not_found -> "" in
+    let send = worker_connection () in
+    worker_send := Some(send);
+    worker_token := token;
+    run_tasks send token (is_warm ())
   )
   with Not_found -> (Util.error_print "Please check you have initialised the correct ENV variables"; exit 201)
 
@@ -8,6 +8,9 @@
 exception IncorrectNumberOfOutputs
 (** exception only thrown in worker mode. Parliament will check that the output of the function contains the correct number of outputs *)
 
+exception UnknownBroadcast of string
+(** exception thrown when a task looks up a broadcast its workload was not submitted with *)
+
 val init : unit -> Door.Context.context Pervasives.ref * string
 (** [init] must be called at the beginning of the application, which creates and returns the connection to the cluster.
     [init] adds a command line interface for passing in the command line cluster options 
@@ -26,4 +29,13 @@ val init : unit -> Door.Context.context Pervasives.ref * string
     - [-help]        print this help text and exit
                  (alias: -?)
 
-*)
\ No newline at end of file
+*)
+
+val report_progress : float -> string -> unit
+(** [report_progress progress message] reports the fraction, between 0 and 1, of the current task completed along with a message, shown to the user in the job status. Does nothing when not running as a worker *)
+
+val broadcast : string -> 'a
+(** [broadcast name] returns the value added to the running task's workload with [Workload.broadcast]. The value is unmarshalled on every call, so look it up once per task *)
+
+val accumulate : string -> float -> unit
+(** [accumulate name value] adds [value] to the accumulator [name] of the running task's job. The cluster sums them over the job's completed tasks, counting each task once even when it is retried. Does nothing when not running as a worker *)
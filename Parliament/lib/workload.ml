@@ -13,20 +13,51 @@ exception LastJobMustBeSingleOutput
 exception MaxOneInputValue
 exception IncorrectFormulationOfStages
 exception MustHaveAtLeastOneJob
+exception LastJobMustBeMapWithSink
 
 exception OnlySingleInputAtStage
 
 type job = SingleInMultiOut of (datapack -> datapack) 
          | SingleInSingleOut of (datapack -> datapack) 
          | MultiInSingleOut of (datapack -> datapack) 
+         | FlatMap of (datapack -> datapack)
+         | Repartition of int
+         | Coalesce of int
+(* FlatMap runs on every datapack in parallel, concatenating their outputs in order *)
+(* Repartition and Coalesce are run by the cluster on datapacks holding lists, without a closure *)
+
+(* How files on the cluster are split into datapacks, lines are never split across datapacks *)
+type split = Split_lines of int
+           | Split_bytes of int
+           | Split_files
+
+type source = From_datapack of datapack
+            | From_job of int32
+            | From_files of string * split
 
 type workload = {
-  input: datapack ;
+  input: source ;
   job_list : job list ;
+  broadcasts : (string * bytes) list ;
 }
 let input x = { 
-  input = x ;
+  input = From_datapack(x) ;
+  job_list = [] ;
+  broadcasts = [] ;
+}
+
+(* The output of a completed job is used as the input, without downloading and uploading it again *)
+let from_job job_id = {
+  input = From_job(job_id) ;
+  job_list = [] ;
+  broadcasts = [] ;
+}
+
+(* Files matching a path or glob in the input directory of the cluster, read without going through the client *)
+let from_files path split = {
+  input = From_files(path, split) ;
   job_list = [] ;
+  broadcasts = [] ;
 }
 
 (* FUNCTIONS *)
@@ -34,6 +65,14 @@ let input x = {
 let add wl job = {
   input = wl.input;
   job_list = job::wl.job_list ;
+  broadcasts = wl.broadcasts ;
+}
+
+(* Uploaded once with the workload instead of being captured by every closure, tasks read it with House.broadcast *)
+let broadcast wl name value = {
+  input = wl.input;
+  job_list = wl.job_list ;
+  broadcasts = (name, Datapack.marshal value)::(List.remove_assoc name wl.broadcasts) ;
 }
 
 let add_all wl jobs = 
@@ -51,11 +90,23 @@ let branch_validate wl =
     | _,MultiInSingleOut(_)::_ -> raise IncorrectFormulationOfStages
     | 0,SingleInMultiOut(_)::tail -> check (acc+1) tail
     | _,SingleInMultiOut(_)::_ -> raise IncorrectFormulationOfStages
+    | _,FlatMap(_)::tail -> check 1 tail
+    | _,Repartition(n)::_ when n < 1 -> raise IncorrectFormulationOfStages
+    | _,Coalesce(n)::_ when n < 1 -> raise IncorrectFormulationOfStages
+    | _,Repartition(1)::tail -> check 0 tail
+    | _,Coalesce(1)::tail -> check 0 tail
+    | _,Repartition(_)::tail -> check 1 tail
+    | _,Coalesce(_)::tail -> check 1 tail
   in
-  match length wl.input with
-    0 -> check 0 jobs
-  | 1 -> check 0 jobs
-  | _ -> check 1 jobs 
+  match wl.input with
+    From_job(_) -> check 1 jobs
+  | From_files(_) -> check 1 jobs
+  | From_datapack(data) -> (
+      match length data with
+        0 -> check 0 jobs
+      | 1 -> check 0 jobs
+      | _ -> check 1 jobs
+    )
 
 let validate wl =
   branch_validate wl;
@@ -64,32 +115,79 @@ let validate wl =
   else
     ()
 
-let build wl starting_id =
+(* Every map job of the workload is only placed on workers advertising all of the required labels,
+   and its tasks are killed after task_timeout seconds unless it is 0 or once they exceed the limits.
+   Only the last job writes its output to the sink, which the cluster can only do for a map job.
+   A docker_name or executable replaces the one given when connecting, for the map jobs of this workload only *)
+let build ?(required_labels=[]) ?(task_timeout=0) ?limits ?sink ?(docker_name="") ?executable wl starting_id =
   validate wl;
-  let input_job = Parliament_proto.Job_types.({
-      job_id = starting_id;
-      action = Input(Parliament_proto.Job_types.({
-          data_loc_in = get_direct wl.input
+  (match sink, wl.job_list with
+     Some(_), (Repartition(_) | Coalesce(_))::_ -> raise LastJobMustBeMapWithSink
+   | _ -> ());
+  let input_action = match wl.input with
+      From_job(job_id) -> Previous_output(Parliament_proto.Job_types.({
+          job_id = job_id
+        })
+        )
+    | From_files(path, split) -> (
+        let split_mode, split_size = (match split with
+              Split_lines(n) -> Lines, n
+            | Split_bytes(n) -> Bytes, n
+            | Split_files -> Files, 0)
+        in
+        File_input(Parliament_proto.Job_types.({
+            path = path;
+            split_mode = split_mode;
+            split_size = Int32.of_int split_size;
+          })
+          )
+      )
+    | From_datapack(data) -> Input(Parliament_proto.Job_types.({
+          data_loc_in = get_direct data
         })
         )
+  in
+  let input_job = Parliament_proto.Job_types.({
+      job_id = starting_id;
+      action = input_action
     }) in
+  let ending_id = Int32.pred (Int32.add starting_id (Int32.of_int (List.length wl.job_list))) in
   let build_job job prev_id = 
-    let map_type_val, function_closure = (match job with
-          SingleInSingleOut(closure) -> Single_in_single_out, closure
-        | MultiInSingleOut(closure) -> Multi_in_single_out, closure
-        | SingleInMultiOut(closure) -> Single_in_multi_out, closure)
+    let map_action map_type_val function_closure =
+      let closure = Marshal.to_bytes function_closure [Compat_32; Closures] in
+      Map(Parliament_proto.Job_types.({
+          map_type = map_type_val;
+          job_id_in = prev_id;
+          function_closure = closure;
+          required_labels = List.map (fun (key, value) -> ({key = key; value = value} : label)) required_labels;
+          task_timeout = Int32.of_int task_timeout;
+          limits = limits;
+          sink = if prev_id = ending_id then
+              (match sink with Some(path) -> Some(({path = path} : output_sink)) | None -> None)
+            else None;
+          docker_name = docker_name;
+          executable = executable;
+        })
+        )
     in
-    let closure = Marshal.to_bytes function_closure [Compat_32; Closures] in
-    Parliament_proto.Job_types.({
-        job_id = (Int32.succ prev_id);
-        action = Map(Parliament_proto.Job_types.({
-            map_type = map_type_val;
-            job_id_in = prev_id;
-            function_closure = closure;
+    let action = (match job with
+          SingleInSingleOut(closure) -> map_action Single_in_single_out closure
+        | MultiInSingleOut(closure) -> map_action Multi_in_single_out closure
+        | SingleInMultiOut(closure) -> map_action Single_in_multi_out closure
+        | FlatMap(closure) -> map_action Flat_map closure
+        | Repartition(n) -> Parliament_proto.Job_types.Repartition(Parliament_proto.Job_types.({
+            partitions = Int32.of_int n
           })
           )
+        | Coalesce(n) -> Parliament_proto.Job_types.Coalesce(Parliament_proto.Job_types.({
+            partitions = Int32.of_int n
+          })
+          ))
+    in
+    Parliament_proto.Job_types.({
+        job_id = (Int32.succ prev_id);
+        action = action
       }) in
-  let ending_id = Int32.pred (Int32.add starting_id (Int32.of_int (List.length wl.job_list))) in
   let rec build_jobs acc id = function 
     | [] -> acc
     | h::tail -> build_jobs ((build_job(h) (id))::acc) (Int32.pred id) (tail) 
@@ -97,3 +195,9 @@ let build wl starting_id =
 
   input_job::(build_jobs ([]) (ending_id) (wl.job_list))
 
+let build_broadcasts wl =
+  List.rev_map (fun (name, value) -> Parliament_proto.Job_types.({
+      name = name;
+      value = value;
+      digest = "";
+    })) wl.broadcasts
@@ -69,6 +69,114 @@ let suite =
       try ( validate_call() )
       with _ -> assert_failure "Exception thrown on correct input"
     );
+    "Allow FlatMap on multiple datapacks" >:: (fun _ ->
+      let single_datapack = Datapack.create 2 in
+      let example_func wl = wl in 
+      let workload = add_all (input single_datapack) 
+          [FlatMap(example_func); SingleInSingleOut(example_func); MultiInSingleOut(example_func)] 
+      in
+      let validate_call() = validate workload in 
+      try ( validate_call() )
+      with _ -> assert_failure "Exception thrown on correct input"
+    );
+    "Allow a single datapack after Coalesce" >:: (fun _ ->
+      let single_datapack = Datapack.create 2 in
+      let example_func wl = wl in 
+      let workload = add_all (input single_datapack) 
+          [Repartition(4); SingleInSingleOut(example_func); Coalesce(1); SingleInMultiOut(example_func)] 
+      in
+      let validate_call() = validate workload in 
+      try ( validate_call() )
+      with _ -> assert_failure "Exception thrown on correct input"
+    );
+    "Don't allow Repartition into no datapacks" >:: (fun _ ->
+      let single_datapack = Datapack.create 2 in
+      let workload = add_all (input single_datapack) [Repartition(0)] in
+      let validate_call() = validate workload in 
+      assert_raises IncorrectFormulationOfStages validate_call
+    );
+    "Allow MultiInSingleOut on the output of a previous job" >:: (fun _ ->
+      let example_func wl = wl in 
+      let workload = add (from_job Int32.one) (MultiInSingleOut(example_func)) in
+      let validate_call() = validate workload in 
+      try ( validate_call() )
+      with _ -> assert_failure "Exception thrown on correct input"
+    );
+    "Don't allow SingleInMultiOut on the output of a previous job" >:: (fun _ ->
+      let example_func wl = wl in 
+      let workload = add (from_job Int32.one) (SingleInMultiOut(example_func)) in
+      let validate_call() = validate workload in 
+      assert_raises IncorrectFormulationOfStages validate_call
+    );
+    "Build places the required labels on every map job" >:: (fun _ ->
+      let example_func wl = wl in 
+      let workload = add_all (input (Datapack.create 1)) 
+          [SingleInSingleOut(example_func); Coalesce(1)] 
+      in
+      let open Parliament_proto.Job_types in
+      let labels job = match job.action with
+          Map(map) -> List.map (fun (x:label) -> (x.key, x.value)) map.required_labels
+        | _ -> []
+      in
+      match build ~required_labels:[("gpu", "true")] workload Int32.one with
+        [_; map_job; coalesce_job] -> (
+          assert_equal [("gpu", "true")] (labels map_job);
+          assert_equal [] (labels coalesce_job)
+        )
+      | _ -> assert_failure "Expected an input job, a map job and a coalesce job"
+    );
+    "Build places the task timeout on every map job" >:: (fun _ ->
+      let example_func wl = wl in 
+      let workload = add (input (Datapack.create 1)) (SingleInSingleOut(example_func)) in
+      let open Parliament_proto.Job_types in
+      match build ~task_timeout:60 workload Int32.one with
+        [_; {action = Map(map); _}] -> assert_equal (Int32.of_int 60) map.task_timeout
+      | _ -> assert_failure "Expected an input job and a map job"
+    );
+    "Build places the limits on every map job" >:: (fun _ ->
+      let example_func wl = wl in 
+      let workload = add (input (Datapack.create 1)) (SingleInSingleOut(example_func)) in
+      let open Parliament_proto.Job_types in
+      let limits = {memory_mb = 512l; cpu_percent = 50l; open_files = 0l} in
+      match build ~limits:limits workload Int32.one with
+        [_; {action = Map(map); _}] -> assert_equal (Some limits) map.limits
+      | _ -> assert_failure "Expected an input job and a map job"
+    );
+    "Build places the sink on the last job only" >:: (fun _ ->
+      let example_func wl = wl in 
+      let workload = add_all (input (Datapack.create 1)) 
+          [SingleInSingleOut(example_func); SingleInSingleOut(example_func)] 
+      in
+      let open Parliament_proto.Job_types in
+      let sink job = match job.action with
+          Map(map) -> map.sink
+        | _ -> None
+      in
+      match build ~sink:"wordcount" workload Int32.one with
+        [_; first_job; last_job] -> (
+          assert_equal None (sink first_job);
+          assert_equal (Some(({path = "wordcount"} : output_sink))) (sink last_job)
+        )
+      | _ -> assert_failure "Expected an input job and two map jobs"
+    );
+    "Build places the image and executable on every map job" >:: (fun _ ->
+      let example_func wl = wl in 
+      let workload = add (input (Datapack.create 1)) (SingleInSingleOut(example_func)) in
+      let open Parliament_proto.Job_types in
+      let executable = ({name = "wordcount/1.2.0"; args = ["-v"]; env = []; artifact = Bytes.empty; artifact_digest = ""} : executable) in
+      match build ~docker_name:"ocaml:4.07" ~executable:executable workload Int32.one with
+        [_; {action = Map(map); _}] -> (
+          assert_equal "ocaml:4.07" map.docker_name;
+          assert_equal (Some executable) map.executable
+        )
+      | _ -> assert_failure "Expected an input job and a map job"
+    );
+    "Don't allow a sink after Coalesce" >:: (fun _ ->
+      let single_datapack = Datapack.create 2 in
+      let workload = add_all (input single_datapack) [Coalesce(1)] in
+      let build_call() = build ~sink:"wordcount" workload Int32.one in 
+      assert_raises LastJobMustBeMapWithSink build_call
+    );
     "Don't allow increase of Variable jobs" >:: (fun _ ->
       let single_datapack = Datapack.create 2 in
       let example_func wl = wl in 
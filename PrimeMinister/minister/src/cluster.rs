@@ -6,8 +6,11 @@
     Also manages everything
 */
 
-use std::collections::HashSet;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::{Arc, RwLock};
+use std::thread::Builder;
 use std::{thread, time};
 
 use chashmap::CHashMap;
@@ -16,28 +19,59 @@ use crossbeam_channel::Sender;
 use log::{error, info, warn};
 
 use config::State;
+use input;
+use marshal;
+use output;
 use model::JobStatus;
-use model::{JobType, TaskStatus, WJob, WTask, Worker, WorkerStatus, WorkerUpdate};
+use model::{FileInput, JobType, JobWatchers, TaskStatus, WJob, WTask, Worker, WorkerStatus, WorkerUpdate};
 use std::sync::Mutex;
+use users::sessions::{self, SessionNames};
 use users::User;
 use util;
 
-fn kick_inactive_users(users: &Arc<CHashMap<String, User>>) {
-    let oldest_acceptable_time = util::current_secs() - 100;
+// A task is a straggler once it has run this many times longer than the median of its stage
+const SPECULATION_MULTIPLIER: u64 = 2;
+const SPECULATION_MIN_RUNTIME: u64 = 5;
+
+// Workers enforce task timeouts themselves, the master only steps in after this grace period
+const TASK_DEADLINE_GRACE: u64 = 15;
+
+// Seconds a user without a named session is kept after its last request
+const USER_TTL: u64 = 100;
+
+fn kick_inactive_users(
+    users: &Arc<CHashMap<String, User>>,
+    session_names: &SessionNames,
+    session_dir: &Option<String>,
+    session_ttl: u64,
+) {
+    let now = util::current_secs();
     let jobs_to_del = Arc::new(Mutex::new(Vec::new()));
+    let sessions_to_del = Mutex::new(Vec::new());
 
     users.retain(|k, v| {
-        if v.last_request > oldest_acceptable_time {
+        let ttl = if v.session.is_some() { session_ttl } else { USER_TTL };
+        if v.last_request > now.saturating_sub(ttl) {
             return true;
         } else {
             warn!("Kicking user {}", k);
             v.jobs
                 .iter()
                 .for_each(|x| jobs_to_del.lock().unwrap().push(x.clone()));
+            if let Some(session) = &v.session {
+                sessions_to_del.lock().unwrap().push(session.name.clone());
+            }
             return false;
         }
     });
 
+    for name in sessions_to_del.into_inner().unwrap() {
+        session_names.release(&name);
+        if let Some(session_dir) = session_dir {
+            sessions::remove(session_dir, &name);
+        }
+    }
+
     //    let tasks = jobs_to_del.lock().unwrap()
     //        .iter()
     //        .map(|x| jobs.get(&x).unwrap())
@@ -48,13 +82,129 @@ fn kick_inactive_users(users: &Arc<CHashMap<String, User>>) {
     // TODO Will need to clear and pre-empt any running tasks and clear all data
 }
 
+// Artifacts are released once no user or unfinished job runs them. Only the ones stored before the
+// sweep started can go, as new artifacts are stored after the user or job that runs them is added
+fn release_unused_artifacts(
+    users: &Arc<CHashMap<String, User>>,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+) {
+    let stored = Mutex::new(Vec::new());
+    data.retain(|key, _| {
+        if key.starts_with(util::ARTIFACT_PREFIX) {
+            stored.lock().unwrap().push(key.clone());
+        }
+        return true;
+    });
+
+    let in_use = Mutex::new(HashSet::new());
+    users.retain(|_, user| {
+        in_use.lock().unwrap().insert(util::artifact_key(&user.executable.artifact_digest));
+        return true;
+    });
+    jobs.retain(|_, job| {
+        match job.status {
+            JobStatus::Blocked | JobStatus::Running | JobStatus::Committing => {
+                in_use.lock().unwrap().insert(util::artifact_key(&job.executable.artifact_digest));
+            }
+            _ => (),
+        }
+        return true;
+    });
+
+    let in_use = in_use.into_inner().unwrap();
+    for key in stored.into_inner().unwrap() {
+        if !in_use.contains(&key) {
+            info!("Artifact {} is no longer in use, releasing it", &key);
+            data.remove(&key);
+        }
+    }
+}
+
+fn halt_workload(
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    watchers: &Arc<JobWatchers>,
+    job_id: &String,
+) {
+    let mut next_job_id = Some(job_id.clone());
+    let mut status = JobStatus::Halted;
+    let mut last_job = None;
+    while let Some(id) = next_job_id {
+        next_job_id = match jobs.get_mut(&id) {
+            Some(mut job) => {
+                job.status = status.clone();
+                watchers.notify(&job.id);
+                last_job = Some((job.id.clone(), job.pinned_input.clone()));
+                job.output_job_id.clone()
+            }
+            None => None,
+        };
+        status = JobStatus::Cancelled;
+    }
+    if let Some((last_job_id, Some(source_id))) = last_job {
+        unpin_output(jobs, data, &source_id, &last_job_id);
+    }
+}
+
+// Reading the files can take a while, so the job is queued again once they have been loaded
+fn load_file_input(
+    job_id: String,
+    input_job_id: String,
+    file_input: FileInput,
+    input_dir: &Option<String>,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    jobs_queue: &Arc<MsQueue<String>>,
+    watchers: &Arc<JobWatchers>,
+) -> bool {
+    let input_dir = input_dir.clone();
+    let jobs = jobs.clone();
+    let data = data.clone();
+    let jobs_queue = jobs_queue.clone();
+    let watchers = watchers.clone();
+
+    let result = Builder::new()
+        .name(format!("loader-{}", &job_id))
+        .spawn(move || match input::load(&input_dir, &file_input) {
+            Ok(datapacks) => {
+                info!(
+                    "Read {} datapacks from {} for job {}",
+                    datapacks.len(),
+                    &file_input.pattern,
+                    &job_id
+                );
+                data.insert(input_job_id, datapacks);
+                jobs_queue.push(job_id);
+            }
+            Err(e) => {
+                error!(
+                    "Could not read the input of job {} from {}! Error: {}",
+                    &job_id, &file_input.pattern, e
+                );
+                data.remove(&input_job_id);
+                halt_workload(&jobs, &data, &watchers, &job_id);
+            }
+        });
+    return match result {
+        Ok(_) => true,
+        Err(e) => {
+            error!("Could not start loader thread! Error: {}", e.to_string());
+            false
+        }
+    };
+}
+
 fn create_tasks_from_queued_jobs(
     jobs: &Arc<CHashMap<String, WJob>>,
     data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
     jobs_queue: &Arc<MsQueue<String>>,
     tasks: &Arc<CHashMap<String, WTask>>,
     tasks_queue: &Arc<MsQueue<String>>,
+    watchers: &Arc<JobWatchers>,
+    input_dir: &Option<String>,
 ) {
+    let mut missing_input = Vec::new();
     while !jobs_queue.is_empty() {
         let job_id = jobs_queue.pop();
         info!("Processing job {} from job queue!", job_id);
@@ -65,6 +215,24 @@ fn create_tasks_from_queued_jobs(
                 continue;
             }
 
+            if let Some(file_input) = job.file_input.take() {
+                let loading = load_file_input(
+                    job_id.clone(),
+                    job.input_job_id.clone(),
+                    file_input,
+                    input_dir,
+                    jobs,
+                    data,
+                    jobs_queue,
+                    watchers,
+                );
+                if !loading {
+                    job.status = JobStatus::Halted;
+                    watchers.notify(&job.id);
+                }
+                continue;
+            }
+
             match job.job_type {
                 JobType::SingleInMultiOut => {
                     let task_id = format!("{}-{}", job_id, 0);
@@ -76,10 +244,28 @@ fn create_tasks_from_queued_jobs(
                         data_in_loc: 0,
                         data_out_id: job_id.clone(),
                         docker_name: job.docker_name.clone(),
+                        executable: job.executable.clone(),
                         data_out_loc: -1,
                         closure: job.closure.clone(),
+                        closure_digest: job.closure_digest.clone(),
                         status: TaskStatus::Awaiting,
                         job_type: JobType::SingleInMultiOut,
+                        required_labels: job.required_labels.clone(),
+                        started_at: 0,
+                        finished_at: 0,
+                        backup_worker: None,
+                        timeout: job.task_timeout,
+                        limits: job.limits.clone(),
+                        progress: 0.0,
+                        progress_message: "".to_string(),
+                        progress_updated_at: 0,
+                        worker_id: "".to_string(),
+                        attempts: 0,
+                        input_size: 0,
+                        output_size: 0,
+                        failure_reason: "".to_string(),
+                        broadcasts: job.broadcasts.clone(),
+                        accumulators: Vec::new(),
                     };
 
                     tasks.insert(task_id.clone(), task);
@@ -87,16 +273,24 @@ fn create_tasks_from_queued_jobs(
                     job.tasks.insert(task_id.clone());
                     job.total_tasks = 1;
                     job.status = JobStatus::Running;
+                    watchers.notify(&job_id);
                     info!("Created task {} for job {}", task_id, job_id);
                 }
-                JobType::SingleInSingleOut => {
-                    let input_data = match data.get(&job.input_job_id) {
-                        Some(data) => data,
-                        None => panic!(),
+                JobType::SingleInSingleOut | JobType::FlatMap => {
+                    let no_of_input = match data.get(&job.input_job_id) {
+                        Some(data) => data.len(),
+                        None => {
+                            error!("Input {} of job {} is gone, halting its workload!", &job.input_job_id, &job_id);
+                            missing_input.push(job_id.clone());
+                            continue;
+                        }
                     };
-                    let no_of_input = input_data.len();
                     if no_of_input == 0 {
-                        warn!("SingleInSingleOut job has 0 inputs, no tasks will be created!");
+                        warn!("{:?} job has 0 inputs, no tasks will be created!", job.job_type);
+                    }
+                    // Each task writes to the place of its input, whatever order they complete in
+                    if job.job_type == JobType::SingleInSingleOut {
+                        data.insert(job_id.clone(), vec![Vec::new(); no_of_input]);
                     }
                     for i in 0..no_of_input {
                         let task_id = format!("{}-{}", job_id, i);
@@ -109,20 +303,57 @@ fn create_tasks_from_queued_jobs(
                             data_out_id: job_id.clone(),
                             data_out_loc: i as i32,
                             closure: job.closure.clone(),
+                            closure_digest: job.closure_digest.clone(),
                             docker_name: job.docker_name.clone(),
+                            executable: job.executable.clone(),
                             status: TaskStatus::Awaiting,
-                            job_type: JobType::SingleInSingleOut,
+                            job_type: job.job_type.clone(),
+                            required_labels: job.required_labels.clone(),
+                            started_at: 0,
+                            finished_at: 0,
+                            backup_worker: None,
+                            timeout: job.task_timeout,
+                            limits: job.limits.clone(),
+                            progress: 0.0,
+                            progress_message: "".to_string(),
+                            progress_updated_at: 0,
+                            worker_id: "".to_string(),
+                            attempts: 0,
+                            input_size: 0,
+                            output_size: 0,
+                            failure_reason: "".to_string(),
+                            broadcasts: job.broadcasts.clone(),
+                            accumulators: Vec::new(),
                         };
 
                         tasks.insert(task_id.clone(), task);
                         tasks_queue.push(task_id.clone());
                         job.tasks.insert(task_id.clone());
                         job.status = JobStatus::Running;
+                        watchers.notify(&job_id);
                         info!("Created task {} for job {}", task_id, job_id);
                     }
-                    job.total_tasks = input_data.len().clone() as i32;
+                    job.total_tasks = no_of_input as i32;
+                }
+                JobType::Repartition(_) | JobType::Coalesce(_) => {
+                    job.status = JobStatus::Running;
+                    watchers.notify(&job_id);
+                    let reshaping = reshape_data(
+                        job_id.clone(),
+                        job.input_job_id.clone(),
+                        job.job_type.clone(),
+                        jobs,
+                        data,
+                        jobs_queue,
+                        watchers,
+                    );
+                    if !reshaping {
+                        job.status = JobStatus::Halted;
+                        watchers.notify(&job.id);
+                    }
                 }
                 JobType::MultiInSingleOut => {
+                    data.insert(job_id.clone(), vec![Vec::new()]);
                     let task_id = format!("{}-{}", job_id, 0);
                     let task = WTask {
                         id: task_id.clone(),
@@ -133,9 +364,27 @@ fn create_tasks_from_queued_jobs(
                         data_out_id: job_id.clone(),
                         data_out_loc: 0,
                         closure: job.closure.clone(),
+                        closure_digest: job.closure_digest.clone(),
                         docker_name: job.docker_name.clone(),
+                        executable: job.executable.clone(),
                         status: TaskStatus::Awaiting,
                         job_type: JobType::MultiInSingleOut,
+                        required_labels: job.required_labels.clone(),
+                        started_at: 0,
+                        finished_at: 0,
+                        backup_worker: None,
+                        timeout: job.task_timeout,
+                        limits: job.limits.clone(),
+                        progress: 0.0,
+                        progress_message: "".to_string(),
+                        progress_updated_at: 0,
+                        worker_id: "".to_string(),
+                        attempts: 0,
+                        input_size: 0,
+                        output_size: 0,
+                        failure_reason: "".to_string(),
+                        broadcasts: job.broadcasts.clone(),
+                        accumulators: Vec::new(),
                     };
 
                     tasks.insert(task_id.clone(), task);
@@ -143,6 +392,7 @@ fn create_tasks_from_queued_jobs(
                     job.tasks.insert(task_id.clone());
                     job.total_tasks = 1;
                     job.status = JobStatus::Running;
+                    watchers.notify(&job_id);
                     info!("Created task {} for job {}", job_id, task_id);
                 }
             }
@@ -150,16 +400,25 @@ fn create_tasks_from_queued_jobs(
             warn!("Job {} was in queue, however removed from map", job_id);
         }
     }
+
+    // Halted once the job's lock has been released
+    for job_id in missing_input {
+        halt_workload(jobs, data, watchers, &job_id);
+    }
 }
 
 fn available_worker(
     names: &Vec<String>,
     workers: &Arc<CHashMap<String, Worker>>,
+    required_labels: &HashMap<String, String>,
 ) -> Option<String> {
     for name in names {
         match workers.get_mut(name) {
             Some(mut worker) => {
-                if worker.status == WorkerStatus::Awaiting && !worker.assigned {
+                if worker.status == WorkerStatus::Awaiting
+                    && !worker.assigned
+                    && util::labels_match(required_labels, &worker.labels)
+                {
                     worker.assigned = true;
                     return Some(worker.id.clone());
                 }
@@ -170,48 +429,228 @@ fn available_worker(
     None
 }
 
+fn free_worker_exists(names: &Vec<String>, workers: &Arc<CHashMap<String, Worker>>) -> bool {
+    return names
+        .iter()
+        .filter_map(|x| workers.get(x))
+        .any(|x| x.status == WorkerStatus::Awaiting && !x.assigned);
+}
+
+fn matching_worker_exists(
+    names: &Vec<String>,
+    workers: &Arc<CHashMap<String, Worker>>,
+    required_labels: &HashMap<String, String>,
+) -> bool {
+    // Halted, cancelled and finishing workers are on their way out and will not take the task
+    return names
+        .iter()
+        .filter_map(|x| workers.get(x))
+        .filter(|x| x.status == WorkerStatus::Awaiting || x.status == WorkerStatus::Processing)
+        .any(|x| util::labels_match(required_labels, &x.labels));
+}
+
 fn assign_tasks_to_workers(
     worker_names: &Arc<RwLock<Vec<String>>>,
     workers: &Arc<CHashMap<String, Worker>>,
     tasks: &Arc<CHashMap<String, WTask>>,
     tasks_queue: &Arc<MsQueue<String>>,
+    jobs: &Arc<CHashMap<String, WJob>>,
     running_tasks: &Arc<RwLock<HashSet<String>>>,
     update_sender: &Sender<WorkerUpdate>,
+    watchers: &Arc<JobWatchers>,
 ) {
-    loop {
-        if !tasks_queue.is_empty() {
-            if let Some(worker_id) = available_worker(&worker_names.read().unwrap(), workers) {
-                let task_id = tasks_queue.pop();
-                match tasks.get(&task_id) {
-                    Some(task) => {
-                        if task.status == TaskStatus::Awaiting {
-                            let mut worker = workers.get_mut(&worker_id).unwrap();
-                            match update_sender
-                                .send(WorkerUpdate::submission(&worker, task_id.clone()))
-                            {
-                                Ok(_) => {
-                                    worker.assigned = true;
-                                    worker.running_task = Some(task_id.clone());
-                                    running_tasks.write().unwrap().insert(task_id.clone());
-                                }
-                                Err(e) => error!(
-                                    "Could not add worker_update to channel! Error: {}",
-                                    e.to_string()
-                                ),
-                            }
+    let names = worker_names.read().unwrap();
+    let mut deferred_tasks = Vec::new();
+
+    // Tasks that cannot be placed yet keep their position in the queue
+    while let Some(task_id) = tasks_queue.try_pop() {
+        if !free_worker_exists(&names, workers) {
+            deferred_tasks.push(task_id);
+            continue;
+        }
+
+        let task = match tasks.get(&task_id) {
+            Some(task) => task.clone(),
+            None => continue,
+        };
+        if task.status != TaskStatus::Awaiting {
+            continue;
+        }
+
+        if let Some(worker_id) = available_worker(&names, workers, &task.required_labels) {
+            let mut worker = workers.get_mut(&worker_id).unwrap();
+            match update_sender.send(WorkerUpdate::submission(&worker, task_id.clone())) {
+                Ok(_) => {
+                    worker.assigned = true;
+                    worker.running_task = Some(task_id.clone());
+                    running_tasks.write().unwrap().insert(task_id.clone());
+
+                    if let Some(mut task) = tasks.get_mut(&task_id) {
+                        task.started_at = util::current_secs();
+                    }
+
+                    if let Some(mut job) = jobs.get_mut(&task.job_id) {
+                        if job.status == JobStatus::Unschedulable {
+                            info!("Job {} can be scheduled again", &job.id);
+                            job.status = JobStatus::Running;
+                            watchers.notify(&job.id);
                         }
                     }
-                    None => (),
                 }
-            } else {
-                break;
+                Err(e) => error!(
+                    "Could not add worker_update to channel! Error: {}",
+                    e.to_string()
+                ),
             }
         } else {
+            if !matching_worker_exists(&names, workers, &task.required_labels) {
+                if let Some(mut job) = jobs.get_mut(&task.job_id) {
+                    if job.status == JobStatus::Running {
+                        warn!(
+                            "No worker matches the labels {:?} required by job {}, marking as unschedulable",
+                            &job.required_labels, &job.id
+                        );
+                        job.status = JobStatus::Unschedulable;
+                        watchers.notify(&job.id);
+                    }
+                }
+            }
+            deferred_tasks.push(task_id);
+        }
+    }
+
+    for task_id in deferred_tasks {
+        tasks_queue.push(task_id);
+    }
+}
+
+fn speculate_straggler_tasks(
+    worker_names: &Arc<RwLock<Vec<String>>>,
+    workers: &Arc<CHashMap<String, Worker>>,
+    tasks: &Arc<CHashMap<String, WTask>>,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    running_tasks: &Arc<RwLock<HashSet<String>>>,
+    update_sender: &Sender<WorkerUpdate>,
+) {
+    let now = util::current_secs();
+    let running: Vec<WTask> = running_tasks
+        .read()
+        .unwrap()
+        .iter()
+        .filter_map(|x| tasks.get(x))
+        .map(|x| x.clone())
+        .collect();
+
+    for task in running {
+        let worker_id = match task.status {
+            TaskStatus::Running(ref worker_id) => worker_id.clone(),
+            _ => continue,
+        };
+        if task.backup_worker.is_some() {
+            continue;
+        }
+
+        // Only speculate once at least half of the stage has finished
+        let threshold = match jobs.get(&task.job_id) {
+            Some(job) => {
+                if job.task_runtimes.is_empty()
+                    || job.task_runtimes.len() * 2 < job.total_tasks as usize
+                {
+                    continue;
+                }
+                cmp::max(
+                    util::median(&job.task_runtimes) * SPECULATION_MULTIPLIER,
+                    SPECULATION_MIN_RUNTIME,
+                )
+            }
+            None => continue,
+        };
+
+        let runtime = now.saturating_sub(task.started_at);
+        if runtime <= threshold {
+            continue;
+        }
+
+        let backup_option =
+            available_worker(&worker_names.read().unwrap(), workers, &task.required_labels);
+        if let Some(backup_id) = backup_option {
+            info!(
+                "Task {} has been running for {}s on worker {}, launching a speculative copy on worker {}",
+                &task.id, runtime, &worker_id, &backup_id
+            );
+            if let Some(mut stored_task) = tasks.get_mut(&task.id) {
+                stored_task.backup_worker = Some(backup_id.clone());
+            }
+
+            let mut backup = workers.get_mut(&backup_id).unwrap();
+            match update_sender.send(WorkerUpdate::submission(&backup, task.id.clone())) {
+                Ok(_) => {
+                    backup.running_task = Some(task.id.clone());
+                }
+                Err(e) => {
+                    backup.assigned = false;
+                    if let Some(mut stored_task) = tasks.get_mut(&task.id) {
+                        stored_task.backup_worker = None;
+                    }
+                    error!(
+                        "Could not add worker_update to channel! Error: {}",
+                        e.to_string()
+                    );
+                }
+            }
+        } else {
+            // No idle workers left to run copies on
             break;
         }
     }
 }
 
+fn enforce_task_deadlines(
+    worker_names: &Arc<RwLock<Vec<String>>>,
+    workers: &Arc<CHashMap<String, Worker>>,
+    tasks: &Arc<CHashMap<String, WTask>>,
+    running_tasks: &Arc<RwLock<HashSet<String>>>,
+    update_sender: &Sender<WorkerUpdate>,
+) {
+    let now = util::current_secs();
+    let mut workers_to_remove = Vec::new();
+
+    for task_id in running_tasks.read().unwrap().iter() {
+        if let Some(mut task) = tasks.get_mut(task_id) {
+            if task.timeout == 0 || now.saturating_sub(task.started_at) <= task.timeout + TASK_DEADLINE_GRACE {
+                continue;
+            }
+            let worker_id = match task.status {
+                TaskStatus::Running(ref worker_id) => worker_id.clone(),
+                _ => continue,
+            };
+
+            warn!(
+                "Task {} has exceeded its {}s timeout on worker {}, cancelling",
+                task_id, task.timeout, &worker_id
+            );
+            task.failure_reason = format!("Exceeded its {}s timeout on worker {}", task.timeout, &worker_id);
+            workers_to_remove.push(worker_id);
+            workers_to_remove.extend(task.backup_worker.take());
+            task.status = TaskStatus::TimedOut;
+            task.finished_at = now;
+        }
+    }
+
+    // The workers restart once cancelled, so they are no longer part of the pool
+    for worker_id in workers_to_remove {
+        if let Some(worker) = workers.remove(&worker_id) {
+            if let Err(e) = update_sender.send(WorkerUpdate::cancellation(&worker)) {
+                error!(
+                    "Could not add worker_update to channel! Error: {}",
+                    e.to_string()
+                );
+            }
+        }
+        util::vec_remove(&mut worker_names.write().unwrap(), worker_id);
+    }
+}
+
 fn send_heartbeat_requests(
     worker_names: &Arc<RwLock<Vec<String>>>,
     workers: &Arc<CHashMap<String, Worker>>,
@@ -253,11 +692,20 @@ fn detect_worker_crashes(
             info!("Removing worker {}, reached heartbeat limit!", worker_id);
 
             if let Some(task_id) = &worker.running_task {
-                info!("Had to reschedule task {} running on worker!", &task_id);
                 let mut task = tasks.get_mut(task_id).unwrap();
-                task.status = TaskStatus::Awaiting;
-                running_tasks.write().unwrap().remove(worker_id);
-                tasks_queue.push(task_id.clone()); //TODO FIX: This is annoying, task placed at the back of the queue
+                if let Some(backup_id) = task.backup_worker.take() {
+                    // The other copy of a speculated task carries on
+                    info!("Task {} still has a copy running, not rescheduling", &task_id);
+                    if backup_id != *worker_id {
+                        task.status = TaskStatus::Running(backup_id);
+                    }
+                } else {
+                    info!("Had to reschedule task {} running on worker!", &task_id);
+                    task.status = TaskStatus::Awaiting;
+                    task.failure_reason = format!("Worker {} stopped responding", worker_id);
+                    running_tasks.write().unwrap().remove(worker_id);
+                    tasks_queue.push(task_id.clone()); //TODO FIX: This is annoying, task placed at the back of the queue
+                }
             }
             workers.remove(&worker_id);
             return false;
@@ -267,12 +715,203 @@ fn detect_worker_crashes(
     });
 }
 
+// Outputs read by another workload are only removed once none of them need it anymore
+fn release_output(
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    job_id: &String,
+) {
+    if let Some(mut job) = jobs.get_mut(job_id) {
+        // Marked under the job's lock, so a workload about to pin the output sees it is going away
+        job.output_released = true;
+        if job.pinned_by.is_empty() {
+            data.remove(job_id);
+        }
+        return;
+    }
+    data.remove(job_id);
+}
+
+pub fn unpin_output(
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    job_id: &String,
+    dependent_id: &String,
+) {
+    if let Some(mut job) = jobs.get_mut(job_id) {
+        job.pinned_by.remove(dependent_id);
+        if job.pinned_by.is_empty() && job.output_released {
+            info!("Output of {} is no longer in use, cleaning it up", job_id);
+            data.remove(job_id);
+        }
+    }
+}
+
+// Placed in input order, however the tasks completed
+fn concatenate_partial_outputs(job: &WJob, data: &Arc<CHashMap<String, Vec<Vec<u8>>>>) {
+    let mut output = Vec::new();
+    for i in 0..job.total_tasks {
+        if let Some(partial) = data.remove(&util::partial_output_key(&job.id, i)) {
+            output.extend(partial);
+        }
+    }
+    data.insert(job.id.clone(), output);
+}
+
+fn discard_partial_outputs(job: &WJob, data: &Arc<CHashMap<String, Vec<Vec<u8>>>>) {
+    for i in 0..job.total_tasks {
+        data.remove(&util::partial_output_key(&job.id, i));
+    }
+}
+
+fn finish_job(
+    job: &mut WJob,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    jobs_queue: &Arc<MsQueue<String>>,
+    watchers: &Arc<JobWatchers>,
+) {
+    job.status = JobStatus::Completed;
+    watchers.notify(&job.id);
+    if let Some(output_job) = &job.output_job_id {
+        info!(
+            "All tasks for {} have completed, placing next job on queue",
+            &job.id
+        );
+        jobs_queue.push(output_job.clone());
+
+        // The output of another workload is left for the last job to unpin
+        if job.pinned_input.as_ref() != Some(&job.input_job_id) {
+            info!("Going to clean up data now!");
+            release_output(jobs, data, &job.input_job_id);
+        }
+    } else {
+        info!("All tasks for {} have completed", &job.id);
+        if let Some(source_id) = &job.pinned_input {
+            unpin_output(jobs, data, source_id, &job.id);
+        }
+    }
+}
+
+// The job is only completed once its output has been written, which happens on its own thread
+fn commit_output(
+    job_id: String,
+    sink: String,
+    output_dir: &Option<String>,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    jobs_queue: &Arc<MsQueue<String>>,
+    watchers: &Arc<JobWatchers>,
+) -> bool {
+    let output_dir = output_dir.clone();
+    let jobs = jobs.clone();
+    let data = data.clone();
+    let jobs_queue = jobs_queue.clone();
+    let watchers = watchers.clone();
+
+    let result = Builder::new()
+        .name(format!("sink-{}", &job_id))
+        .spawn(move || {
+            // Copied out first, so the output is not locked while it is written to disk
+            let datapacks = data.get(&job_id).map(|x| x.clone());
+            let result = match datapacks {
+                Some(datapacks) => output::commit(&output_dir, &sink, &datapacks),
+                None => Err("The job has no output".to_string()),
+            };
+            match result {
+                Ok(path) => {
+                    info!("Wrote the output of job {} to {}", &job_id, path.display());
+                    let last_job = match jobs.get_mut(&job_id) {
+                        Some(mut job) => {
+                            finish_job(&mut job, &jobs, &data, &jobs_queue, &watchers);
+                            job.output_job_id.is_none()
+                        }
+                        None => false,
+                    };
+                    // Nothing else reads the output of the last job, so it is only kept in the sink
+                    if last_job {
+                        release_output(&jobs, &data, &job_id);
+                    }
+                }
+                Err(e) => {
+                    error!(
+                        "Could not write the output of job {} to {}! Error: {}",
+                        &job_id, &sink, e
+                    );
+                    halt_workload(&jobs, &data, &watchers, &job_id);
+                }
+            }
+        });
+    return match result {
+        Ok(_) => true,
+        Err(e) => {
+            error!("Could not start sink thread! Error: {}", e.to_string());
+            false
+        }
+    };
+}
+
+// Repartitioning is done by the master on its own thread, the job completes once the new datapacks are in place
+fn reshape_data(
+    job_id: String,
+    input_job_id: String,
+    job_type: JobType,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    jobs_queue: &Arc<MsQueue<String>>,
+    watchers: &Arc<JobWatchers>,
+) -> bool {
+    let jobs = jobs.clone();
+    let data = data.clone();
+    let jobs_queue = jobs_queue.clone();
+    let watchers = watchers.clone();
+
+    let result = Builder::new()
+        .name(format!("reshape-{}", &job_id))
+        .spawn(move || {
+            let result = match data.get(&input_job_id) {
+                // A panic would otherwise leave the job running forever
+                Some(datapacks) => panic::catch_unwind(AssertUnwindSafe(|| match job_type {
+                    JobType::Repartition(partitions) => marshal::repartition(&datapacks, partitions),
+                    JobType::Coalesce(partitions) => marshal::coalesce(&datapacks, partitions),
+                    _ => Err(format!("{:?} jobs are run by workers", job_type)),
+                }))
+                .unwrap_or_else(|_| Err("Reading the datapacks panicked".to_string())),
+                None => Err("The job has no input".to_string()),
+            };
+            match result {
+                Ok(datapacks) => {
+                    info!("Placed {} datapacks for job {}", datapacks.len(), &job_id);
+                    if let Some(mut job) = jobs.get_mut(&job_id) {
+                        data.insert(job_id.clone(), datapacks);
+                        job.total_tasks = 1;
+                        job.completed_tasks = 1;
+                        finish_job(&mut job, &jobs, &data, &jobs_queue, &watchers);
+                    }
+                }
+                Err(e) => {
+                    error!("Could not repartition the input of job {}! Error: {}", &job_id, e);
+                    halt_workload(&jobs, &data, &watchers, &job_id);
+                }
+            }
+        });
+    return match result {
+        Ok(_) => true,
+        Err(e) => {
+            error!("Could not start reshape thread! Error: {}", e.to_string());
+            false
+        }
+    };
+}
+
 fn handle_finished_tasks(
     running_tasks: &Arc<RwLock<HashSet<String>>>,
     data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
     tasks: &Arc<CHashMap<String, WTask>>,
     jobs_queue: &Arc<MsQueue<String>>,
     jobs: &Arc<CHashMap<String, WJob>>,
+    watchers: &Arc<JobWatchers>,
+    output_dir: &Option<String>,
 ) {
     running_tasks.write().unwrap().retain(|x| {
         let task_option = tasks.get(x);
@@ -284,25 +923,43 @@ fn handle_finished_tasks(
         if task.status == TaskStatus::Completed {
             let mut job = jobs.get_mut(&task.job_id).unwrap();
             job.completed_tasks += 1;
+            job.task_runtimes
+                .push(task.finished_at.saturating_sub(task.started_at));
+            // Only the attempt that completed the task is counted, so retries do not add to them
+            util::add_accumulators(&mut job.accumulators, &task.accumulators);
             if job.completed_tasks == job.total_tasks {
-                job.status = JobStatus::Completed;
-                if let Some(output_job) = &job.output_job_id {
+                if job.job_type == JobType::FlatMap {
+                    concatenate_partial_outputs(&job, data);
+                }
+                if let Some(sink) = job.sink.clone() {
                     info!(
-                        "All tasks for {} have completed, placing next job on queue",
-                        &task.job_id
+                        "All tasks for {} have completed, writing its output to {}",
+                        &task.job_id, &sink
                     );
-                    jobs_queue.push(output_job.clone());
-
-                    info!("Going to clean up data now!");
-                    data.remove(&job.input_job_id);
+                    job.status = JobStatus::Committing;
+                    watchers.notify(&job.id);
+                    let committing = commit_output(
+                        job.id.clone(),
+                        sink,
+                        output_dir,
+                        jobs,
+                        data,
+                        jobs_queue,
+                        watchers,
+                    );
+                    if !committing {
+                        job.status = JobStatus::Halted;
+                        watchers.notify(&job.id);
+                    }
                 } else {
-                    info!("All tasks for {} have completed", &task.job_id);
+                    finish_job(&mut job, jobs, data, jobs_queue, watchers);
                 }
             } else {
                 info!(
                     "Completed tasks {}/{} for job {}",
                     &job.completed_tasks, &job.total_tasks, &task.job_id
                 );
+                watchers.notify(&job.id);
             }
             return false;
         } else {
@@ -320,6 +977,7 @@ fn handle_errored_tasks(
     update_sender: &Sender<WorkerUpdate>,
     consensus_mode: bool,
     consensus_state: &Arc<State>,
+    watchers: &Arc<JobWatchers>,
 ) {
     running_tasks.write().unwrap()
         .retain(|x| {
@@ -332,31 +990,45 @@ fn handle_errored_tasks(
             let task = task_option.unwrap().clone();
             if task.status == TaskStatus::Cancelled {
                 return false;
-            } else if task.status == TaskStatus::Halted {
+            } else if let Some(failed_status) = util::task_failure(&task.status) {
                 let mut job = jobs.get_mut(&task.job_id).unwrap();
-                if job.status != JobStatus::Halted {
-                    job.status = JobStatus::Halted;
+                if !util::job_failed(&job.status) {
+                    job.status = failed_status;
+                    watchers.notify(&job.id);
 
                     // Cancel tasks
+                    let failed_task_id = task.id.clone();
                     job.tasks.clone()
                         .into_iter()
                         .for_each(|x| {
                             let mut task = tasks.get_mut(&x).unwrap();
+                            if x != failed_task_id && task.status != TaskStatus::Completed {
+                                task.failure_reason = format!("Cancelled after task {} failed", &failed_task_id);
+                            }
+                            let backup_option = task.backup_worker.take();
                             task.status = match task.status {
                                 TaskStatus::Awaiting => TaskStatus::Cancelled,
                                 TaskStatus::Completed => TaskStatus::Completed,
                                 TaskStatus::Halted => TaskStatus::Cancelled,
+                                TaskStatus::TimedOut => TaskStatus::Cancelled,
+                                TaskStatus::OutOfMemory => TaskStatus::Cancelled,
                                 TaskStatus::Cancelled => TaskStatus::Cancelled, // Shouldn't ever happen
                                 TaskStatus::Running(ref worker_id) => {
-                                    if let Some(worker) = workers.get(&worker_id) {
-                                        if consensus_mode {
-                                            if consensus_state.active.read().unwrap().get_value() {
-                                                update_sender.send(WorkerUpdate::cancellation(&worker));
+                                    // Speculated tasks have a copy running on a second worker
+                                    let mut running_on = vec![worker_id.clone()];
+                                    running_on.extend(backup_option);
+
+                                    for worker_id in running_on {
+                                        if let Some(worker) = workers.get(&worker_id) {
+                                            if consensus_mode {
+                                                if consensus_state.active.read().unwrap().get_value() {
+                                                    update_sender.send(WorkerUpdate::cancellation(&worker));
+                                                } else {
+                                                    info!("CONSENSUS: Did not send cancellation message as master not active")
+                                                }
                                             } else {
-                                                info!("CONSENSUS: Did not send cancellation message as master not active")
+                                                update_sender.send(WorkerUpdate::cancellation(&worker));
                                             }
-                                        } else {
-                                            update_sender.send(WorkerUpdate::cancellation(&worker));
                                         }
                                     }
                                     TaskStatus::Cancelled
@@ -364,7 +1036,10 @@ fn handle_errored_tasks(
                             };
                         });
 
-                    
+                    if job.job_type == JobType::FlatMap {
+                        discard_partial_outputs(&job, data);
+                    }
+
                     let mut prev_job_id = job.id.clone();
 
                     // Cancel all subsequent jobs
@@ -372,12 +1047,23 @@ fn handle_errored_tasks(
                     while next_job.output_job_id != None {
                         next_job = jobs.get_mut(&next_job.output_job_id.clone().unwrap()).unwrap();
                         next_job.status = JobStatus::Cancelled;
+                        watchers.notify(&next_job.id);
                     }
+                    let last_job_id = next_job.id.clone();
+                    let pinned_input = next_job.pinned_input.clone();
+                    drop(next_job);
 
-                    // Remove all data
-                    while prev_job_id.len() > 0 {
-                        data.remove(&prev_job_id);
-                        prev_job_id = jobs.get(&prev_job_id).unwrap().input_job_id.clone();
+                    // Remove all data, stopping at the output of another workload this one read from
+                    while prev_job_id.len() > 0 && Some(&prev_job_id) != pinned_input.as_ref() {
+                        let input_job_id = jobs
+                            .get(&prev_job_id)
+                            .map(|x| x.input_job_id.clone())
+                            .unwrap_or_default();
+                        release_output(jobs, data, &prev_job_id);
+                        prev_job_id = input_job_id;
+                    }
+                    if let Some(source_id) = pinned_input {
+                        unpin_output(jobs, data, &source_id, &last_job_id);
                     }
 
                 }
@@ -431,14 +1117,26 @@ pub fn run(
     update_sender: Sender<WorkerUpdate>,
     consensus_mode: bool,
     consensus_state: Arc<State>,
+    watchers: Arc<JobWatchers>,
+    session_names: Arc<SessionNames>,
+    input_dir: Option<String>,
+    output_dir: Option<String>,
+    session_dir: Option<String>,
+    session_ttl: u64,
 ) {
     const SLEEP_TIME: time::Duration = time::Duration::from_millis(50);
 
     let mut loop_count: u64 = 0;
 
     loop {
+        if (loop_count % 100) == 0 {
+            release_unused_artifacts(&users, &jobs, &data);
+        }
         if (loop_count % 5) == 0 {
-            kick_inactive_users(&users);
+            kick_inactive_users(&users, &session_names, &session_dir, session_ttl);
+            if let Some(session_dir) = &session_dir {
+                sessions::save_changed(session_dir, &users);
+            }
             detect_worker_crashes(
                 &worker_names,
                 &running_tasks,
@@ -448,7 +1146,15 @@ pub fn run(
             );
         }
 
-        handle_finished_tasks(&running_tasks, &data, &tasks, &jobs_queue, &jobs);
+        handle_finished_tasks(
+            &running_tasks,
+            &data,
+            &tasks,
+            &jobs_queue,
+            &jobs,
+            &watchers,
+            &output_dir,
+        );
         handle_errored_tasks(
             &running_tasks,
             &workers,
@@ -458,8 +1164,17 @@ pub fn run(
             &update_sender,
             consensus_mode,
             &consensus_state,
+            &watchers,
+        );
+        create_tasks_from_queued_jobs(
+            &jobs,
+            &data,
+            &jobs_queue,
+            &tasks,
+            &tasks_queue,
+            &watchers,
+            &input_dir,
         );
-        create_tasks_from_queued_jobs(&jobs, &data, &jobs_queue, &tasks, &tasks_queue);
 
         if !consensus_mode || (consensus_mode && consensus_state.active.read().unwrap().get_value())
         {
@@ -468,9 +1183,29 @@ pub fn run(
                 &workers,
                 &tasks,
                 &tasks_queue,
+                &jobs,
                 &running_tasks,
                 &update_sender,
+                &watchers,
             );
+
+            if (loop_count % 20) == 0 {
+                enforce_task_deadlines(
+                    &worker_names,
+                    &workers,
+                    &tasks,
+                    &running_tasks,
+                    &update_sender,
+                );
+                speculate_straggler_tasks(
+                    &worker_names,
+                    &workers,
+                    &tasks,
+                    &jobs,
+                    &running_tasks,
+                    &update_sender,
+                );
+            }
         }
 
         if (loop_count % 15) == 0 {
@@ -485,3 +1220,99 @@ pub fn run(
         loop_count = loop_count + 1;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use util::tests::job;
+
+    fn flat_map(total_tasks: i32) -> WJob {
+        let mut job = job(vec![], total_tasks, total_tasks);
+        job.job_type = JobType::FlatMap;
+        return job;
+    }
+
+    #[test]
+    fn test_sanity_concatenate_partial_outputs() {
+        let job = flat_map(3);
+        let data = Arc::new(CHashMap::new());
+        // Tasks finish in any order, and a task may produce nothing
+        data.insert(util::partial_output_key(&job.id, 2), vec![b"e".to_vec()]);
+        data.insert(util::partial_output_key(&job.id, 0), vec![b"a".to_vec(), b"b".to_vec()]);
+        data.insert(util::partial_output_key(&job.id, 1), vec![]);
+        data.insert(util::partial_output_key(&"user-2".to_string(), 0), vec![b"x".to_vec()]);
+
+        concatenate_partial_outputs(&job, &data);
+        assert_eq!(*data.get(&job.id).unwrap(), vec![b"a".to_vec(), b"b".to_vec(), b"e".to_vec()]);
+        assert_eq!(data.contains_key(&util::partial_output_key(&job.id, 0)), false);
+        assert_eq!(data.contains_key(&util::partial_output_key(&job.id, 2)), false);
+        assert_eq!(data.len(), 2);
+    }
+
+    #[test]
+    fn test_sanity_enforce_task_deadlines() {
+        let workers = Arc::new(CHashMap::new());
+        let worker_names = Arc::new(RwLock::new(Vec::new()));
+        for worker_id in vec!["w1", "w2", "w3"] {
+            let worker = Worker::new(worker_id.to_string(), "127.0.0.1".to_string(), 3000, HashMap::new());
+            workers.insert(worker.id.clone(), worker);
+            worker_names.write().unwrap().push(worker_id.to_string());
+        }
+        let now = util::current_secs();
+        let mut timed = util::tests::task("user-1-0", TaskStatus::Running("w1".to_string()), 0.5, "", now);
+        timed.timeout = 60;
+        timed.started_at = now - 60 - TASK_DEADLINE_GRACE - 1;
+        timed.backup_worker = Some("w2".to_string());
+        // Tasks without a timeout may run for as long as they need
+        let mut untimed = util::tests::task("user-1-1", TaskStatus::Running("w3".to_string()), 0.5, "", now);
+        untimed.started_at = 0;
+        let tasks = Arc::new(CHashMap::new());
+        tasks.insert(timed.id.clone(), timed);
+        tasks.insert(untimed.id.clone(), untimed);
+        let running_tasks = Arc::new(RwLock::new(vec!["user-1-0".to_string(), "user-1-1".to_string()].into_iter().collect()));
+        let (sender, receiver) = crossbeam_channel::unbounded();
+
+        enforce_task_deadlines(&worker_names, &workers, &tasks, &running_tasks, &sender);
+        let timed = tasks.get(&"user-1-0".to_string()).unwrap().clone();
+        assert_eq!(timed.status, TaskStatus::TimedOut);
+        assert_eq!(timed.finished_at >= now, true);
+        assert_eq!(timed.backup_worker, None);
+        assert_eq!(timed.failure_reason, "Exceeded its 60s timeout on worker w1");
+        assert_eq!(tasks.get(&"user-1-1".to_string()).unwrap().status, TaskStatus::Running("w3".to_string()));
+
+        let cancelled: Vec<String> = receiver.try_iter().map(|x| x.worker_id).collect();
+        assert_eq!(cancelled, vec!["w1".to_string(), "w2".to_string()]);
+        assert_eq!(*worker_names.read().unwrap(), vec!["w3".to_string()]);
+        assert_eq!(workers.contains_key(&"w1".to_string()), false);
+        assert_eq!(workers.contains_key(&"w3".to_string()), true);
+    }
+
+    #[test]
+    fn test_sanity_matching_worker_exists() {
+        let mut labels = HashMap::new();
+        labels.insert("gpu".to_string(), "true".to_string());
+        let workers = Arc::new(CHashMap::new());
+        let mut worker = Worker::new("worker-1".to_string(), "127.0.0.1".to_string(), 3000, labels.clone());
+        worker.status = WorkerStatus::Halted;
+        workers.insert(worker.id.clone(), worker);
+        let names = vec!["worker-1".to_string()];
+
+        assert_eq!(matching_worker_exists(&names, &workers, &labels), false);
+        workers.get_mut(&"worker-1".to_string()).unwrap().status = WorkerStatus::Processing;
+        assert_eq!(matching_worker_exists(&names, &workers, &labels), true);
+        assert_eq!(matching_worker_exists(&names, &workers, &HashMap::new()), true);
+    }
+
+    #[test]
+    fn test_sanity_discard_partial_outputs() {
+        let job = flat_map(2);
+        let data = Arc::new(CHashMap::new());
+        data.insert(util::partial_output_key(&job.id, 1), vec![b"b".to_vec()]);
+        data.insert(util::partial_output_key(&"user-2".to_string(), 1), vec![b"x".to_vec()]);
+
+        discard_partial_outputs(&job, &data);
+        assert_eq!(data.contains_key(&util::partial_output_key(&job.id, 1)), false);
+        assert_eq!(data.contains_key(&job.id), false);
+        assert_eq!(data.len(), 1);
+    }
+}
@@ -19,6 +19,10 @@ pub struct Config {
     pub user_server: Server,
     pub transmission_threads: i32,
     pub consensus_mode: bool,
+    pub input_dir: Option<String>,
+    pub output_dir: Option<String>,
+    pub session_dir: Option<String>,
+    pub session_ttl: u64,
 }
 
 #[derive(Debug)]
@@ -40,6 +44,10 @@ impl Default for Config {
             },
             transmission_threads: 5,
             consensus_mode: false,
+            input_dir: None,
+            output_dir: None,
+            session_dir: None,
+            session_ttl: 86400,
         };
     }
 }
@@ -0,0 +1,177 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    input.rs - Reads workload inputs from the filesystem of the master
+*/
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, ErrorKind, Read};
+use std::path::PathBuf;
+
+use glob::{glob, Pattern};
+
+use marshal;
+use model::{FileInput, FileSplit};
+use util;
+
+// Datapacks hold marshalled OCaml values, so each chunk is stored as an OCaml string
+fn marshal_string(bytes: &[u8]) -> io::Result<Vec<u8>> {
+    return marshal::string(bytes).map_err(|e| io::Error::new(ErrorKind::InvalidData, e));
+}
+
+fn input_files(input_dir: &String, pattern: &String) -> Result<Vec<PathBuf>, String> {
+    if !util::relative_path(pattern) {
+        return Err(format!("{} is not relative to the input directory", pattern));
+    }
+
+    let root = fs::canonicalize(input_dir).map_err(|e| e.to_string())?;
+    let full_pattern = format!("{}/{}", Pattern::escape(&root.to_string_lossy()), pattern);
+
+    let mut files = Vec::new();
+    for entry in glob(&full_pattern).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?;
+        // Symlinks may point outside of the input directory
+        let path = fs::canonicalize(&path).map_err(|e| e.to_string())?;
+        if !path.starts_with(&root) {
+            return Err(format!("{} is outside of the input directory", path.display()));
+        }
+        if path.is_file() {
+            files.push(path);
+        }
+    }
+
+    if files.is_empty() {
+        return Err(format!("No files match {}", pattern));
+    }
+    return Ok(files);
+}
+
+fn split_file(path: &PathBuf, split: &FileSplit, datapacks: &mut Vec<Vec<u8>>) -> io::Result<()> {
+    let mut reader = BufReader::new(File::open(path)?);
+    if *split == FileSplit::Files {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        datapacks.push(marshal_string(&bytes)?);
+        return Ok(());
+    }
+
+    let mut chunk = Vec::new();
+    let mut lines = 0;
+    while reader.read_until(b'\n', &mut chunk)? > 0 {
+        lines += 1;
+        let full = match *split {
+            FileSplit::Lines(max_lines) => lines >= max_lines,
+            FileSplit::Bytes(max_bytes) => chunk.len() as u64 >= max_bytes,
+            FileSplit::Files => false,
+        };
+        if full {
+            datapacks.push(marshal_string(&chunk)?);
+            chunk.clear();
+            lines = 0;
+        }
+    }
+    if !chunk.is_empty() {
+        datapacks.push(marshal_string(&chunk)?);
+    }
+    return Ok(());
+}
+
+/*
+    EXPORTED FUNCTIONS
+*/
+
+pub fn load(input_dir: &Option<String>, file_input: &FileInput) -> Result<Vec<Vec<u8>>, String> {
+    let input_dir = match input_dir {
+        Some(input_dir) => input_dir,
+        None => return Err("The master was not started with an input directory".to_string()),
+    };
+
+    let mut datapacks = Vec::new();
+    for file in input_files(input_dir, &file_input.pattern)? {
+        split_file(&file, &file_input.split, &mut datapacks)
+            .map_err(|e| format!("Could not read {}: {}", file.display(), e))?;
+    }
+    return Ok(datapacks);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::os::unix::fs::symlink;
+
+    // Each test gets a directory of its own, as tests run in parallel
+    fn input_dir(name: &str) -> String {
+        let dir = env::temp_dir().join(format!("parliament-input-test-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("inputs").join("nested")).unwrap();
+        fs::write(dir.join("inputs").join("a.txt"), "1\n2\n3\n").unwrap();
+        fs::write(dir.join("inputs").join("b.txt"), "hello").unwrap();
+        fs::write(dir.join("inputs").join("nested").join("c.txt"), "nested\n").unwrap();
+        fs::write(dir.join("secret.txt"), "secret\n").unwrap();
+        return dir.join("inputs").to_string_lossy().to_string();
+    }
+
+    fn strings(chunks: Vec<&str>) -> Vec<Vec<u8>> {
+        return chunks.into_iter().map(|x| marshal::string(x.as_bytes()).unwrap()).collect();
+    }
+
+    fn file_input(pattern: &str, split: FileSplit) -> FileInput {
+        return FileInput {
+            pattern: pattern.to_string(),
+            split,
+        };
+    }
+
+    #[test]
+    fn test_sanity_split_file() {
+        let dir = input_dir("split");
+        let path = PathBuf::from(&dir).join("a.txt");
+
+        let mut datapacks = Vec::new();
+        split_file(&path, &FileSplit::Lines(2), &mut datapacks).unwrap();
+        assert_eq!(datapacks, strings(vec!["1\n2\n", "3\n"]));
+
+        // Chunks end on the line that reaches the limit
+        let mut datapacks = Vec::new();
+        split_file(&path, &FileSplit::Bytes(3), &mut datapacks).unwrap();
+        assert_eq!(datapacks, strings(vec!["1\n2\n", "3\n"]));
+
+        let mut datapacks = Vec::new();
+        split_file(&path, &FileSplit::Files, &mut datapacks).unwrap();
+        assert_eq!(datapacks, strings(vec!["1\n2\n3\n"]));
+
+        // A last line without a newline is kept
+        let mut datapacks = Vec::new();
+        split_file(&PathBuf::from(&dir).join("b.txt"), &FileSplit::Lines(1), &mut datapacks).unwrap();
+        assert_eq!(datapacks, strings(vec!["hello"]));
+    }
+
+    #[test]
+    fn test_sanity_load() {
+        let dir = input_dir("load");
+        let datapacks = load(&Some(dir.clone()), &file_input("*.txt", FileSplit::Files)).unwrap();
+        assert_eq!(datapacks, strings(vec!["1\n2\n3\n", "hello"]));
+
+        let datapacks = load(&Some(dir.clone()), &file_input("**/c.txt", FileSplit::Lines(10))).unwrap();
+        assert_eq!(datapacks, strings(vec!["nested\n"]));
+
+        assert!(load(&None, &file_input("*.txt", FileSplit::Files)).is_err());
+        assert!(load(&Some(dir), &file_input("*.csv", FileSplit::Files)).is_err());
+    }
+
+    #[test]
+    fn test_sanity_input_files_confined() {
+        let dir = input_dir("confined");
+        assert!(input_files(&dir, &"../secret.txt".to_string()).is_err());
+        assert!(input_files(&dir, &"nested/../../*.txt".to_string()).is_err());
+        assert!(input_files(&dir, &format!("{}/../secret.txt", &dir)).is_err());
+
+        // Symlinks are followed, but may not leave the input directory
+        symlink(PathBuf::from(&dir).join("../secret.txt"), PathBuf::from(&dir).join("link.txt")).unwrap();
+        assert!(input_files(&dir, &"link.txt".to_string()).is_err());
+        assert!(input_files(&dir, &"*.txt".to_string()).is_err());
+        assert_eq!(input_files(&dir, &"a.txt".to_string()).unwrap().len(), 1);
+    }
+}
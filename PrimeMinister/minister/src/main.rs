@@ -12,6 +12,8 @@ extern crate clap;
 extern crate crossbeam;
 extern crate crossbeam_channel;
 extern crate futures;
+extern crate glob;
+extern crate libc;
 extern crate log;
 extern crate protobuf;
 extern crate shared;
@@ -28,12 +30,16 @@ use crossbeam::queue::MsQueue;
 use crossbeam_channel::unbounded;
 
 use config::{Config, State};
-use model::{WJob, WTask, Worker};
+use model::{JobWatchers, WJob, WTask, Worker};
+use users::sessions::SessionNames;
 use users::User;
 
 mod cluster;
 mod config;
+mod input;
+mod marshal;
 mod model;
+mod output;
 mod users;
 mod util;
 mod workers;
@@ -49,6 +55,8 @@ lazy_static! {
     static ref TASK_QUEUE: Arc<MsQueue<String>> = Arc::new(MsQueue::new());
     static ref RUNNING_TASKS: Arc<RwLock<HashSet<String>>> = Arc::new(RwLock::new(HashSet::new()));
     static ref CONSENSUS_STATE: Arc<State> = Arc::new(State::default());
+    static ref WATCHERS: Arc<JobWatchers> = Arc::new(JobWatchers::new());
+    static ref SESSION_NAMES: Arc<SessionNames> = Arc::new(SessionNames::new());
 }
 
 fn load_config(arg: ArgMatches) -> Config {
@@ -73,6 +81,22 @@ fn load_config(arg: ArgMatches) -> Config {
         config.consensus_mode = true;
     }
 
+    if let Some(input_dir) = arg.value_of("INPUT_DIR") {
+        config.input_dir = Some(input_dir.to_string());
+    }
+
+    if let Some(output_dir) = arg.value_of("OUTPUT_DIR") {
+        config.output_dir = Some(output_dir.to_string());
+    }
+
+    if let Some(session_dir) = arg.value_of("SESSION_DIR") {
+        config.session_dir = Some(session_dir.to_string());
+    }
+
+    if let Some(session_ttl) = arg.value_of("SESSION_TTL") {
+        config.session_ttl = session_ttl.parse::<u64>().unwrap();
+    }
+
     return config;
 }
 
@@ -90,12 +114,20 @@ fn main() {
 
         (@arg THREADS: --threads -t +takes_value "Number of transmission threads")
         (@arg CONSENSUS: --consensus -c "Use in consensus mode")
+        (@arg INPUT_DIR: --inputs -i +takes_value "Directory that workloads can read input files from. Default: none, file inputs are rejected")
+        (@arg OUTPUT_DIR: --outputs -o +takes_value "Directory that job sinks write outputs to. Default: none, jobs with sinks halt")
+        (@arg SESSION_DIR: --sessions -s +takes_value "Directory that named sessions are saved to, so they can be resumed after a restart. Jobs are not saved, so workloads running during a restart are lost. Default: none")
+        (@arg SESSION_TTL: --ttl +takes_value "Seconds a named session is kept after its last request. Default: 86400")
     );
     // Load in config
     let config = load_config(matches.get_matches());
 
     print_header();
 
+    if let Some(session_dir) = &config.session_dir {
+        users::sessions::load(session_dir, &USERS, &SESSION_NAMES);
+    }
+
     let (update_sender, update_receiver) = unbounded();
 
     users::server::start(
@@ -105,7 +137,10 @@ fn main() {
         USERS.clone(),
         JOBS.clone(),
         JOBS_QUEUE.clone(),
+        TASKS.clone(),
         DATA.clone(),
+        WATCHERS.clone(),
+        SESSION_NAMES.clone(),
     )
     .expect("Could not start user server!");
 
@@ -118,6 +153,7 @@ fn main() {
         TASKS.clone(),
         DATA.clone(),
         RUNNING_TASKS.clone(),
+        TASK_QUEUE.clone(),
         update_sender.clone(),
     )
     .expect("Could not start worker server!");
@@ -149,6 +185,12 @@ fn main() {
         update_sender,
         config.consensus_mode.clone(),
         CONSENSUS_STATE.clone(),
+        WATCHERS.clone(),
+        SESSION_NAMES.clone(),
+        config.input_dir.clone(),
+        config.output_dir.clone(),
+        config.session_dir.clone(),
+        config.session_ttl,
     );
 }
 
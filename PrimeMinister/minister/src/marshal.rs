@@ -0,0 +1,694 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    marshal.rs - Reads and writes the OCaml lists held by datapacks, so the master can repartition them
+*/
+
+use std::collections::HashMap;
+
+use byteorder::{BigEndian, ByteOrder};
+
+const MAGIC_SMALL: u32 = 0x8495_A6BE;
+const MAGIC_BIG: u32 = 0x8495_A6BF;
+const HEADER_SMALL: usize = 20;
+const HEADER_BIG: usize = 32;
+
+const PREFIX_SMALL_BLOCK: u8 = 0x80;
+const PREFIX_SMALL_INT: u8 = 0x40;
+const PREFIX_SMALL_STRING: u8 = 0x20;
+const CODE_INT8: u8 = 0x00;
+const CODE_INT16: u8 = 0x01;
+const CODE_INT32: u8 = 0x02;
+const CODE_INT64: u8 = 0x03;
+const CODE_SHARED8: u8 = 0x04;
+const CODE_SHARED16: u8 = 0x05;
+const CODE_SHARED32: u8 = 0x06;
+const CODE_DOUBLE_ARRAY32_LITTLE: u8 = 0x07;
+const CODE_BLOCK32: u8 = 0x08;
+const CODE_STRING8: u8 = 0x09;
+const CODE_STRING32: u8 = 0x0A;
+const CODE_DOUBLE_BIG: u8 = 0x0B;
+const CODE_DOUBLE_LITTLE: u8 = 0x0C;
+const CODE_DOUBLE_ARRAY8_BIG: u8 = 0x0D;
+const CODE_DOUBLE_ARRAY8_LITTLE: u8 = 0x0E;
+const CODE_DOUBLE_ARRAY32_BIG: u8 = 0x0F;
+const CODE_CUSTOM: u8 = 0x12;
+const CODE_BLOCK64: u8 = 0x13;
+const CODE_SHARED64: u8 = 0x14;
+const CODE_STRING64: u8 = 0x15;
+const CODE_DOUBLE_ARRAY64_BIG: u8 = 0x16;
+const CODE_DOUBLE_ARRAY64_LITTLE: u8 = 0x17;
+const CODE_CUSTOM_LEN: u8 = 0x18;
+const CODE_CUSTOM_FIXED: u8 = 0x19;
+
+// Values are kept in an arena and refer to each other by index, so sharing is preserved and long lists don't recurse
+enum Node {
+    Int(i64),
+    Block(u8, Vec<usize>),
+    String(Vec<u8>),
+    // The code the double was read with, which gives its byte order, and its bytes
+    Double(u8, Vec<u8>),
+    DoubleArray(u8, usize, Vec<u8>),
+    // Only int32, int64 and nativeint, whose size is known without their deserializer
+    Custom(u8, Vec<u8>, usize, usize),
+}
+
+enum Item {
+    Complete(usize),
+    Block(usize, usize),
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    arena: &'a mut Vec<Node>,
+    objects: Vec<usize>,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() - self.pos < len {
+            return Err("Datapack ends unexpectedly".to_string());
+        }
+        let bytes = self.bytes;
+        let slice = &bytes[self.pos..self.pos + len];
+        self.pos += len;
+        return Ok(slice);
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        return Ok(self.take(1)?[0]);
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        return Ok(BigEndian::read_u16(self.take(2)?));
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        return Ok(BigEndian::read_u32(self.take(4)?));
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        return Ok(BigEndian::read_u64(self.take(8)?));
+    }
+
+    // Every field or byte a value claims must still be in the datapack, which also keeps lengths within a usize
+    fn length(&self, len: u64) -> Result<usize, String> {
+        if len > (self.bytes.len() - self.pos) as u64 {
+            return Err("Datapack holds a value longer than itself".to_string());
+        }
+        return Ok(len as usize);
+    }
+
+    fn push(&mut self, node: Node, object: bool) -> usize {
+        self.arena.push(node);
+        let index = self.arena.len() - 1;
+        if object {
+            self.objects.push(index);
+        }
+        return index;
+    }
+
+    fn block(&mut self, tag: u8, size: usize) -> Item {
+        // Empty blocks are atoms, which are not shared
+        let index = self.push(Node::Block(tag, Vec::with_capacity(size.min(1024))), size > 0);
+        return if size > 0 { Item::Block(index, size) } else { Item::Complete(index) };
+    }
+
+    fn shared(&mut self, offset: u64) -> Result<Item, String> {
+        if offset == 0 || offset > self.objects.len() as u64 {
+            return Err("Datapack refers to an unknown value".to_string());
+        }
+        return Ok(Item::Complete(self.objects[self.objects.len() - offset as usize]));
+    }
+
+    fn string(&mut self, len: u64) -> Result<Item, String> {
+        let len = self.length(len)?;
+        let bytes = self.take(len)?.to_vec();
+        return Ok(Item::Complete(self.push(Node::String(bytes), true)));
+    }
+
+    fn double_array(&mut self, code: u8, len: u64) -> Result<Item, String> {
+        let len = self.length(len)?;
+        let size = len
+            .checked_mul(8)
+            .ok_or("Datapack holds a value longer than itself".to_string())?;
+        let bytes = self.take(size)?.to_vec();
+        return Ok(Item::Complete(self.push(Node::DoubleArray(code, len, bytes), true)));
+    }
+
+    fn custom(&mut self, code: u8) -> Result<Item, String> {
+        let start = self.pos;
+        let mut identifier = Vec::new();
+        loop {
+            match self.byte()? {
+                0 => break,
+                x => identifier.push(x),
+            }
+        }
+        if code == CODE_CUSTOM_LEN {
+            self.take(12)?;
+        }
+        let (size_32, size_64) = match identifier.as_slice() {
+            b"_i" => {
+                self.take(4)?;
+                (4, 4)
+            }
+            b"_j" => {
+                self.take(8)?;
+                (8, 8)
+            }
+            b"_n" => {
+                match self.byte()? {
+                    1 => self.take(4)?,
+                    2 => self.take(8)?,
+                    _ => return Err("Datapack holds an invalid nativeint".to_string()),
+                };
+                (4, 8)
+            }
+            _ => {
+                return Err(format!(
+                    "Datapack holds a custom value of type {}, which cannot be repartitioned",
+                    String::from_utf8_lossy(&identifier)
+                ))
+            }
+        };
+        let bytes = self.bytes[start..self.pos].to_vec();
+        return Ok(Item::Complete(self.push(Node::Custom(code, bytes, size_32, size_64), true)));
+    }
+
+    fn item(&mut self) -> Result<Item, String> {
+        let code = self.byte()?;
+        if code >= PREFIX_SMALL_BLOCK {
+            return Ok(self.block(code & 0x0F, ((code >> 4) & 0x07) as usize));
+        }
+        if code >= PREFIX_SMALL_INT {
+            let index = self.push(Node::Int((code & 0x3F) as i64), false);
+            return Ok(Item::Complete(index));
+        }
+        if code >= PREFIX_SMALL_STRING {
+            return self.string((code & 0x1F) as u64);
+        }
+
+        let int = match code {
+            CODE_INT8 => Some(self.byte()? as i8 as i64),
+            CODE_INT16 => Some(self.u16()? as i16 as i64),
+            CODE_INT32 => Some(self.u32()? as i32 as i64),
+            CODE_INT64 => Some(self.u64()? as i64),
+            _ => None,
+        };
+        if let Some(int) = int {
+            return Ok(Item::Complete(self.push(Node::Int(int), false)));
+        }
+
+        return match code {
+            CODE_SHARED8 => {
+                let offset = self.byte()? as u64;
+                self.shared(offset)
+            }
+            CODE_SHARED16 => {
+                let offset = self.u16()? as u64;
+                self.shared(offset)
+            }
+            CODE_SHARED32 => {
+                let offset = self.u32()? as u64;
+                self.shared(offset)
+            }
+            CODE_SHARED64 => {
+                let offset = self.u64()?;
+                self.shared(offset)
+            }
+            CODE_BLOCK32 => {
+                let header = self.u32()?;
+                let size = self.length((header >> 10) as u64)?;
+                Ok(self.block((header & 0xFF) as u8, size))
+            }
+            CODE_BLOCK64 => {
+                let header = self.u64()?;
+                let size = self.length(header >> 10)?;
+                Ok(self.block((header & 0xFF) as u8, size))
+            }
+            CODE_STRING8 => {
+                let len = self.byte()? as u64;
+                self.string(len)
+            }
+            CODE_STRING32 => {
+                let len = self.u32()? as u64;
+                self.string(len)
+            }
+            CODE_STRING64 => {
+                let len = self.u64()?;
+                self.string(len)
+            }
+            CODE_DOUBLE_BIG | CODE_DOUBLE_LITTLE => {
+                let bytes = self.take(8)?.to_vec();
+                Ok(Item::Complete(self.push(Node::Double(code, bytes), true)))
+            }
+            CODE_DOUBLE_ARRAY8_BIG | CODE_DOUBLE_ARRAY8_LITTLE => {
+                let len = self.byte()? as u64;
+                self.double_array(code, len)
+            }
+            CODE_DOUBLE_ARRAY32_BIG | CODE_DOUBLE_ARRAY32_LITTLE => {
+                let len = self.u32()? as u64;
+                self.double_array(code, len)
+            }
+            CODE_DOUBLE_ARRAY64_BIG | CODE_DOUBLE_ARRAY64_LITTLE => {
+                let len = self.u64()?;
+                self.double_array(code, len)
+            }
+            CODE_CUSTOM | CODE_CUSTOM_LEN | CODE_CUSTOM_FIXED => self.custom(code),
+            _ => Err(format!(
+                "Datapack holds a value with code {:#x}, such as a closure, which cannot be repartitioned",
+                code
+            )),
+        };
+    }
+
+    fn value(&mut self) -> Result<usize, String> {
+        // Blocks waiting for their fields, with the number of fields still to be read
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        loop {
+            let mut index = match self.item()? {
+                Item::Complete(index) => index,
+                Item::Block(index, size) => {
+                    stack.push((index, size));
+                    continue;
+                }
+            };
+            loop {
+                match stack.last_mut() {
+                    None => return Ok(index),
+                    Some((block, remaining)) => {
+                        if let Node::Block(_, ref mut fields) = self.arena[*block] {
+                            fields.push(index);
+                        }
+                        *remaining -= 1;
+                        if *remaining > 0 {
+                            break;
+                        }
+                    }
+                }
+                index = stack.pop().unwrap().0;
+            }
+        }
+    }
+}
+
+fn read(arena: &mut Vec<Node>, bytes: &[u8]) -> Result<usize, String> {
+    if bytes.len() < HEADER_SMALL {
+        return Err("Datapack is too short to be a marshalled value".to_string());
+    }
+    let start = match BigEndian::read_u32(&bytes[0..4]) {
+        MAGIC_SMALL => HEADER_SMALL,
+        MAGIC_BIG if bytes.len() >= HEADER_BIG => HEADER_BIG,
+        _ => return Err("Datapack is not a marshalled value".to_string()),
+    };
+
+    let mut reader = Reader {
+        bytes,
+        pos: start,
+        arena,
+        objects: Vec::new(),
+    };
+    return reader.value();
+}
+
+fn write_int(out: &mut Vec<u8>, int: i64) {
+    if int >= 0 && int < 0x40 {
+        out.push(PREFIX_SMALL_INT + int as u8);
+    } else if int >= -(1 << 7) && int < (1 << 7) {
+        out.push(CODE_INT8);
+        out.push(int as u8);
+    } else if int >= -(1 << 15) && int < (1 << 15) {
+        out.push(CODE_INT16);
+        let mut bytes = [0; 2];
+        BigEndian::write_i16(&mut bytes, int as i16);
+        out.extend_from_slice(&bytes);
+    } else if int >= -(1 << 31) && int < (1 << 31) {
+        out.push(CODE_INT32);
+        let mut bytes = [0; 4];
+        BigEndian::write_i32(&mut bytes, int as i32);
+        out.extend_from_slice(&bytes);
+    } else {
+        out.push(CODE_INT64);
+        let mut bytes = [0; 8];
+        BigEndian::write_i64(&mut bytes, int);
+        out.extend_from_slice(&bytes);
+    }
+}
+
+fn write_length(out: &mut Vec<u8>, code8: Option<u8>, code32: u8, code64: u8, len: usize) {
+    if let (Some(code8), true) = (code8, len < 0x100) {
+        out.push(code8);
+        out.push(len as u8);
+    } else if len <= 0xFFFF_FFFF {
+        out.push(code32);
+        let mut bytes = [0; 4];
+        BigEndian::write_u32(&mut bytes, len as u32);
+        out.extend_from_slice(&bytes);
+    } else {
+        out.push(code64);
+        let mut bytes = [0; 8];
+        BigEndian::write_u64(&mut bytes, len as u64);
+        out.extend_from_slice(&bytes);
+    }
+}
+
+// The header holds the number of shared values and the size in words on 32 and 64-bit platforms
+fn write(arena: &Vec<Node>, root: usize) -> Result<Vec<u8>, String> {
+    let mut out = vec![0; HEADER_SMALL];
+    let mut objects: u64 = 0;
+    let mut size_32: u64 = 0;
+    let mut size_64: u64 = 0;
+    let mut written: HashMap<usize, u64> = HashMap::new();
+
+    let mut stack = vec![root];
+    while let Some(index) = stack.pop() {
+        if let Some(object) = written.get(&index) {
+            write_length(&mut out, Some(CODE_SHARED8), CODE_SHARED32, CODE_SHARED64, (objects - object) as usize);
+            continue;
+        }
+
+        let words = match &arena[index] {
+            Node::Int(int) => {
+                write_int(&mut out, *int);
+                continue;
+            }
+            Node::Block(tag, fields) => {
+                let size = fields.len();
+                if *tag < 16 && size < 8 {
+                    out.push(PREFIX_SMALL_BLOCK + tag + ((size as u8) << 4));
+                } else if size < (1 << 22) {
+                    let mut bytes = [0; 4];
+                    BigEndian::write_u32(&mut bytes, ((size as u32) << 10) | *tag as u32);
+                    out.push(CODE_BLOCK32);
+                    out.extend_from_slice(&bytes);
+                } else {
+                    let mut bytes = [0; 8];
+                    BigEndian::write_u64(&mut bytes, ((size as u64) << 10) | *tag as u64);
+                    out.push(CODE_BLOCK64);
+                    out.extend_from_slice(&bytes);
+                }
+                stack.extend(fields.iter().rev());
+                if size == 0 {
+                    continue;
+                }
+                (size as u64 + 1, size as u64 + 1)
+            }
+            Node::String(bytes) => {
+                let len = bytes.len();
+                if len < 0x20 {
+                    out.push(PREFIX_SMALL_STRING + len as u8);
+                } else {
+                    write_length(&mut out, Some(CODE_STRING8), CODE_STRING32, CODE_STRING64, len);
+                }
+                out.extend_from_slice(bytes);
+                ((len as u64 + 4) / 4 + 1, (len as u64 + 8) / 8 + 1)
+            }
+            Node::Double(code, bytes) => {
+                out.push(*code);
+                out.extend_from_slice(bytes);
+                (3, 2)
+            }
+            Node::DoubleArray(code, len, bytes) => {
+                let little = match *code {
+                    CODE_DOUBLE_ARRAY8_LITTLE | CODE_DOUBLE_ARRAY32_LITTLE | CODE_DOUBLE_ARRAY64_LITTLE => true,
+                    _ => false,
+                };
+                if little {
+                    write_length(&mut out, Some(CODE_DOUBLE_ARRAY8_LITTLE), CODE_DOUBLE_ARRAY32_LITTLE, CODE_DOUBLE_ARRAY64_LITTLE, *len);
+                } else {
+                    write_length(&mut out, Some(CODE_DOUBLE_ARRAY8_BIG), CODE_DOUBLE_ARRAY32_BIG, CODE_DOUBLE_ARRAY64_BIG, *len);
+                }
+                out.extend_from_slice(bytes);
+                (*len as u64 * 2 + 1, *len as u64 + 1)
+            }
+            Node::Custom(code, bytes, custom_32, custom_64) => {
+                out.push(*code);
+                out.extend_from_slice(bytes);
+                (2 + (*custom_32 as u64 + 3) / 4, 2 + (*custom_64 as u64 + 7) / 8)
+            }
+        };
+        written.insert(index, objects);
+        objects += 1;
+        size_32 += words.0;
+        size_64 += words.1;
+    }
+
+    let data_len = out.len() - HEADER_SMALL;
+    if data_len > 0xFFFF_FFFF || size_64 > 0xFFFF_FFFF {
+        return Err("Datapack is too large to be written".to_string());
+    }
+    BigEndian::write_u32(&mut out[0..4], MAGIC_SMALL);
+    BigEndian::write_u32(&mut out[4..8], data_len as u32);
+    BigEndian::write_u32(&mut out[8..12], objects as u32);
+    BigEndian::write_u32(&mut out[12..16], size_32 as u32);
+    BigEndian::write_u32(&mut out[16..20], size_64 as u32);
+    return Ok(out);
+}
+
+fn list_elements(arena: &Vec<Node>, root: usize, elements: &mut Vec<usize>) -> Result<(), String> {
+    let mut index = root;
+    // A cyclic list would never end
+    for _ in 0..arena.len() {
+        match &arena[index] {
+            Node::Int(0) => return Ok(()),
+            Node::Block(0, fields) if fields.len() == 2 => {
+                elements.push(fields[0]);
+                index = fields[1];
+            }
+            _ => return Err("Datapack does not hold a list".to_string()),
+        }
+    }
+    return Err("Datapack holds a cyclic list".to_string());
+}
+
+fn write_list(arena: &mut Vec<Node>, elements: &[usize]) -> Result<Vec<u8>, String> {
+    let start = arena.len();
+    arena.push(Node::Int(0));
+    for element in elements.iter().rev() {
+        let tail = arena.len() - 1;
+        arena.push(Node::Block(0, vec![*element, tail]));
+    }
+    let root = arena.len() - 1;
+    let bytes = write(arena, root);
+    arena.truncate(start);
+    return bytes;
+}
+
+// Splits items into contiguous groups whose sizes differ by at most one
+fn group_sizes(items: usize, groups: usize) -> Vec<usize> {
+    return (0..groups)
+        .map(|i| items / groups + if i < items % groups { 1 } else { 0 })
+        .collect();
+}
+
+fn read_lists(arena: &mut Vec<Node>, datapacks: &[Vec<u8>]) -> Result<Vec<Vec<usize>>, String> {
+    let mut lists = Vec::new();
+    for datapack in datapacks {
+        let root = read(arena, datapack)?;
+        let mut elements = Vec::new();
+        list_elements(arena, root, &mut elements)?;
+        lists.push(elements);
+    }
+    return Ok(lists);
+}
+
+/*
+    EXPORTED FUNCTIONS
+*/
+
+// A single OCaml string, which is how chunks of input files are stored
+pub fn string(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let arena = vec![Node::String(bytes.to_vec())];
+    return write(&arena, 0);
+}
+
+// Each datapack must hold an OCaml list, whose elements are spread evenly over the partitions in order
+pub fn repartition(datapacks: &[Vec<u8>], partitions: usize) -> Result<Vec<Vec<u8>>, String> {
+    let mut arena = Vec::new();
+    let elements: Vec<usize> = read_lists(&mut arena, datapacks)?.into_iter().flatten().collect();
+
+    let mut output = Vec::new();
+    let mut start = 0;
+    for size in group_sizes(elements.len(), partitions) {
+        output.push(write_list(&mut arena, &elements[start..start + size])?);
+        start += size;
+    }
+    return Ok(output);
+}
+
+// Neighbouring lists are concatenated, a dataset with no more datapacks than partitions is left as it is
+pub fn coalesce(datapacks: &[Vec<u8>], partitions: usize) -> Result<Vec<Vec<u8>>, String> {
+    if datapacks.len() <= partitions {
+        return Ok(datapacks.to_vec());
+    }
+
+    let mut arena = Vec::new();
+    let mut lists = read_lists(&mut arena, datapacks)?.into_iter();
+
+    let mut output = Vec::new();
+    for size in group_sizes(datapacks.len(), partitions) {
+        let elements: Vec<usize> = lists.by_ref().take(size).flatten().collect();
+        output.push(write_list(&mut arena, &elements)?);
+    }
+    return Ok(output);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A small header around data, the reader only checks the magic number
+    fn datapack(data: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0; HEADER_SMALL];
+        BigEndian::write_u32(&mut bytes[0..4], MAGIC_SMALL);
+        BigEndian::write_u32(&mut bytes[4..8], data.len() as u32);
+        bytes.extend_from_slice(data);
+        return bytes;
+    }
+
+    fn list(arena: &mut Vec<Node>, elements: &[Node]) -> Vec<u8> {
+        let mut indexes = Vec::new();
+        for element in elements {
+            let node = match element {
+                Node::Int(int) => Node::Int(*int),
+                Node::String(bytes) => Node::String(bytes.clone()),
+                Node::Double(code, bytes) => Node::Double(*code, bytes.clone()),
+                Node::DoubleArray(code, len, bytes) => Node::DoubleArray(*code, *len, bytes.clone()),
+                Node::Custom(code, bytes, size_32, size_64) => Node::Custom(*code, bytes.clone(), *size_32, *size_64),
+                Node::Block(_, _) => panic!("Blocks are built by the tests themselves"),
+            };
+            arena.push(node);
+            indexes.push(arena.len() - 1);
+        }
+        return write_list(arena, &indexes).unwrap();
+    }
+
+    fn ints(values: &[i64]) -> Vec<u8> {
+        let elements: Vec<Node> = values.iter().map(|x| Node::Int(*x)).collect();
+        return list(&mut Vec::new(), &elements);
+    }
+
+    #[test]
+    fn test_sanity_ocaml_list() {
+        // Marshal.to_bytes [1; 2; 3] []
+        let bytes = vec![
+            0x84, 0x95, 0xA6, 0xBE, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+            0x09, 0xA0, 0x41, 0xA0, 0x42, 0xA0, 0x43, 0x40,
+        ];
+        assert_eq!(ints(&[1, 2, 3]), bytes);
+        assert_eq!(repartition(&[bytes.clone()], 1).unwrap(), vec![bytes]);
+    }
+
+    #[test]
+    fn test_sanity_repartition() {
+        let datapacks = vec![ints(&[1, 2, 3]), ints(&[]), ints(&[4, 300, -70000, 1 << 40])];
+        let output = repartition(&datapacks, 4).unwrap();
+        assert_eq!(output, vec![ints(&[1, 2]), ints(&[3, 4]), ints(&[300, -70000]), ints(&[1 << 40])]);
+
+        let output = repartition(&[ints(&[1])], 3).unwrap();
+        assert_eq!(output, vec![ints(&[1]), ints(&[]), ints(&[])]);
+    }
+
+    #[test]
+    fn test_sanity_coalesce() {
+        let datapacks = vec![ints(&[1]), ints(&[2, 3]), ints(&[4])];
+        assert_eq!(coalesce(&datapacks, 2).unwrap(), vec![ints(&[1, 2, 3]), ints(&[4])]);
+        assert_eq!(coalesce(&datapacks, 3).unwrap(), datapacks);
+        assert_eq!(coalesce(&datapacks, 5).unwrap(), datapacks);
+    }
+
+    #[test]
+    fn test_sanity_group_sizes() {
+        assert_eq!(group_sizes(7, 3), vec![3, 2, 2]);
+        assert_eq!(group_sizes(2, 4), vec![1, 1, 0, 0]);
+        assert_eq!(group_sizes(0, 2), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_sanity_shared_blocks() {
+        // let s = "ab" in [s; s]
+        let mut arena = vec![Node::String(b"ab".to_vec())];
+        let shared = write_list(&mut arena, &[0, 0]).unwrap();
+        assert!(shared.windows(2).any(|x| x == [CODE_SHARED8, 2]));
+        assert_eq!(repartition(&[shared.clone()], 1).unwrap(), vec![shared.clone()]);
+
+        // Each partition gets a copy of its own
+        let single = list(&mut Vec::new(), &[Node::String(b"ab".to_vec())]);
+        assert_eq!(repartition(&[shared], 2).unwrap(), vec![single.clone(), single]);
+    }
+
+    #[test]
+    fn test_sanity_strings() {
+        let elements = vec![
+            Node::String(b"abc".to_vec()),
+            Node::String(vec![b'x'; 40]),
+            Node::String(vec![b'y'; 300]),
+        ];
+        let bytes = list(&mut Vec::new(), &elements);
+        assert!(bytes.contains(&(PREFIX_SMALL_STRING + 3)));
+        assert!(bytes.windows(2).any(|x| x == [CODE_STRING8, 40]));
+        assert!(bytes.windows(5).any(|x| x == [CODE_STRING32, 0, 0, 1, 44]));
+        assert_eq!(repartition(&[bytes.clone()], 1).unwrap(), vec![bytes]);
+
+        // An OCaml string in a datapack of its own, as input chunks are stored
+        let chunk = string(b"hello").unwrap();
+        assert_eq!(&chunk[0..4], &[0x84, 0x95, 0xA6, 0xBE]);
+        assert_eq!(&chunk[HEADER_SMALL..], b"\x25hello");
+        assert_eq!(BigEndian::read_u32(&chunk[16..20]), 2);
+    }
+
+    #[test]
+    fn test_sanity_doubles() {
+        let elements = vec![
+            Node::Double(CODE_DOUBLE_LITTLE, vec![0, 0, 0, 0, 0, 0, 0xF0, 0x3F]),
+            Node::DoubleArray(CODE_DOUBLE_ARRAY8_LITTLE, 2, vec![0; 16]),
+            Node::DoubleArray(CODE_DOUBLE_ARRAY8_BIG, 1, vec![0x3F, 0xF0, 0, 0, 0, 0, 0, 0]),
+        ];
+        let bytes = list(&mut Vec::new(), &elements);
+        let output = repartition(&[bytes], 3).unwrap();
+        assert_eq!(output[0], list(&mut Vec::new(), &elements[0..1]));
+        assert_eq!(output[1], list(&mut Vec::new(), &elements[1..2]));
+        assert_eq!(output[2], list(&mut Vec::new(), &elements[2..3]));
+    }
+
+    #[test]
+    fn test_sanity_int64_custom() {
+        // [1L; 2L]
+        let mut data = vec![0xA0, CODE_CUSTOM_FIXED, b'_', b'j', 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        data.extend_from_slice(&[0xA0, CODE_CUSTOM_FIXED, b'_', b'j', 0, 0, 0, 0, 0, 0, 0, 0, 2, 0x40]);
+        let output = repartition(&[datapack(&data)], 2).unwrap();
+
+        let one = Node::Custom(CODE_CUSTOM_FIXED, vec![b'_', b'j', 0, 0, 0, 0, 0, 0, 0, 0, 1], 8, 8);
+        let two = Node::Custom(CODE_CUSTOM_FIXED, vec![b'_', b'j', 0, 0, 0, 0, 0, 0, 0, 0, 2], 8, 8);
+        assert_eq!(output, vec![list(&mut Vec::new(), &[one]), list(&mut Vec::new(), &[two])]);
+        // The list cell, then the custom value's header, operations and payload
+        assert_eq!(BigEndian::read_u32(&output[0][12..16]), 3 + 2 + 2);
+        assert_eq!(BigEndian::read_u32(&output[0][16..20]), 3 + 2 + 1);
+    }
+
+    #[test]
+    fn test_sanity_invalid_datapacks() {
+        let mut truncated = ints(&[1, 2, 3]);
+        truncated.pop();
+        assert!(repartition(&[truncated], 1).is_err());
+        assert!(repartition(&[vec![0x84, 0x95]], 1).is_err());
+
+        let mut string64 = vec![0xA0, CODE_STRING64];
+        string64.extend_from_slice(&[0xFF; 8]);
+        assert!(repartition(&[datapack(&string64)], 1).is_err());
+
+        let mut double_array64 = vec![0xA0, CODE_DOUBLE_ARRAY64_LITTLE];
+        double_array64.extend_from_slice(&[0x20, 0, 0, 0, 0, 0, 0, 1]);
+        assert!(repartition(&[datapack(&double_array64)], 1).is_err());
+
+        let mut block64 = vec![CODE_BLOCK64];
+        block64.extend_from_slice(&[0xFF; 8]);
+        assert!(repartition(&[datapack(&block64)], 1).is_err());
+
+        assert!(repartition(&[datapack(&[0xA0, CODE_SHARED8, 5, 0x40])], 1).is_err());
+        assert!(repartition(&[string(b"x").unwrap()], 1).is_err());
+    }
+}
@@ -3,8 +3,12 @@
     Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
 */
 
-use std::collections::HashSet;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use crossbeam_channel::{unbounded, Receiver, Sender};
+
+use shared::util as sutil;
 use util;
 
 #[derive(PartialEq, Clone, Debug)]
@@ -26,10 +30,16 @@ pub struct Worker {
     pub status: WorkerStatus,
     pub assigned: bool,
     pub missed_heartbeats: i32,
+    pub labels: HashMap<String, String>,
 }
 
 impl Worker {
-    pub fn new(id: String, ip_addr: String, ip_port: i32) -> Worker {
+    pub fn new(
+        id: String,
+        ip_addr: String,
+        ip_port: i32,
+        labels: HashMap<String, String>,
+    ) -> Worker {
         return Worker {
             id,
             ip_addr,
@@ -39,6 +49,7 @@ impl Worker {
             status: WorkerStatus::Awaiting,
             assigned: false,
             missed_heartbeats: 0,
+            labels,
         };
     }
 }
@@ -100,15 +111,54 @@ pub enum JobType {
     SingleInMultiOut,
     SingleInSingleOut,
     MultiInSingleOut,
+    FlatMap,
+    // Run by the master on the dataset itself, with the number of datapacks to produce
+    Repartition(usize),
+    Coalesce(usize),
 }
 
 #[derive(PartialEq, Clone, Debug)]
 pub enum JobStatus {
     Blocked,
     Running,
+    // Every task has finished and the output is being written to the job's sink
+    Committing,
     Completed,
     Halted,
     Cancelled,
+    Unschedulable,
+    TimedOut,
+    OutOfMemory,
+}
+
+// Program run by the ShellExecutor when a job has no docker image
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Executable {
+    pub name: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    pub artifact_digest: String,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct ResourceLimits {
+    pub memory_mb: u64,
+    pub cpu_percent: u64,
+    pub open_files: u64,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum FileSplit {
+    Lines(u64),
+    Bytes(u64),
+    Files,
+}
+
+// Files on the master read into a workload's input once its first job is queued
+#[derive(PartialEq, Clone, Debug)]
+pub struct FileInput {
+    pub pattern: String,
+    pub split: FileSplit,
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -120,10 +170,24 @@ pub struct WJob {
     pub job_type: JobType,
     pub status: JobStatus,
     pub docker_name: String,
+    pub executable: Executable,
     pub closure: Arc<Vec<u8>>,
+    pub closure_digest: String,
     pub total_tasks: i32,
     pub completed_tasks: i32,
     pub tasks: HashSet<String>,
+    pub required_labels: HashMap<String, String>,
+    pub task_runtimes: Vec<u64>,
+    pub task_timeout: u64,
+    pub limits: ResourceLimits,
+    pub pinned_input: Option<String>,
+    pub pinned_by: HashSet<String>,
+    pub output_released: bool,
+    pub file_input: Option<FileInput>,
+    pub sink: Option<String>,
+    // Name and digest of each of the workload's broadcasts
+    pub broadcasts: Vec<(String, String)>,
+    pub accumulators: HashMap<String, f64>,
 }
 
 impl WJob {
@@ -132,8 +196,11 @@ impl WJob {
         user_id: String,
         input_job_id: String,
         docker_name: String,
+        executable: Executable,
         job_type: JobType,
         closure: Vec<u8>,
+        required_labels: HashMap<String, String>,
+        task_timeout: u64,
     ) -> WJob {
         return WJob {
             id,
@@ -143,16 +210,33 @@ impl WJob {
             job_type,
             status: JobStatus::Blocked,
             docker_name,
+            executable,
+            closure_digest: sutil::sha256_digest(&closure),
             closure: Arc::new(closure),
             total_tasks: 0,
             completed_tasks: 0,
             tasks: HashSet::new(),
+            required_labels,
+            task_runtimes: Vec::new(),
+            task_timeout,
+            limits: ResourceLimits::default(),
+            pinned_input: None,
+            pinned_by: HashSet::new(),
+            output_released: false,
+            file_input: None,
+            sink: None,
+            broadcasts: Vec::new(),
+            accumulators: HashMap::new(),
         };
     }
 
     pub fn set_output_id(&mut self, output_job_id: String) {
         self.output_job_id = Some(output_job_id);
     }
+
+    pub fn completed(&self) -> bool {
+        return self.status == JobStatus::Completed;
+    }
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -162,6 +246,8 @@ pub enum TaskStatus {
     Completed,
     Halted,
     Cancelled,
+    TimedOut,
+    OutOfMemory,
 }
 
 #[derive(PartialEq, Clone, Debug)]
@@ -174,7 +260,53 @@ pub struct WTask {
     pub data_out_id: String,
     pub data_out_loc: i32,
     pub docker_name: String,
+    pub executable: Executable,
     pub status: TaskStatus,
     pub closure: Arc<Vec<u8>>,
+    pub closure_digest: String,
     pub job_type: JobType,
+    pub required_labels: HashMap<String, String>,
+    pub started_at: u64,
+    pub finished_at: u64,
+    pub backup_worker: Option<String>,
+    pub timeout: u64,
+    pub limits: ResourceLimits,
+    pub progress: f32,
+    pub progress_message: String,
+    pub progress_updated_at: u64,
+    pub worker_id: String,
+    pub attempts: u32,
+    pub input_size: u64,
+    pub output_size: u64,
+    pub failure_reason: String,
+    pub broadcasts: Vec<(String, String)>,
+    // Reported by the attempt that completed the task
+    pub accumulators: Vec<(String, f64)>,
+}
+
+// Passes the ids of changed jobs on to every open WatchJobsRequest
+pub struct JobWatchers {
+    senders: Mutex<Vec<Sender<String>>>,
+}
+
+impl JobWatchers {
+    pub fn new() -> JobWatchers {
+        return JobWatchers {
+            senders: Mutex::new(Vec::new()),
+        };
+    }
+
+    pub fn subscribe(&self) -> Receiver<String> {
+        let (sender, receiver) = unbounded();
+        self.senders.lock().unwrap().push(sender);
+        return receiver;
+    }
+
+    pub fn notify(&self, job_id: &String) {
+        // Watchers whose connection has closed are dropped here
+        self.senders
+            .lock()
+            .unwrap()
+            .retain(|x| x.send(job_id.clone()).is_ok());
+    }
 }
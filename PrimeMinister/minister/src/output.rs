@@ -0,0 +1,84 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    output.rs - Writes job outputs to the filesystem of the master
+*/
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use shared::util as sutil;
+use util;
+
+const MANIFEST: &str = "MANIFEST";
+
+fn write_file(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(bytes)?;
+    return file.sync_all();
+}
+
+// The manifest lists each datapack's file with its size and SHA-256
+fn write_datapacks(directory: &Path, datapacks: &Vec<Vec<u8>>) -> io::Result<()> {
+    fs::create_dir(directory)?;
+    let mut manifest = String::new();
+    for (i, datapack) in datapacks.iter().enumerate() {
+        let name = format!("part-{:05}", i);
+        write_file(&directory.join(&name), datapack)?;
+        manifest.push_str(&format!(
+            "{} {} {}\n",
+            name,
+            datapack.len(),
+            sutil::sha256_digest(datapack)
+        ));
+    }
+    return write_file(&directory.join(MANIFEST), manifest.as_bytes());
+}
+
+/*
+    EXPORTED FUNCTIONS
+*/
+
+// Written to a hidden directory first, which is renamed into place once every file is on disk
+pub fn commit(
+    output_dir: &Option<String>,
+    sink: &String,
+    datapacks: &Vec<Vec<u8>>,
+) -> Result<PathBuf, String> {
+    let output_dir = match output_dir {
+        Some(output_dir) => output_dir,
+        None => return Err("The master was not started with an output directory".to_string()),
+    };
+    if !util::relative_path(sink) {
+        return Err(format!("{} is not relative to the output directory", sink));
+    }
+
+    let root = fs::canonicalize(output_dir).map_err(|e| e.to_string())?;
+    let target = root.join(sink);
+    let name = match target.file_name() {
+        Some(name) => name.to_string_lossy().to_string(),
+        None => return Err(format!("{} does not name a directory", sink)),
+    };
+    if target.exists() {
+        return Err(format!("{} already exists", target.display()));
+    }
+
+    let parent = target.parent().unwrap_or(&root).to_path_buf();
+    fs::create_dir_all(&parent).map_err(|e| e.to_string())?;
+    // Symlinks may point outside of the output directory
+    let parent = fs::canonicalize(&parent).map_err(|e| e.to_string())?;
+    if !parent.starts_with(&root) {
+        return Err(format!("{} is outside of the output directory", parent.display()));
+    }
+
+    let staging = parent.join(format!(".{}.{}", name, sutil::random_alphanum_string(8)));
+    let target = parent.join(&name);
+    let result = write_datapacks(&staging, datapacks).and_then(|_| fs::rename(&staging, &target));
+    if let Err(e) = result {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e.to_string());
+    }
+    return Ok(target);
+}
@@ -3,25 +3,39 @@
     Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
 */
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
 use std::mem;
+use std::net;
+use std::os::unix::io::{AsRawFd, FromRawFd};
 use std::sync::Arc;
+use std::thread::Builder;
+use std::time::Duration;
 use tokio::net::TcpStream;
 
 use byteorder::{BigEndian, WriteBytesExt};
 use chashmap::CHashMap;
+use crossbeam_channel::{Receiver, RecvTimeoutError};
+use libc;
 use log::{debug, error, info, warn};
-use protobuf::{CodedOutputStream, Message};
+use protobuf::{CodedOutputStream, Message, ProtobufResult};
 
+use cluster;
 use crossbeam::queue::MsQueue;
-use model::WJob;
+use model::{Executable, FileInput, FileSplit, JobType, JobWatchers, ResourceLimits, WJob, WTask};
+use shared::protos::user_cluster;
 use shared::protos::user_cluster::ServerMessage_Action::USER_TIMEOUT;
 use shared::protos::user_cluster::*;
-use users::User;
+use shared::util as sutil;
+use users::sessions::{self, SessionNames};
+use users::{Session, User};
 use util;
 
 use protobuf::RepeatedField;
 
+// How often a watcher checks its user is still connected to the cluster
+const WATCH_KEEPALIVE: Duration = Duration::from_secs(5);
+
 pub trait UserMessageHandler {
     fn handle_message(
         &mut self,
@@ -30,7 +44,10 @@ pub trait UserMessageHandler {
         users: Arc<CHashMap<String, User>>,
         jobs: Arc<CHashMap<String, WJob>>,
         jobs_queue: Arc<MsQueue<String>>,
+        tasks: Arc<CHashMap<String, WTask>>,
         data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        watchers: Arc<JobWatchers>,
+        session_names: Arc<SessionNames>,
     );
 }
 
@@ -77,7 +94,10 @@ impl UserMessageHandler for CreateConnectionRequest {
         users: Arc<CHashMap<String, User>>,
         _jobs: Arc<CHashMap<String, WJob>>,
         _jobs_queue: Arc<MsQueue<String>>,
-        _data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _tasks: Arc<CHashMap<String, WTask>>,
+        data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _watchers: Arc<JobWatchers>,
+        session_names: Arc<SessionNames>,
     ) {
         info!(
             "{} || Processing message as a CreateConnectionRequest",
@@ -88,16 +108,41 @@ impl UserMessageHandler for CreateConnectionRequest {
         // -- ACCEPT USER --
 
         let mut user_id = self.take_authentication();
+        if user_id.len() == 0 {
+            user_id = util::unique_id(&users);
+        }
+        let mut artifacts = Vec::new();
+        let executable_option =
+            load_executable(&message_id, self.take_executable(), &data, &mut artifacts);
+        let session_name = self.take_session_name();
 
-        if user_id.len() == 0 || !users.contains_key(&user_id) {
-            if user_id.len() == 0 {
-                user_id = util::unique_id(&users);
-            }
-
+        if executable_option.is_none() {
+            return_message.set_connection_accepted(false);
+            info!("{} || Sending rejection response back", &message_id);
+        } else if session_name.len() > 0
+            && (!sessions::valid_name(&session_name) || self.session_secret.len() == 0)
+        {
+            error!(
+                "{} || Session {} is invalid or has no secret!",
+                &message_id, &session_name
+            );
+            return_message.set_connection_accepted(false);
+            info!("{} || Sending rejection response back", &message_id);
+        } else if users.contains_key(&user_id) {
+            error!("{} || User ID {} is not unique!", &message_id, &user_id);
+            return_message.set_connection_accepted(false);
+            info!("{} || Sending rejection response back", &message_id);
+        } else if session_name.len() > 0 && !session_names.claim(&session_name, &user_id) {
+            error!("{} || Session {} already exists!", &message_id, &session_name);
+            return_message.set_connection_accepted(false);
+            info!("{} || Sending rejection response back", &message_id);
+        } else {
+            let executable = executable_option.unwrap();
+            let artifact_digest = executable.artifact_digest.clone();
             let docker_name = self.take_docker_name();
             info!(
-                "{} || Accepting user! User ID: {}, Docker Name: {}",
-                &message_id, &user_id, &docker_name
+                "{} || Accepting user! User ID: {}, Docker Name: {}, Executable: {}",
+                &message_id, &user_id, &docker_name, &executable.name
             );
             let new_user = User {
                 id: user_id.clone(),
@@ -105,16 +150,25 @@ impl UserMessageHandler for CreateConnectionRequest {
                 jobs: HashSet::new(),
                 to_be_deleted: false,
                 docker_name,
+                executable,
+                submissions: HashMap::new(),
+                session: if session_name.len() > 0 {
+                    Some(Session {
+                        name: session_name,
+                        secret_digest: sutil::sha256_digest(self.session_secret.as_bytes()),
+                        unsaved: true,
+                    })
+                } else {
+                    None
+                },
             };
             users.insert(user_id.clone(), new_user);
+            store_artifacts(&message_id, artifacts, &data);
 
             return_message.set_user_id(user_id);
             return_message.set_connection_accepted(true);
+            return_message.set_artifact_digest(artifact_digest);
             info!("{} || Sending acceptance response back", &message_id);
-        } else {
-            error!("{} || User ID {} is not unique!", &message_id, &user_id);
-            return_message.set_connection_accepted(false);
-            info!("{} || Sending rejection response back", &message_id);
         }
 
         let mut single_response = SingleUserResponse::new();
@@ -123,6 +177,128 @@ impl UserMessageHandler for CreateConnectionRequest {
     }
 }
 
+// Returns the digest of the user's artifact, empty if there is none, or None if it is unknown.
+// Uploaded artifacts are only stored once their submission has been accepted, see store_artifacts
+fn verify_artifact(
+    message_id: &String,
+    artifact: Vec<u8>,
+    digest: String,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    artifacts: &mut Vec<(String, Vec<u8>)>,
+) -> Option<String> {
+    if artifact.len() > 0 {
+        let digest = sutil::sha256_digest(&artifact);
+        artifacts.push((digest.clone(), artifact));
+        return Some(digest);
+    } else if digest.len() > 0 && !data.contains_key(&util::artifact_key(&digest)) {
+        error!(
+            "{} || Artifact {} has not been uploaded to the cluster!",
+            &message_id, &digest
+        );
+        return None;
+    }
+    return Some(digest);
+}
+
+fn store_artifacts(
+    message_id: &String,
+    artifacts: Vec<(String, Vec<u8>)>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+) {
+    for (digest, artifact) in artifacts {
+        let key = util::artifact_key(&digest);
+        if !data.contains_key(&key) {
+            info!(
+                "{} || Storing artifact {} ({} bytes)",
+                &message_id,
+                &digest,
+                artifact.len()
+            );
+            data.insert(key, vec![artifact]);
+        }
+    }
+}
+
+// None if the executable sets a reserved variable or refers to an artifact that has not been uploaded
+fn load_executable(
+    message_id: &String,
+    mut request: user_cluster::Executable,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    artifacts: &mut Vec<(String, Vec<u8>)>,
+) -> Option<Executable> {
+    if let Some(variable) = request.env.iter().find(|x| util::reserved_env_key(&x.key)) {
+        error!(
+            "{} || Executable sets the reserved environment variable {}!",
+            &message_id, &variable.key
+        );
+        return None;
+    }
+    let artifact_digest = verify_artifact(
+        message_id,
+        request.take_artifact(),
+        request.take_artifact_digest(),
+        data,
+        artifacts,
+    )?;
+    return Some(Executable {
+        name: request.take_name(),
+        args: request.take_args().into_vec(),
+        env: request
+            .take_env()
+            .into_iter()
+            .map(|x| (x.key, x.value))
+            .collect(),
+        artifact_digest,
+    });
+}
+
+// Returns the name and digest of each broadcast, or None if one is unnamed, repeated or unknown
+fn store_broadcasts(
+    message_id: &String,
+    broadcasts: RepeatedField<Broadcast>,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+) -> Option<Vec<(String, String)>> {
+    let mut stored: Vec<(String, String)> = Vec::new();
+    for mut broadcast in broadcasts.into_iter() {
+        let name = broadcast.take_name();
+        if name.len() == 0 || stored.iter().any(|(x, _)| *x == name) {
+            error!(
+                "{} || Broadcast \"{}\" is unnamed or given more than once!",
+                &message_id, &name
+            );
+            return None;
+        }
+
+        let digest = if broadcast.value.len() > 0 {
+            let value = broadcast.take_value();
+            let digest = sutil::sha256_digest(&value);
+            let key = util::broadcast_key(&digest);
+            if !data.contains_key(&key) {
+                info!(
+                    "{} || Storing broadcast {} ({} bytes)",
+                    &message_id,
+                    &digest,
+                    value.len()
+                );
+                data.insert(key, vec![value]);
+            }
+            digest
+        } else {
+            let digest = broadcast.take_digest();
+            if !data.contains_key(&util::broadcast_key(&digest)) {
+                error!(
+                    "{} || Broadcast {} has not been uploaded to the cluster!",
+                    &message_id, &digest
+                );
+                return None;
+            }
+            digest
+        };
+        stored.push((name, digest));
+    }
+    return Some(stored);
+}
+
 fn authenticate(
     message_id: &String,
     user_id: &String,
@@ -156,7 +332,10 @@ impl UserMessageHandler for ConnectionRequest {
         users: Arc<CHashMap<String, User>>,
         _jobs: Arc<CHashMap<String, WJob>>,
         _jobs_queue: Arc<MsQueue<String>>,
+        _tasks: Arc<CHashMap<String, WTask>>,
         _data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _watchers: Arc<JobWatchers>,
+        _session_names: Arc<SessionNames>,
     ) {
         info!(
             "{} || Processing message as a ConnectionRequest",
@@ -196,6 +375,80 @@ impl UserMessageHandler for ConnectionRequest {
     }
 }
 
+impl UserMessageHandler for ResumeSessionRequest {
+    fn handle_message(
+        &mut self,
+        message_id: &String,
+        stream: &mut TcpStream,
+        users: Arc<CHashMap<String, User>>,
+        jobs: Arc<CHashMap<String, WJob>>,
+        _jobs_queue: Arc<MsQueue<String>>,
+        _tasks: Arc<CHashMap<String, WTask>>,
+        _data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _watchers: Arc<JobWatchers>,
+        session_names: Arc<SessionNames>,
+    ) {
+        info!(
+            "{} || Processing message as a ResumeSessionRequest",
+            &message_id
+        );
+        let mut return_message = ResumeSessionResponse::new();
+        let secret_digest = sutil::sha256_digest(self.session_secret.as_bytes());
+
+        let user_option = session_names.find(&self.session_name).and_then(|x| users.get_mut(&x));
+        match user_option {
+            Some(mut user) => {
+                let authenticated = match &user.session {
+                    Some(session) => sutil::constant_time_eq(session.secret_digest.as_bytes(), secret_digest.as_bytes()),
+                    None => false,
+                };
+                if authenticated && !user.to_be_deleted {
+                    user.last_request = util::current_secs();
+
+                    // Job ids are only unique for each user, so they are tracked as {user_id}-{job_id}
+                    let prefix = format!("{}-", &user.id);
+                    let mut job_ids: Vec<(i32, bool)> = user
+                        .jobs
+                        .iter()
+                        .filter(|x| x.starts_with(&prefix))
+                        .filter_map(|x| {
+                            let job_id = x[prefix.len()..].parse::<i32>().ok()?;
+                            Some((job_id, jobs.contains_key(x)))
+                        })
+                        .collect();
+                    job_ids.sort();
+
+                    info!(
+                        "{} || Resumed session {} for user {}",
+                        &message_id, &self.session_name, &user.id
+                    );
+                    return_message.set_session_resumed(true);
+                    return_message.set_user_id(user.id.clone());
+                    return_message.set_next_job_id(job_ids.last().map(|x| x.0 + 1).unwrap_or(1));
+                    return_message.set_job_ids(job_ids.into_iter().filter(|x| x.1).map(|x| x.0).collect());
+                } else {
+                    warn!(
+                        "{} || Could not resume session {}, its secret does not match or it was closed!",
+                        &message_id, &self.session_name
+                    );
+                    return_message.set_session_resumed(false);
+                }
+            }
+            None => {
+                warn!(
+                    "{} || Session {} does not exist!",
+                    &message_id, &self.session_name
+                );
+                return_message.set_session_resumed(false);
+            }
+        }
+
+        let mut single_response = SingleUserResponse::new();
+        single_response.set_resume_session_response(return_message);
+        return write_single_response(&message_id, single_response, stream);
+    }
+}
+
 fn transfer_bytes(request: &mut InputAction, data_input: &mut Vec<Vec<u8>>) {
     let mut data_in = request.take_data_loc_in().to_vec();
     let mut i = 0;
@@ -211,10 +464,44 @@ fn transfer_bytes(request: &mut InputAction, data_input: &mut Vec<Vec<u8>>) {
 fn process_jobs(
     message_id: &String,
     docker_name: &String,
+    executable: &Executable,
+    input_jobs: RepeatedField<Job>,
+    broadcasts: RepeatedField<Broadcast>,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    user_id: &String,
+    data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+) -> Result<Vec<WJob>, String> {
+    // The input job's output is pinned while it is validated, so a rejected workload lets go of it
+    let mut pinned = None;
+    let result = build_jobs(
+        message_id,
+        docker_name,
+        executable,
+        input_jobs,
+        broadcasts,
+        jobs,
+        user_id,
+        data,
+        &mut pinned,
+    );
+    if result.is_err() {
+        if let Some((source_id, dependent_id)) = pinned {
+            cluster::unpin_output(jobs, data, &source_id, &dependent_id);
+        }
+    }
+    return result;
+}
+
+fn build_jobs(
+    message_id: &String,
+    docker_name: &String,
+    executable: &Executable,
     input_jobs: RepeatedField<Job>,
+    broadcasts: RepeatedField<Broadcast>,
     jobs: &Arc<CHashMap<String, WJob>>,
     user_id: &String,
     data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    pinned: &mut Option<(String, String)>,
 ) -> Result<Vec<WJob>, String> {
     let mut prev: String = "".parse().unwrap();
     let mut input_processed = false;
@@ -223,12 +510,37 @@ fn process_jobs(
 
     let mut data_loc = "".parse().unwrap();
     let mut data_bytes: Vec<Vec<u8>> = Vec::new();
+    let mut pinned_input: Option<String> = None;
+    let mut file_input: Option<FileInput> = None;
+    let mut artifacts: Vec<(String, Vec<u8>)> = Vec::new();
 
     if input_jobs.len() == 0 {
         warn!("{} || No jobs submitted!", &message_id);
         return Err("No jobs submitted".to_string());
     }
 
+    // Without a job to queue, the input would be loaded or pinned for nothing
+    let runs_jobs = input_jobs.iter().any(|x| match x.action {
+        Some(Job_oneof_action::map(_))
+        | Some(Job_oneof_action::repartition(_))
+        | Some(Job_oneof_action::coalesce(_)) => true,
+        _ => false,
+    });
+    if !runs_jobs {
+        warn!("{} || No map or reshape jobs submitted!", &message_id);
+        return Err("No map or reshape jobs submitted".to_string());
+    }
+
+    let broadcasts = match store_broadcasts(&message_id, broadcasts, &data) {
+        Some(broadcasts) => broadcasts,
+        None => return Err("Invalid broadcast".to_string()),
+    };
+    // The job that keeps a previous output pinned until it is done with it
+    let last_job_id = match input_jobs.iter().filter(|x| x.action.is_some()).last() {
+        Some(j) => format!("{}-{}", user_id, j.job_id),
+        None => "".to_string(),
+    };
+
     info!("Processing {} jobs!", input_jobs.len());
     for j in input_jobs.into_iter() {
         if let Some(action) = j.action {
@@ -245,14 +557,56 @@ fn process_jobs(
                             return Err("There has been a job clash!".to_string());
                         }
 
-                        let job = WJob::new(
+                        // Jobs run with the image and executable of the session unless they set their own
+                        let (job_docker_name, job_executable) =
+                            if map.docker_name.len() == 0 && !map.has_executable() {
+                                (docker_name.clone(), executable.clone())
+                            } else if map.has_executable() {
+                                match load_executable(&message_id, map.take_executable(), &data, &mut artifacts) {
+                                    Some(job_executable) => (map.take_docker_name(), job_executable),
+                                    None => return Err("Invalid executable".to_string()),
+                                }
+                            } else {
+                                (map.take_docker_name(), executable.clone())
+                            };
+
+                        let mut job = WJob::new(
                             job_id.clone(),
                             user_id.clone(),
                             prev,
-                            docker_name.clone(),
+                            job_docker_name,
+                            job_executable,
                             util::convert_map_type(&map.mapType),
                             map.take_function_closure(),
+                            map.take_required_labels()
+                                .into_iter()
+                                .map(|x| (x.key, x.value))
+                                .collect(),
+                            map.task_timeout.max(0) as u64,
                         );
+                        job.pinned_input = pinned_input.clone();
+                        job.broadcasts = broadcasts.clone();
+                        // Only the first job reads the files
+                        job.file_input = file_input.take();
+                        if map.has_sink() {
+                            let sink = map.take_sink().take_path();
+                            if sink.len() == 0 || !util::relative_path(&sink) {
+                                error!(
+                                    "{} || Sink {} of job {} is not a relative path! Cancelling submission...",
+                                    &message_id, &sink, &job_id
+                                );
+                                return Err("Invalid sink".to_string());
+                            }
+                            job.sink = Some(sink);
+                        }
+                        if map.has_limits() {
+                            let limits = map.get_limits();
+                            job.limits = ResourceLimits {
+                                memory_mb: limits.memory_mb.max(0) as u64,
+                                cpu_percent: limits.cpu_percent.max(0) as u64,
+                                open_files: limits.open_files.max(0) as u64,
+                            };
+                        }
 
                         // Change prev job next_job if exists
                         if !jobs_to_add.is_empty() {
@@ -269,6 +623,63 @@ fn process_jobs(
                         ));
                     }
                 }
+                reshape @ Job_oneof_action::repartition(_)
+                | reshape @ Job_oneof_action::coalesce(_) => {
+                    if !input_processed {
+                        return Err(String::from(
+                            "First job is a repartition job! Cancelling submission...",
+                        ));
+                    }
+                    let job_id = format!("{}-{}", user_id, j.job_id.to_string());
+
+                    if jobs.contains_key(&job_id) {
+                        error!(
+                            "{} || Job {} already exists! Cancelling submission...",
+                            &message_id, &job_id
+                        );
+                        return Err("There has been a job clash!".to_string());
+                    }
+
+                    let job_type = match reshape {
+                        Job_oneof_action::repartition(ref action) if action.partitions > 0 => {
+                            JobType::Repartition(action.partitions as usize)
+                        }
+                        Job_oneof_action::coalesce(ref action) if action.partitions > 0 => {
+                            JobType::Coalesce(action.partitions as usize)
+                        }
+                        _ => {
+                            error!(
+                                "{} || Job {} has no partitions! Cancelling submission...",
+                                &message_id, &job_id
+                            );
+                            return Err("Invalid number of partitions".to_string());
+                        }
+                    };
+
+                    // Run by the master on the data itself, so there is no closure, image or executable
+                    let mut job = WJob::new(
+                        job_id.clone(),
+                        user_id.clone(),
+                        prev,
+                        "".to_string(),
+                        Executable::default(),
+                        job_type,
+                        Vec::new(),
+                        HashMap::new(),
+                        0,
+                    );
+                    job.pinned_input = pinned_input.clone();
+                    job.file_input = file_input.take();
+
+                    if !jobs_to_add.is_empty() {
+                        let mut prev_job = jobs_to_add.pop().unwrap();
+                        prev_job.set_output_id(job_id.clone());
+                        jobs_to_add.push(prev_job);
+                    }
+
+                    prev = job_id;
+                    jobs_to_add.push(job);
+                }
                 Job_oneof_action::input(mut input) => {
                     if input_processed {
                         return Err(String::from(
@@ -293,6 +704,77 @@ fn process_jobs(
                         input_processed = true;
                     }
                 }
+                Job_oneof_action::previous_output(previous) => {
+                    if input_processed {
+                        return Err(String::from(
+                            "Multiple input jobs! Cancelling submission...",
+                        ));
+                    }
+                    let source_id = format!("{}-{}", user_id, previous.job_id);
+                    // Checked and pinned under one lock, so the output cannot be released in between
+                    let available = match jobs.get_mut(&source_id) {
+                        Some(mut source) => {
+                            let available = source.user_id == *user_id
+                                && source.completed()
+                                && !source.output_released
+                                && data.contains_key(&source_id);
+                            if available && source.pinned_by.insert(last_job_id.clone()) {
+                                *pinned = Some((source_id.clone(), last_job_id.clone()));
+                            }
+                            available
+                        }
+                        None => false,
+                    };
+                    if !available {
+                        error!(
+                            "{} || Job {} has no output to use as input! Cancelling submission...",
+                            &message_id, &source_id
+                        );
+                        return Err("Input job has no output".to_string());
+                    }
+
+                    // The output is read in place, so no data is loaded for this workload
+                    prev = source_id.clone();
+                    pinned_input = Some(source_id);
+                    input_processed = true;
+                }
+                Job_oneof_action::file_input(mut input) => {
+                    if input_processed {
+                        return Err(String::from(
+                            "Multiple input jobs! Cancelling submission...",
+                        ));
+                    }
+                    let job_id = format!("{}-{}", user_id, j.job_id.to_string());
+
+                    if jobs.contains_key(&job_id) {
+                        error!(
+                            "{} || Job {} already exists! Cancelling submission...",
+                            &message_id, &job_id
+                        );
+                        return Err("There has been a job clash!".to_string());
+                    }
+
+                    let split_size = input.split_size.max(0) as u64;
+                    let split = match input.split_mode {
+                        FileInputAction_SplitMode::LINES => FileSplit::Lines(split_size),
+                        FileInputAction_SplitMode::BYTES => FileSplit::Bytes(split_size),
+                        FileInputAction_SplitMode::FILES => FileSplit::Files,
+                    };
+                    if input.path.len() == 0 || (split != FileSplit::Files && split_size == 0) {
+                        return Err(String::from(
+                            "File input needs a path and a split size! Cancelling submission...",
+                        ));
+                    }
+
+                    // The files are read into the data once the first job is queued
+                    prev = job_id.clone();
+                    data_loc = job_id;
+                    file_input = Some(FileInput {
+                        pattern: input.take_path(),
+                        split,
+                    });
+                    input_processed = true;
+                }
             }
         } else {
             warn!(
@@ -303,11 +785,24 @@ fn process_jobs(
         }
     }
 
-    debug!("Placing data at {}", &data_loc);
-    data.insert(data_loc, data_bytes);
+    if pinned_input.is_none() {
+        debug!("Placing data at {}", &data_loc);
+        data.insert(data_loc, data_bytes);
+    }
+    store_artifacts(&message_id, artifacts, &data);
     return Ok(jobs_to_add);
 }
 
+// Identifies a submission by its jobs and broadcasts, whatever idempotency key it was sent with
+fn submission_digest(submission: &JobSubmission) -> String {
+    let mut submission = submission.clone();
+    submission.clear_idempotency_key();
+    return match submission.write_to_bytes() {
+        Ok(bytes) => sutil::sha256_digest(&bytes),
+        Err(_) => "".to_string(),
+    };
+}
+
 impl UserMessageHandler for JobSubmission {
     fn handle_message(
         &mut self,
@@ -316,7 +811,10 @@ impl UserMessageHandler for JobSubmission {
         users: Arc<CHashMap<String, User>>,
         jobs: Arc<CHashMap<String, WJob>>,
         jobs_queue: Arc<MsQueue<String>>,
+        _tasks: Arc<CHashMap<String, WTask>>,
         data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _watchers: Arc<JobWatchers>,
+        _session_names: Arc<SessionNames>,
     ) {
         info!(
             "{} || Processing message as a JobSubmissionHandler",
@@ -325,46 +823,84 @@ impl UserMessageHandler for JobSubmission {
         let mut single_response = SingleUserResponse::new();
 
         if authenticate(&message_id, &self.user_id, &users) {
-            let docker_name = users.get(&self.user_id).unwrap().docker_name.clone();
-            match process_jobs(
-                &message_id,
-                &docker_name,
-                self.take_jobs(),
-                &jobs,
-                &self.user_id,
-                &data,
-            ) {
-                Ok(jobs_to_add) => {
+            let idempotency_key = self.take_idempotency_key();
+            let digest = submission_digest(self);
+            let mut return_message = JobSubmissionResponse::new();
+
+            // Held until the jobs are added, so a retry waits for the outcome of the first attempt
+            let mut user = users.get_mut(&self.user_id).unwrap();
+            let previous_digest = user.submissions.get(&idempotency_key).cloned();
+            match previous_digest {
+                Some(ref previous_digest) if *previous_digest == digest => {
                     info!(
-                        "{} || Added jobs successfully! {}",
-                        &message_id, &self.user_id
+                        "{} || Submission {} from user {} was already accepted",
+                        &message_id, &idempotency_key, &self.user_id
                     );
+                    return_message.set_job_accepted(true);
+                }
+                Some(_) => {
+                    warn!(
+                        "{} || Submission {} from user {} does not match the one accepted with that key!",
+                        &message_id, &idempotency_key, &self.user_id
+                    );
+                    return_message.set_job_accepted(false);
+                    return_message.set_conflict(true);
+                    return_message.set_error(format!(
+                        "Idempotency key {} was already used for a different submission",
+                        &idempotency_key
+                    ));
+                }
+                None => match process_jobs(
+                    &message_id,
+                    &user.docker_name,
+                    &user.executable,
+                    self.take_jobs(),
+                    self.take_broadcasts(),
+                    &jobs,
+                    &self.user_id,
+                    &data,
+                ) {
+                    Ok(ref jobs_to_add) if jobs_to_add.is_empty() => {
+                        warn!("{} || Workload from user {} has no jobs to run!", &message_id, self.user_id);
 
-                    let first_job = jobs_to_add.first().unwrap().id.clone();
-                    info!("{} || First job: {}", &message_id, &first_job);
-
-                    for job in jobs_to_add {
-                        info!("{} || Adding job {} ", &message_id, &job.id);
-                        let mut user = users.get_mut(&mut self.user_id).unwrap();
-                        user.jobs.insert(job.id.clone()); // Adding to user's job map
-                        data.insert(job.id.clone(), Vec::new());
-                        jobs.insert(job.id.clone(), job); // Adding to global static job map
+                        return_message.set_job_accepted(false);
+                        return_message.set_error("No jobs to run".to_string());
                     }
+                    Ok(jobs_to_add) => {
+                        info!(
+                            "{} || Added jobs successfully! {}",
+                            &message_id, &self.user_id
+                        );
 
-                    jobs_queue.push(first_job);
+                        let first_job = jobs_to_add[0].id.clone();
+                        info!("{} || First job: {}", &message_id, &first_job);
 
-                    let mut return_message = JobSubmissionResponse::new();
-                    return_message.set_job_accepted(true);
-                    single_response.set_job_submission_response(return_message);
-                }
-                Err(_) => {
-                    warn!("{} || Could not add workload from user {}. Error encountered in validation/processing!", &message_id, self.user_id);
+                        for job in jobs_to_add {
+                            info!("{} || Adding job {} ", &message_id, &job.id);
+                            user.jobs.insert(job.id.clone()); // Adding to user's job map
+                            data.insert(job.id.clone(), Vec::new());
+                            jobs.insert(job.id.clone(), job); // Adding to global static job map
+                        }
 
-                    let mut return_message = JobSubmissionResponse::new();
-                    return_message.set_job_accepted(false);
-                    single_response.set_job_submission_response(return_message);
-                }
+                        jobs_queue.push(first_job);
+
+                        if idempotency_key.len() > 0 {
+                            user.submissions.insert(idempotency_key, digest);
+                        }
+                        if let Some(session) = user.session.as_mut() {
+                            session.unsaved = true;
+                        }
+                        return_message.set_job_accepted(true);
+                    }
+                    Err(e) => {
+                        warn!("{} || Could not add workload from user {}. Error encountered in validation/processing!", &message_id, self.user_id);
+
+                        return_message.set_job_accepted(false);
+                        return_message.set_error(e);
+                    }
+                },
             }
+            single_response.set_job_submission_response(return_message);
         } else {
             let mut return_message = ServerMessage::new();
             return_message.set_action(USER_TIMEOUT);
@@ -387,7 +923,10 @@ impl UserMessageHandler for DataRetrievalRequest {
         users: Arc<CHashMap<String, User>>,
         jobs: Arc<CHashMap<String, WJob>>,
         _jobs_queue: Arc<MsQueue<String>>,
+        _tasks: Arc<CHashMap<String, WTask>>,
         data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _watchers: Arc<JobWatchers>,
+        _session_names: Arc<SessionNames>,
     ) {
         info!(
             "{} || Processing message as a DataRetrievalRequest",
@@ -442,6 +981,31 @@ impl UserMessageHandler for DataRetrievalRequest {
     }
 }
 
+fn job_status(job_id: i32, job: &WJob, tasks: &Arc<CHashMap<String, WTask>>) -> JobStatus {
+    let mut status = JobStatus::new();
+    status.set_job_id(job_id);
+    status.set_status(util::convert_job_status(&job.status));
+    status.set_completed_tasks(job.completed_tasks);
+    status.set_total_tasks(job.total_tasks);
+    let (progress, progress_message) = util::job_progress(job, tasks);
+    status.set_progress(progress);
+    status.set_message(progress_message);
+
+    let mut accumulators: Vec<Accumulator> = job
+        .accumulators
+        .iter()
+        .map(|(name, value)| {
+            let mut accumulator = Accumulator::new();
+            accumulator.set_name(name.clone());
+            accumulator.set_value(*value);
+            accumulator
+        })
+        .collect();
+    accumulators.sort_by(|a, b| a.name.cmp(&b.name));
+    status.set_accumulators(RepeatedField::from_vec(accumulators));
+    return status;
+}
+
 impl UserMessageHandler for JobStatusRequest {
     fn handle_message(
         &mut self,
@@ -450,7 +1014,10 @@ impl UserMessageHandler for JobStatusRequest {
         users: Arc<CHashMap<String, User>>,
         jobs: Arc<CHashMap<String, WJob>>,
         _jobs_queue: Arc<MsQueue<String>>,
+        tasks: Arc<CHashMap<String, WTask>>,
         _data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _watchers: Arc<JobWatchers>,
+        _session_names: Arc<SessionNames>,
     ) {
         info!(
             "{} || Processing message as a JobStatusRequest",
@@ -468,9 +1035,7 @@ impl UserMessageHandler for JobStatusRequest {
                 if user.jobs.contains(&unique_id) {
                     let job = jobs.get(&unique_id).unwrap();
 
-                    let mut status = JobStatus::new();
-                    status.set_job_id(job_id.clone());
-                    status.set_status(util::convert_job_status(&job.status));
+                    let status = job_status(*job_id, &job, &tasks);
                     debug!(
                         "{} || {} | {:?} ",
                         &message_id,
@@ -501,3 +1066,388 @@ impl UserMessageHandler for JobStatusRequest {
         return write_single_response(&message_id, single_response, stream);
     }
 }
+
+fn task_description(task: &WTask) -> TaskDescription {
+    let mut description = TaskDescription::new();
+    description.set_task_id(task.id.clone());
+    description.set_status(util::convert_task_status(&task.status));
+    description.set_worker_id(task.worker_id.clone());
+    description.set_attempts(task.attempts as i32);
+    description.set_started_at(task.started_at);
+    description.set_finished_at(task.finished_at);
+    description.set_input_size(task.input_size);
+    description.set_output_size(task.output_size);
+    description.set_failure_reason(task.failure_reason.clone());
+    description.set_progress(task.progress);
+    return description;
+}
+
+impl UserMessageHandler for DescribeJobRequest {
+    fn handle_message(
+        &mut self,
+        message_id: &String,
+        stream: &mut TcpStream,
+        users: Arc<CHashMap<String, User>>,
+        jobs: Arc<CHashMap<String, WJob>>,
+        _jobs_queue: Arc<MsQueue<String>>,
+        tasks: Arc<CHashMap<String, WTask>>,
+        _data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        _watchers: Arc<JobWatchers>,
+        _session_names: Arc<SessionNames>,
+    ) {
+        info!(
+            "{} || Processing message as a DescribeJobRequest",
+            &message_id
+        );
+        let mut single_response = SingleUserResponse::new();
+        let mut error_message = ServerMessage::new();
+
+        if authenticate(&message_id, &self.user_id, &users) {
+            let user = users.get(&mut self.user_id).unwrap();
+            let unique_id = format!("{}-{}", self.user_id, self.job_id);
+            match (user.jobs.contains(&unique_id), jobs.get(&unique_id)) {
+                (true, Some(job)) => {
+                    let mut job_tasks: Vec<WTask> = job
+                        .tasks
+                        .iter()
+                        .filter_map(|x| tasks.get(x))
+                        .map(|x| x.clone())
+                        .collect();
+                    // Listed in partition order
+                    job_tasks.sort_by_key(|x| x.data_in_loc);
+                    let task_descriptions = job_tasks.iter().map(task_description).collect();
+
+                    let mut describe_response = DescribeJobResponse::new();
+                    describe_response.set_job_status(job_status(self.job_id, &job, &tasks));
+                    describe_response.set_tasks(RepeatedField::from_vec(task_descriptions));
+                    single_response.set_describe_job_response(describe_response);
+                    return write_single_response(&message_id, single_response, stream);
+                }
+                _ => {
+                    warn!(
+                        "{} || Could not find job {} for user {}!",
+                        &message_id, self.job_id, self.user_id
+                    );
+                    error_message.set_action(ServerMessage_Action::MISSING_JOBS);
+                }
+            }
+        } else {
+            warn!(
+                "{} || Received request from unknown user with id {} ",
+                &message_id, self.user_id
+            );
+            error_message.set_action(USER_TIMEOUT);
+        }
+        single_response.set_server_message(error_message);
+        return write_single_response(&message_id, single_response, stream);
+    }
+}
+
+// Watched jobs are paired with their id unique across users
+fn watched_statuses(
+    job_ids: &Vec<(i32, String)>,
+    jobs: &Arc<CHashMap<String, WJob>>,
+    tasks: &Arc<CHashMap<String, WTask>>,
+) -> Vec<JobStatus> {
+    return job_ids
+        .iter()
+        .filter_map(|x| jobs.get(&x.1).map(|job| job_status(x.0, &job, tasks)))
+        .collect();
+}
+
+fn workload_finished(statuses: &Vec<JobStatus>) -> bool {
+    return statuses.iter().all(|x| x.status == JobStatus_Status::COMPLETED)
+        || statuses.iter().any(|x| match x.status {
+            JobStatus_Status::HALTED
+            | JobStatus_Status::CANCELLED
+            | JobStatus_Status::TIMED_OUT
+            | JobStatus_Status::OUT_OF_MEMORY => true,
+            _ => false,
+        });
+}
+
+fn watch_response(statuses: Vec<JobStatus>, finished: bool) -> SingleUserResponse {
+    let mut watch_response = WatchJobsResponse::new();
+    watch_response.set_job_statuses(RepeatedField::from_vec(statuses));
+    watch_response.set_finished(finished);
+
+    let mut single_response = SingleUserResponse::new();
+    single_response.set_watch_jobs_response(watch_response);
+    return single_response;
+}
+
+fn write_message<W: Write>(message: &SingleUserResponse, stream: &mut W) -> ProtobufResult<()> {
+    stream.write_u32::<BigEndian>(message.compute_size())?;
+    let mut output_stream = CodedOutputStream::new(stream);
+    message.write_to(&mut output_stream)?;
+    return output_stream.flush();
+}
+
+// The tokio stream is closed once the handler returns, so the watcher writes to its own blocking copy
+fn blocking_stream(stream: &TcpStream) -> io::Result<net::TcpStream> {
+    let fd = unsafe { libc::dup(stream.as_raw_fd()) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let stream = unsafe { net::TcpStream::from_raw_fd(fd) };
+    stream.set_nonblocking(false)?;
+    return Ok(stream);
+}
+
+// Users never write on a watch connection, so a readable end of stream means they have gone
+fn peer_closed(stream: &net::TcpStream) -> bool {
+    if stream.set_nonblocking(true).is_err() {
+        return true;
+    }
+    let mut buffer = [0; 1];
+    let closed = match stream.peek(&mut buffer) {
+        Ok(0) => true,
+        Ok(_) => false,
+        Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => false,
+        Err(_) => true,
+    };
+    return stream.set_nonblocking(false).is_err() || closed;
+}
+
+fn watch_jobs(
+    message_id: &String,
+    mut stream: net::TcpStream,
+    user_id: String,
+    job_ids: Vec<(i32, String)>,
+    users: Arc<CHashMap<String, User>>,
+    jobs: Arc<CHashMap<String, WJob>>,
+    tasks: Arc<CHashMap<String, WTask>>,
+    receiver: Receiver<String>,
+) {
+    loop {
+        let changed_id = match receiver.recv_timeout(WATCH_KEEPALIVE) {
+            Ok(changed_id) => changed_id,
+            Err(RecvTimeoutError::Timeout) => {
+                if peer_closed(&stream) {
+                    info!("{} || Stopped watching jobs, user {} has disconnected", &message_id, &user_id);
+                    return;
+                }
+                // An open watch keeps the user from being kicked for inactivity
+                match users.get_mut(&user_id) {
+                    Some(mut user) => user.last_request = util::current_secs(),
+                    None => {
+                        warn!("{} || User {} was removed while watching jobs", &message_id, &user_id);
+                        return;
+                    }
+                }
+                continue;
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+        };
+
+        let job_id = match job_ids.iter().find(|x| x.1 == changed_id) {
+            Some(x) => x.0,
+            None => continue,
+        };
+        let statuses = watched_statuses(&job_ids, &jobs, &tasks);
+        let finished = workload_finished(&statuses);
+
+        // The last response carries every watched job, so the user does not have to ask again
+        let changed = if finished {
+            statuses
+        } else {
+            statuses.into_iter().filter(|x| x.job_id == job_id).collect()
+        };
+        debug!("{} || Pushing status of job {} to user {}", &message_id, job_id, &user_id);
+        if let Err(e) = write_message(&watch_response(changed, finished), &mut stream) {
+            info!("{} || Stopped watching jobs, user {} has disconnected. Error: {}", &message_id, &user_id, e.to_string());
+            return;
+        }
+        if finished {
+            info!("{} || Watched jobs of user {} have finished", &message_id, &user_id);
+            return;
+        }
+    }
+}
+
+impl UserMessageHandler for WatchJobsRequest {
+    fn handle_message(
+        &mut self,
+        message_id: &String,
+        stream: &mut TcpStream,
+        users: Arc<CHashMap<String, User>>,
+        jobs: Arc<CHashMap<String, WJob>>,
+        _jobs_queue: Arc<MsQueue<String>>,
+        tasks: Arc<CHashMap<String, WTask>>,
+        _data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+        watchers: Arc<JobWatchers>,
+        _session_names: Arc<SessionNames>,
+    ) {
+        info!(
+            "{} || Processing message as a WatchJobsRequest",
+            &message_id
+        );
+        let mut single_response = SingleUserResponse::new();
+        if !authenticate(&message_id, &self.user_id, &users.clone()) {
+            let mut return_message = ServerMessage::new();
+            return_message.set_action(USER_TIMEOUT);
+            single_response.set_server_message(return_message);
+            warn!(
+                "{} || Received request from unknown user with id {} ",
+                &message_id, self.user_id
+            );
+            return write_single_response(&message_id, single_response, stream);
+        }
+
+        let job_ids: Vec<(i32, String)> = self
+            .job_ids
+            .iter()
+            .map(|x| (*x, format!("{}-{}", self.user_id, x)))
+            .collect();
+        let missing_job = {
+            let user = users.get(&self.user_id).unwrap();
+            job_ids.iter().find(|x| !user.jobs.contains(&x.1)).map(|x| x.0)
+        };
+        if let Some(job_id) = missing_job {
+            warn!("{} || User {} tried to watch an unknown job with {}. Sending response back now", &message_id, self.user_id, job_id);
+            let mut error_message = ServerMessage::new();
+            error_message.set_action(ServerMessage_Action::MISSING_JOBS);
+            single_response.set_server_message(error_message);
+            return write_single_response(&message_id, single_response, stream);
+        }
+
+        // Subscribed before the first response, so no change in between is missed
+        let receiver = watchers.subscribe();
+        let statuses = watched_statuses(&job_ids, &jobs, &tasks);
+        let finished = workload_finished(&statuses);
+        write_single_response(&message_id, watch_response(statuses, finished), stream);
+        if finished {
+            return;
+        }
+
+        let watch_stream = match blocking_stream(stream) {
+            Ok(watch_stream) => watch_stream,
+            Err(e) => {
+                error!("{} || Could not keep the connection open to watch jobs! Error: {}", &message_id, e.to_string());
+                return;
+            }
+        };
+
+        // Jobs can run for hours, so the user server is not held up while they are watched
+        let watcher_message_id = message_id.clone();
+        let user_id = self.take_user_id();
+        let spawned = Builder::new()
+            .name(format!("watcher-{}", &message_id))
+            .spawn(move || {
+                watch_jobs(&watcher_message_id, watch_stream, user_id, job_ids, users, jobs, tasks, receiver)
+            });
+        if let Err(e) = spawned {
+            error!("{} || Could not start watching jobs! Error: {}", &message_id, e.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use model::{JobStatus, TaskStatus};
+    use util::tests::{job, task};
+
+    #[test]
+    fn test_sanity_task_description() {
+        let mut failed = task("user-1-3", TaskStatus::TimedOut, 0.4, "", 0);
+        failed.worker_id = "w1".to_string();
+        failed.attempts = 2;
+        failed.started_at = 100;
+        failed.finished_at = 160;
+        failed.input_size = 2048;
+        failed.failure_reason = "Exceeded its 60s timeout on worker w1".to_string();
+
+        let description = task_description(&failed);
+        assert_eq!(description.get_task_id(), "user-1-3");
+        assert_eq!(description.get_status(), TaskDescription_Status::TIMED_OUT);
+        assert_eq!(description.get_worker_id(), "w1");
+        assert_eq!(description.get_attempts(), 2);
+        assert_eq!(description.get_started_at(), 100);
+        assert_eq!(description.get_finished_at(), 160);
+        assert_eq!(description.get_input_size(), 2048);
+        assert_eq!(description.get_output_size(), 0);
+        assert_eq!(description.get_failure_reason(), "Exceeded its 60s timeout on worker w1");
+        assert_eq!(description.get_progress(), 0.4);
+    }
+
+    #[test]
+    fn test_sanity_job_status_accumulators() {
+        let mut summed = job(vec![], 2, 2);
+        summed.accumulators.insert("words".to_string(), 12.0);
+        summed.accumulators.insert("errors".to_string(), 1.5);
+
+        // Sorted by name, so users see them in the same order every time
+        let status = job_status(1, &summed, &Arc::new(CHashMap::new()));
+        let accumulators: Vec<(&str, f64)> = status
+            .get_accumulators()
+            .iter()
+            .map(|x| (x.get_name(), x.get_value()))
+            .collect();
+        assert_eq!(accumulators, vec![("errors", 1.5), ("words", 12.0)]);
+    }
+
+    #[test]
+    fn test_sanity_previous_output_without_jobs() {
+        let jobs = Arc::new(CHashMap::new());
+        let data = Arc::new(CHashMap::new());
+        let mut source = job(vec![], 1, 1);
+        source.status = JobStatus::Completed;
+        jobs.insert(source.id.clone(), source);
+        data.insert("user-1".to_string(), vec![b"a".to_vec()]);
+
+        let mut previous = PreviousOutputAction::new();
+        previous.set_job_id(1);
+        let mut input = Job::new();
+        input.set_job_id(2);
+        input.set_previous_output(previous);
+
+        let result = process_jobs(
+            &"message".to_string(),
+            &"".to_string(),
+            &Executable::default(),
+            RepeatedField::from_vec(vec![input]),
+            RepeatedField::new(),
+            &jobs,
+            &"user".to_string(),
+            &data,
+        );
+        assert_eq!(result.err(), Some("No map or reshape jobs submitted".to_string()));
+        assert_eq!(jobs.get(&"user-1".to_string()).unwrap().pinned_by.is_empty(), true);
+        assert_eq!(jobs.len(), 1);
+    }
+
+    fn submission(key: &str, job_ids: Vec<i32>, broadcast: &[u8]) -> JobSubmission {
+        let mut submission = JobSubmission::new();
+        submission.set_user_id("abcde".to_string());
+        submission.set_idempotency_key(key.to_string());
+        submission.set_jobs(RepeatedField::from_vec(
+            job_ids
+                .into_iter()
+                .map(|x| {
+                    let mut job = Job::new();
+                    job.set_job_id(x);
+                    job
+                })
+                .collect(),
+        ));
+        let mut value = Broadcast::new();
+        value.set_name("table".to_string());
+        value.set_value(broadcast.to_vec());
+        submission.set_broadcasts(RepeatedField::from_vec(vec![value]));
+        return submission;
+    }
+
+    #[test]
+    fn test_sanity_submission_digest() {
+        let digest = submission_digest(&submission("nightly", vec![1, 2], b"v1"));
+        assert_eq!(digest.len(), 64);
+
+        // A retry may come with any key, only its contents matter
+        assert_eq!(submission_digest(&submission("retry", vec![1, 2], b"v1")), digest);
+        assert_eq!(submission_digest(&submission("", vec![1, 2], b"v1")), digest);
+        assert_ne!(submission_digest(&submission("nightly", vec![1, 3], b"v1")), digest);
+        assert_ne!(submission_digest(&submission("nightly", vec![2, 1], b"v1")), digest);
+        assert_ne!(submission_digest(&submission("nightly", vec![1, 2], b"v2")), digest);
+    }
+}
@@ -3,10 +3,13 @@
     Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
 */
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+use model::Executable;
 
 pub mod handlers;
 pub mod server;
+pub mod sessions;
 
 pub struct User {
     pub id: String,
@@ -14,4 +17,16 @@ pub struct User {
     pub jobs: HashSet<String>,
     pub to_be_deleted: bool,
     pub docker_name: String,
+    pub executable: Executable,
+    // Digest of each accepted submission by its idempotency key
+    pub submissions: HashMap<String, String>,
+    pub session: Option<Session>,
+}
+
+// A named session is kept for the session TTL rather than the default 100 seconds, and can be resumed with its secret
+pub struct Session {
+    pub name: String,
+    pub secret_digest: String,
+    // Set whenever the session changes, so the cluster saves it again
+    pub unsaved: bool,
 }
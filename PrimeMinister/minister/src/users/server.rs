@@ -20,10 +20,11 @@ use crossbeam::queue::MsQueue;
 use log::{error, info, trace, warn};
 
 use config::{Server, State};
-use model::WJob;
+use model::{JobWatchers, WJob, WTask};
 use shared::protos::user_cluster::*;
 use shared::util;
 use users::handlers::*;
+use users::sessions::SessionNames;
 use users::User;
 
 fn server(
@@ -33,7 +34,10 @@ fn server(
     users: Arc<CHashMap<String, User>>,
     jobs: Arc<CHashMap<String, WJob>>,
     jobs_queue: Arc<MsQueue<String>>,
+    tasks: Arc<CHashMap<String, WTask>>,
     data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    watchers: Arc<JobWatchers>,
+    session_names: Arc<SessionNames>,
 ) {
     let server = listener
         .incoming()
@@ -42,7 +46,10 @@ fn server(
             let users = users.clone();
             let jobs = jobs.clone();
             let jobs_queue = jobs_queue.clone();
+            let tasks = tasks.clone();
             let data = data.clone();
+            let watchers = watchers.clone();
+            let session_names = session_names.clone();
             let consensus_mode = consensus_mode.clone();
             let consensus_state = consensus_state.clone();
 
@@ -52,7 +59,10 @@ fn server(
                     users,
                     jobs,
                     jobs_queue,
+                    tasks,
                     data,
+                    watchers,
+                    session_names,
                     consensus_mode,
                     consensus_state,
                 );
@@ -69,7 +79,10 @@ fn process_message(
     users: Arc<CHashMap<String, User>>,
     jobs: Arc<CHashMap<String, WJob>>,
     jobs_queue: Arc<MsQueue<String>>,
+    tasks: Arc<CHashMap<String, WTask>>,
     data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    watchers: Arc<JobWatchers>,
+    session_names: Arc<SessionNames>,
     consensus_mode: bool,
     consensus_state: Arc<State>,
 ) -> impl Future<Item = (), Error = ()> + Send {
@@ -92,7 +105,10 @@ fn process_message(
                     users,
                     jobs,
                     jobs_queue,
+                    tasks,
                     data,
+                    watchers,
+                    session_names,
                 );
             } else {
                 let current_id = consensus_state.id_counter.load(SeqCst);
@@ -105,7 +121,10 @@ fn process_message(
                         users,
                         jobs,
                         jobs_queue,
+                        tasks,
                         data,
+                        watchers,
+                        session_names,
                     );
                 } else {
                     error!(
@@ -132,24 +151,36 @@ fn handle_message(
     users: Arc<CHashMap<String, User>>,
     jobs: Arc<CHashMap<String, WJob>>,
     jobs_queue: Arc<MsQueue<String>>,
+    tasks: Arc<CHashMap<String, WTask>>,
     data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    watchers: Arc<JobWatchers>,
+    session_names: Arc<SessionNames>,
 ) {
     if let Some(request) = worker_message.request {
         match request {
             SingleUserRequest_oneof_request::create_connection_request(mut x) => {
-                x.handle_message(&message_id, stream, users, jobs, jobs_queue, data)
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
             }
             SingleUserRequest_oneof_request::connection_request(mut x) => {
-                x.handle_message(&message_id, stream, users, jobs, jobs_queue, data)
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
             }
             SingleUserRequest_oneof_request::job_submission(mut x) => {
-                x.handle_message(&message_id, stream, users, jobs, jobs_queue, data)
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
             }
             SingleUserRequest_oneof_request::data_retrieval_request(mut x) => {
-                x.handle_message(&message_id, stream, users, jobs, jobs_queue, data)
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
             }
             SingleUserRequest_oneof_request::job_status_request(mut x) => {
-                x.handle_message(&message_id, stream, users, jobs, jobs_queue, data)
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
+            }
+            SingleUserRequest_oneof_request::watch_jobs_request(mut x) => {
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
+            }
+            SingleUserRequest_oneof_request::describe_job_request(mut x) => {
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
+            }
+            SingleUserRequest_oneof_request::resume_session_request(mut x) => {
+                x.handle_message(&message_id, stream, users, jobs, jobs_queue, tasks, data, watchers, session_names)
             }
         };
     } else {
@@ -171,7 +202,10 @@ pub fn start(
     users: Arc<CHashMap<String, User>>,
     jobs: Arc<CHashMap<String, WJob>>,
     jobs_queue: Arc<MsQueue<String>>,
+    tasks: Arc<CHashMap<String, WTask>>,
     data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
+    watchers: Arc<JobWatchers>,
+    session_names: Arc<SessionNames>,
 ) -> std::io::Result<JoinHandle<()>> {
     info!(
         "Starting user server, listening on port {}",
@@ -190,7 +224,10 @@ pub fn start(
                     users,
                     jobs,
                     jobs_queue,
+                    tasks,
                     data,
+                    watchers,
+                    session_names,
                 )
             }))
         }
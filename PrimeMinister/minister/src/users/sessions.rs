@@ -0,0 +1,233 @@
+/*
+    Parliament - A distributed general-purpose cluster-computing framework for OCaml
+    Copyright (c) 2018-2019 [Name REDACTED] [Email REDACTED]
+
+    users/sessions.rs - Saves named sessions to disk, so they can be resumed after the master restarts.
+                        Only the sessions are saved, their jobs and data are lost on a restart
+*/
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chashmap::CHashMap;
+use log::{error, info, warn};
+use protobuf::{parse_from_bytes, Message, RepeatedField};
+
+use model::Executable;
+use shared::protos::sessions::*;
+use users::{Session, User};
+use util;
+
+const SESSION_EXTENSION: &str = "session";
+
+fn session_path(session_dir: &String, name: &String) -> PathBuf {
+    return Path::new(session_dir).join(name).with_extension(SESSION_EXTENSION);
+}
+
+fn to_saved(user: &User, session: &Session) -> SavedSession {
+    let mut saved = SavedSession::new();
+    saved.set_name(session.name.clone());
+    saved.set_user_id(user.id.clone());
+    saved.set_secret_digest(session.secret_digest.clone());
+    saved.set_docker_name(user.docker_name.clone());
+    saved.set_executable_name(user.executable.name.clone());
+    saved.set_executable_args(RepeatedField::from_vec(user.executable.args.clone()));
+    saved.set_executable_env(RepeatedField::from_vec(
+        user.executable
+            .env
+            .iter()
+            .map(|(key, value)| {
+                let mut variable = SavedVariable::new();
+                variable.set_key(key.clone());
+                variable.set_value(value.clone());
+                variable
+            })
+            .collect(),
+    ));
+    saved.set_artifact_digest(user.executable.artifact_digest.clone());
+    return saved;
+}
+
+// Jobs and their data are only kept in memory, so a restored session starts without jobs and without the
+// submissions that created them. Resubmitting with an old idempotency key runs the workload again
+fn from_saved(mut saved: SavedSession) -> User {
+    return User {
+        id: saved.take_user_id(),
+        // Restarting the master gives every session a full TTL to be resumed in
+        last_request: util::current_secs(),
+        jobs: HashSet::new(),
+        to_be_deleted: false,
+        docker_name: saved.take_docker_name(),
+        executable: Executable {
+            name: saved.take_executable_name(),
+            args: saved.take_executable_args().into_vec(),
+            env: saved
+                .take_executable_env()
+                .into_iter()
+                .map(|mut x| (x.take_key(), x.take_value()))
+                .collect(),
+            artifact_digest: saved.take_artifact_digest(),
+        },
+        submissions: HashMap::new(),
+        session: Some(Session {
+            name: saved.take_name(),
+            secret_digest: saved.take_secret_digest(),
+            unsaved: false,
+        }),
+    };
+}
+
+fn save(session_dir: &String, user: &User, session: &Session) -> Result<(), String> {
+    let bytes = to_saved(user, session)
+        .write_to_bytes()
+        .map_err(|e| e.to_string())?;
+
+    // Written under a temporary name first so a partially written session is never loaded
+    let path = session_path(session_dir, &session.name);
+    let partial_path = path.with_extension("partial");
+    fs::create_dir_all(session_dir).map_err(|e| e.to_string())?;
+    fs::write(&partial_path, bytes).map_err(|e| e.to_string())?;
+    return fs::rename(&partial_path, &path).map_err(|e| e.to_string());
+}
+
+/*
+    EXPORTED FUNCTIONS
+*/
+
+// Maps session names to the users holding them, so a name is checked and claimed under a single lock
+pub struct SessionNames {
+    names: Mutex<HashMap<String, String>>,
+}
+
+impl SessionNames {
+    pub fn new() -> SessionNames {
+        return SessionNames {
+            names: Mutex::new(HashMap::new()),
+        };
+    }
+
+    pub fn find(&self, name: &String) -> Option<String> {
+        return self.names.lock().unwrap().get(name).cloned();
+    }
+
+    // Returns false if another user already holds the name
+    pub fn claim(&self, name: &String, user_id: &String) -> bool {
+        let mut names = self.names.lock().unwrap();
+        if names.contains_key(name) {
+            return false;
+        }
+        names.insert(name.clone(), user_id.clone());
+        return true;
+    }
+
+    pub fn release(&self, name: &String) {
+        self.names.lock().unwrap().remove(name);
+    }
+}
+
+// Session names are used as file names, so they are kept to letters, digits, dashes and underscores
+pub fn valid_name(name: &String) -> bool {
+    return name.len() > 0
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || x == '-' || x == '_');
+}
+
+pub fn load(session_dir: &String, users: &Arc<CHashMap<String, User>>, session_names: &SessionNames) {
+    let entries = match fs::read_dir(session_dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!("Could not read sessions from {}! Error: {}", session_dir, e.to_string());
+            return;
+        }
+    };
+
+    for entry in entries.filter_map(|x| x.ok()) {
+        let path = entry.path();
+        if path.extension().and_then(|x| x.to_str()) != Some(SESSION_EXTENSION) {
+            continue;
+        }
+        let saved = fs::read(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| parse_from_bytes::<SavedSession>(&bytes).map_err(|e| e.to_string()));
+        match saved {
+            Ok(saved) => {
+                let user = from_saved(saved);
+                let claimed = match &user.session {
+                    Some(session) => session_names.claim(&session.name, &user.id),
+                    None => false,
+                };
+                if !claimed {
+                    error!("Could not restore session {}! Error: Its name is already taken", path.display());
+                    continue;
+                }
+                info!("Restored session {} for user {}", path.display(), &user.id);
+                users.insert(user.id.clone(), user);
+            }
+            Err(e) => error!("Could not restore session {}! Error: {}", path.display(), e),
+        }
+    }
+}
+
+// Writes every session that changed since it was last saved
+pub fn save_changed(session_dir: &String, users: &Arc<CHashMap<String, User>>) {
+    let changed = Mutex::new(Vec::new());
+    users.retain(|user_id, user| {
+        if user.session.as_ref().map(|x| x.unsaved) == Some(true) {
+            changed.lock().unwrap().push(user_id.clone());
+        }
+        return true;
+    });
+
+    for user_id in changed.into_inner().unwrap() {
+        if let Some(mut user) = users.get_mut(&user_id) {
+            let result = match &user.session {
+                Some(session) => save(session_dir, &user, session),
+                None => continue,
+            };
+            match result {
+                Ok(_) => {
+                    if let Some(session) = user.session.as_mut() {
+                        session.unsaved = false;
+                    }
+                }
+                Err(e) => error!("Could not save the session of user {}! Error: {}", &user_id, e),
+            }
+        }
+    }
+}
+
+pub fn remove(session_dir: &String, name: &String) {
+    if let Err(e) = fs::remove_file(session_path(session_dir, name)) {
+        warn!("Could not remove session {}! Error: {}", name, e.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanity_session_names() {
+        let names = SessionNames::new();
+        let name = "nightly".to_string();
+        assert!(names.claim(&name, &"abcde".to_string()));
+        assert!(!names.claim(&name, &"fghij".to_string()));
+        assert_eq!(names.find(&name), Some("abcde".to_string()));
+
+        names.release(&name);
+        assert_eq!(names.find(&name), None);
+        assert!(names.claim(&name, &"fghij".to_string()));
+    }
+
+    #[test]
+    fn test_sanity_valid_name() {
+        assert!(valid_name(&"nightly-run_2".to_string()));
+        assert!(!valid_name(&"".to_string()));
+        assert!(!valid_name(&"../etc".to_string()));
+        assert!(!valid_name(&"a".repeat(65)));
+    }
+}
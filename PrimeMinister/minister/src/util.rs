@@ -5,6 +5,8 @@
     util.rs - Helpful functions used everywhere
 */
 
+use std::collections::HashMap;
+use std::path::{Component, Path};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -12,11 +14,11 @@ use chashmap::CHashMap;
 use log::warn;
 
 use config::Server;
-use model::{JobStatus, JobType, WorkerStatus};
+use model::{JobStatus, JobType, TaskStatus, WJob, WTask, WorkerStatus};
 use shared::protos::intra_cluster::{
     WorkerHeartbeatResponse_HeartbeatStatus, WorkerTaskSubmissionRequest_MapType,
 };
-use shared::protos::user_cluster::{JobStatus_Status, MapAction_MapType};
+use shared::protos::user_cluster::{JobStatus_Status, MapAction_MapType, TaskDescription_Status};
 use shared::util::random_alphanum_string;
 
 pub fn convert_worker_status(status: &WorkerHeartbeatResponse_HeartbeatStatus) -> WorkerStatus {
@@ -69,12 +71,120 @@ pub fn convert_job_status(status: &JobStatus) -> JobStatus_Status {
     return match status {
         JobStatus::Blocked => JobStatus_Status::BLOCKED,
         JobStatus::Running => JobStatus_Status::RUNNING,
+        JobStatus::Committing => JobStatus_Status::RUNNING,
         JobStatus::Completed => JobStatus_Status::COMPLETED,
         JobStatus::Halted => JobStatus_Status::HALTED,
         JobStatus::Cancelled => JobStatus_Status::CANCELLED,
+        JobStatus::Unschedulable => JobStatus_Status::UNSCHEDULABLE,
+        JobStatus::TimedOut => JobStatus_Status::TIMED_OUT,
+        JobStatus::OutOfMemory => JobStatus_Status::OUT_OF_MEMORY,
     };
 }
 
+pub fn convert_task_status(status: &TaskStatus) -> TaskDescription_Status {
+    return match status {
+        TaskStatus::Awaiting => TaskDescription_Status::AWAITING,
+        TaskStatus::Running(_) => TaskDescription_Status::RUNNING,
+        TaskStatus::Completed => TaskDescription_Status::COMPLETED,
+        TaskStatus::Halted => TaskDescription_Status::HALTED,
+        TaskStatus::Cancelled => TaskDescription_Status::CANCELLED,
+        TaskStatus::TimedOut => TaskDescription_Status::TIMED_OUT,
+        TaskStatus::OutOfMemory => TaskDescription_Status::OUT_OF_MEMORY,
+    };
+}
+
+// The job status a failed task puts its job in, None if the task has not failed
+pub fn task_failure(status: &TaskStatus) -> Option<JobStatus> {
+    return match status {
+        TaskStatus::Halted => Some(JobStatus::Halted),
+        TaskStatus::TimedOut => Some(JobStatus::TimedOut),
+        TaskStatus::OutOfMemory => Some(JobStatus::OutOfMemory),
+        _ => None,
+    };
+}
+
+// Completed tasks count fully and running ones by the fraction they last reported.
+// The message is the one most recently reported by any of the job's running tasks
+pub fn job_progress(job: &WJob, tasks: &CHashMap<String, WTask>) -> (f32, String) {
+    let mut running_progress = 0.0;
+    let mut message = String::new();
+    let mut message_updated_at = 0;
+    for task_id in job.tasks.iter() {
+        if let Some(task) = tasks.get(task_id) {
+            if let TaskStatus::Running(_) = task.status {
+                running_progress += task.progress;
+                if task.progress_message.len() > 0 && task.progress_updated_at >= message_updated_at {
+                    message = task.progress_message.clone();
+                    message_updated_at = task.progress_updated_at;
+                }
+            }
+        }
+    }
+
+    if job.total_tasks == 0 {
+        let progress = if job.status == JobStatus::Completed { 1.0 } else { 0.0 };
+        return (progress, message);
+    }
+    let progress = (job.completed_tasks as f32 + running_progress) / job.total_tasks as f32;
+    return (progress.min(1.0), message);
+}
+
+// Accumulators are summed by name, each starting at zero
+pub fn add_accumulators(totals: &mut HashMap<String, f64>, values: &Vec<(String, f64)>) {
+    for (name, value) in values.iter() {
+        *totals.entry(name.clone()).or_insert(0.0) += value;
+    }
+}
+
+pub fn job_failed(status: &JobStatus) -> bool {
+    return match status {
+        JobStatus::Halted | JobStatus::TimedOut | JobStatus::OutOfMemory => true,
+        _ => false,
+    };
+}
+
+// Paths from users are resolved against a directory of the master, and may not leave it
+pub fn relative_path(path: &String) -> bool {
+    return Path::new(path).components().all(|x| match x {
+        Component::Normal(_) | Component::CurDir => true,
+        _ => false,
+    });
+}
+
+pub fn labels_match(required: &HashMap<String, String>, labels: &HashMap<String, String>) -> bool {
+    return required
+        .iter()
+        .all(|(key, value)| labels.get(key) == Some(value));
+}
+
+pub fn median(values: &Vec<u64>) -> u64 {
+    let mut sorted = values.clone();
+    sorted.sort();
+    return sorted.get(sorted.len() / 2).cloned().unwrap_or(0);
+}
+
+// Variables that would let users change how the executable is loaded or talks to its worker.
+// Workers drop the same keys again, keep in sync with reserved_env_key in MemberOfParliament/src/util.rs
+pub fn reserved_env_key(key: &str) -> bool {
+    return key.starts_with("LD_") || key.starts_with("PARLIAMENT_");
+}
+
+pub const ARTIFACT_PREFIX: &str = "artifact:";
+
+// Artifacts are kept alongside job data, keyed by their SHA-256
+pub fn artifact_key(digest: &String) -> String {
+    return format!("{}{}", ARTIFACT_PREFIX, digest);
+}
+
+pub fn broadcast_key(digest: &String) -> String {
+    return format!("broadcast:{}", digest);
+}
+
+// Outputs of each task of a flat map are kept apart until they can be concatenated in order
+pub fn partial_output_key(job_id: &String, data_out_loc: i32) -> String {
+    return format!("partial:{}:{}", job_id, data_out_loc);
+}
+
 pub fn current_secs() -> u64 {
     return SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -87,6 +197,7 @@ pub fn convert_map_type(map_type: &MapAction_MapType) -> JobType {
         MapAction_MapType::SINGLE_IN_SINGLE_OUT => JobType::SingleInSingleOut,
         MapAction_MapType::MULTI_IN_SINGLE_OUT => JobType::MultiInSingleOut,
         MapAction_MapType::SINGLE_IN_MULTI_OUT => JobType::SingleInMultiOut,
+        MapAction_MapType::FLAT_MAP => JobType::FlatMap,
     };
 }
 
@@ -95,5 +206,198 @@ pub fn convert_map_task_type(job_type: &JobType) -> WorkerTaskSubmissionRequest_
         JobType::SingleInSingleOut => WorkerTaskSubmissionRequest_MapType::SINGLE_IN_SINGLE_OUT,
         JobType::MultiInSingleOut => WorkerTaskSubmissionRequest_MapType::MULTI_IN_SINGLE_OUT,
         JobType::SingleInMultiOut => WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT,
+        // Each task of a flat map is a SingleInMultiOut task on one of the datapacks
+        JobType::FlatMap => WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT,
+        JobType::Repartition(_) | JobType::Coalesce(_) => {
+            unreachable!("{:?} jobs are run by the master", job_type)
+        }
     };
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use model::{Executable, ResourceLimits};
+
+    pub fn job(tasks: Vec<&WTask>, total_tasks: i32, completed_tasks: i32) -> WJob {
+        let mut job = WJob::new(
+            "user-1".to_string(),
+            "user".to_string(),
+            "".to_string(),
+            "".to_string(),
+            Executable::default(),
+            JobType::SingleInSingleOut,
+            Vec::new(),
+            HashMap::new(),
+            0,
+        );
+        job.tasks = tasks.iter().map(|x| x.id.clone()).collect();
+        job.total_tasks = total_tasks;
+        job.completed_tasks = completed_tasks;
+        return job;
+    }
+
+    // Shared with the tests of other modules that work on tasks
+    pub fn task(id: &str, status: TaskStatus, progress: f32, message: &str, updated_at: u64) -> WTask {
+        return WTask {
+            id: id.to_string(),
+            job_id: "user-1".to_string(),
+            user_id: "user".to_string(),
+            data_in_id: "".to_string(),
+            data_in_loc: 0,
+            data_out_id: "user-1".to_string(),
+            data_out_loc: 0,
+            docker_name: "".to_string(),
+            executable: Executable::default(),
+            status,
+            closure: Arc::new(Vec::new()),
+            closure_digest: "".to_string(),
+            job_type: JobType::SingleInSingleOut,
+            required_labels: HashMap::new(),
+            started_at: 0,
+            finished_at: 0,
+            backup_worker: None,
+            timeout: 0,
+            limits: ResourceLimits::default(),
+            progress,
+            progress_message: message.to_string(),
+            progress_updated_at: updated_at,
+            worker_id: "".to_string(),
+            attempts: 0,
+            input_size: 0,
+            output_size: 0,
+            failure_reason: "".to_string(),
+            broadcasts: Vec::new(),
+            accumulators: Vec::new(),
+        };
+    }
+
+    #[test]
+    fn test_sanity_job_progress() {
+        let tasks = CHashMap::new();
+        let done = task("a", TaskStatus::Completed, 1.0, "finished", 30);
+        let halfway = task("b", TaskStatus::Running("w1".to_string()), 0.5, "halfway", 10);
+        let quarter = task("c", TaskStatus::Running("w2".to_string()), 0.25, "", 20);
+        let waiting = task("d", TaskStatus::Awaiting, 0.0, "", 0);
+        let job = job(vec![&done, &halfway, &quarter, &waiting], 4, 1);
+        for x in vec![done, halfway, quarter, waiting] {
+            tasks.insert(x.id.clone(), x);
+        }
+
+        // Only running tasks report messages, and one without a message keeps the last one
+        assert_eq!(job_progress(&job, &tasks), ((1.0 + 0.5 + 0.25) / 4.0, "halfway".to_string()));
+    }
+
+    #[test]
+    fn test_sanity_job_progress_latest_message() {
+        let tasks = CHashMap::new();
+        let older = task("a", TaskStatus::Running("w1".to_string()), 0.5, "older", 10);
+        let newer = task("b", TaskStatus::Running("w2".to_string()), 0.5, "newer", 20);
+        let job = job(vec![&older, &newer], 2, 0);
+        tasks.insert(newer.id.clone(), newer);
+        tasks.insert(older.id.clone(), older);
+        assert_eq!(job_progress(&job, &tasks), (0.5, "newer".to_string()));
+    }
+
+    #[test]
+    fn test_sanity_job_progress_bounds() {
+        let tasks = CHashMap::new();
+        let mut finished = job(vec![], 0, 0);
+        assert_eq!(job_progress(&finished, &tasks).0, 0.0);
+        finished.status = JobStatus::Completed;
+        assert_eq!(job_progress(&finished, &tasks).0, 1.0);
+
+        // Progress never goes past the whole job
+        let retried = task("a", TaskStatus::Running("w1".to_string()), 0.9, "", 0);
+        let job = job(vec![&retried], 1, 1);
+        tasks.insert(retried.id.clone(), retried);
+        assert_eq!(job_progress(&job, &tasks).0, 1.0);
+    }
+
+    #[test]
+    fn test_sanity_median() {
+        assert_eq!(median(&vec![]), 0);
+        assert_eq!(median(&vec![7]), 7);
+        assert_eq!(median(&vec![9, 1, 5]), 5);
+        assert_eq!(median(&vec![4, 1, 3, 2]), 3);
+    }
+
+    #[test]
+    fn test_sanity_reserved_env_key() {
+        assert_eq!(reserved_env_key("LD_PRELOAD"), true);
+        assert_eq!(reserved_env_key("PARLIAMENT_TOKEN"), true);
+        assert_eq!(reserved_env_key("OCAMLRUNPARAM"), false);
+        assert_eq!(reserved_env_key("OLD_PATH"), false);
+    }
+
+    #[test]
+    fn test_sanity_convert_task_status() {
+        assert_eq!(convert_task_status(&TaskStatus::Awaiting), TaskDescription_Status::AWAITING);
+        assert_eq!(convert_task_status(&TaskStatus::Running("w1".to_string())), TaskDescription_Status::RUNNING);
+        assert_eq!(convert_task_status(&TaskStatus::Completed), TaskDescription_Status::COMPLETED);
+        assert_eq!(convert_task_status(&TaskStatus::Halted), TaskDescription_Status::HALTED);
+        assert_eq!(convert_task_status(&TaskStatus::Cancelled), TaskDescription_Status::CANCELLED);
+        assert_eq!(convert_task_status(&TaskStatus::TimedOut), TaskDescription_Status::TIMED_OUT);
+        assert_eq!(convert_task_status(&TaskStatus::OutOfMemory), TaskDescription_Status::OUT_OF_MEMORY);
+    }
+
+    #[test]
+    fn test_sanity_task_failure() {
+        assert_eq!(task_failure(&TaskStatus::Halted), Some(JobStatus::Halted));
+        assert_eq!(task_failure(&TaskStatus::TimedOut), Some(JobStatus::TimedOut));
+        assert_eq!(task_failure(&TaskStatus::OutOfMemory), Some(JobStatus::OutOfMemory));
+        assert_eq!(task_failure(&TaskStatus::Cancelled), None);
+        assert_eq!(task_failure(&TaskStatus::Completed), None);
+    }
+
+    #[test]
+    fn test_sanity_add_accumulators() {
+        let mut totals = HashMap::new();
+        add_accumulators(&mut totals, &vec![("lines".to_string(), 10.0), ("errors".to_string(), 1.0)]);
+        add_accumulators(&mut totals, &vec![]);
+        add_accumulators(&mut totals, &vec![("lines".to_string(), 2.5), ("words".to_string(), -3.0)]);
+        assert_eq!(totals.len(), 3);
+        assert_eq!(totals["lines"], 12.5);
+        assert_eq!(totals["errors"], 1.0);
+        assert_eq!(totals["words"], -3.0);
+
+        // A task may report the same name twice
+        let mut totals = HashMap::new();
+        add_accumulators(&mut totals, &vec![("lines".to_string(), 1.0), ("lines".to_string(), 1.0)]);
+        assert_eq!(totals["lines"], 2.0);
+    }
+
+    #[test]
+    fn test_sanity_partial_output_key() {
+        let key = partial_output_key(&"user-1".to_string(), 2);
+        assert_eq!(key, "partial:user-1:2");
+        assert_ne!(key, partial_output_key(&"user-1".to_string(), 12));
+        assert_ne!(key, partial_output_key(&"user-12".to_string(), 2));
+        assert_ne!(partial_output_key(&"user-1".to_string(), 12), partial_output_key(&"user-11".to_string(), 2));
+
+        // Never taken for the output of a job, an artifact or a broadcast
+        assert!(!key.starts_with("user-"));
+        assert!(!key.starts_with(ARTIFACT_PREFIX));
+        assert_ne!(key, broadcast_key(&"user-1:2".to_string()));
+    }
+
+    #[test]
+    fn test_sanity_convert_map_task_type() {
+        assert_eq!(
+            convert_map_task_type(&JobType::FlatMap),
+            WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT
+        );
+        assert_eq!(
+            convert_map_task_type(&JobType::SingleInSingleOut),
+            WorkerTaskSubmissionRequest_MapType::SINGLE_IN_SINGLE_OUT
+        );
+        assert_eq!(convert_map_type(&MapAction_MapType::FLAT_MAP), JobType::FlatMap);
+    }
+
+    #[test]
+    fn test_sanity_median_keeps_input_order() {
+        let runtimes = vec![30, 10, 20];
+        assert_eq!(median(&runtimes), 20);
+        assert_eq!(runtimes, vec![30, 10, 20]);
+    }
+}
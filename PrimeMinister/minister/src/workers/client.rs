@@ -59,7 +59,14 @@ fn create_server_message(
             );
             let mut task = tasks.get_mut(&task_id).unwrap();
 
-            task.status = TaskStatus::Running(update.worker_id.clone());
+            // A speculative copy leaves the task recorded against its original worker
+            if task.backup_worker.as_ref() != Some(&update.worker_id) {
+                task.status = TaskStatus::Running(update.worker_id.clone());
+                task.progress = 0.0;
+                task.progress_message = "".to_string();
+                task.worker_id = update.worker_id.clone();
+                task.attempts += 1;
+            }
 
             let data_bank = data.get(&task.data_in_id).unwrap();
             let data = {
@@ -76,8 +83,7 @@ fn create_server_message(
                     RepeatedField::from_vec(data_bank.clone())
                 }
             };
-
-            let closure = &task.closure;
+            task.input_size = data.iter().map(|x| x.len() as u64).sum();
 
             let mut submission_request = WorkerTaskSubmissionRequest::new();
             submission_request.set_worker_id(update.worker_id.clone());
@@ -85,7 +91,44 @@ fn create_server_message(
             submission_request.set_data_in(data);
             submission_request.set_docker_name(task.docker_name.clone());
             submission_request.set_map_type(util::convert_map_task_type(&task.job_type));
-            submission_request.set_closure(Vec::from(closure.as_slice()));
+            // Workers cache closures, so only the digest is sent with each task
+            submission_request.set_closure_digest(task.closure_digest.clone());
+            submission_request.set_task_timeout(task.timeout as i32);
+
+            let mut limits = WorkerResourceLimits::new();
+            limits.set_memory_mb(task.limits.memory_mb as i32);
+            limits.set_cpu_percent(task.limits.cpu_percent as i32);
+            limits.set_open_files(task.limits.open_files as i32);
+            submission_request.set_limits(limits);
+
+            let mut executable = WorkerExecutable::new();
+            executable.set_name(task.executable.name.clone());
+            executable.set_artifact_digest(task.executable.artifact_digest.clone());
+            executable.set_args(RepeatedField::from_vec(task.executable.args.clone()));
+            executable.set_env(RepeatedField::from_vec(
+                task.executable
+                    .env
+                    .iter()
+                    .map(|(key, value)| {
+                        let mut variable = WorkerEnvironmentVariable::new();
+                        variable.set_key(key.clone());
+                        variable.set_value(value.clone());
+                        variable
+                    })
+                    .collect(),
+            ));
+            submission_request.set_executable(executable);
+            submission_request.set_broadcasts(RepeatedField::from_vec(
+                task.broadcasts
+                    .iter()
+                    .map(|(name, digest)| {
+                        let mut broadcast = WorkerBroadcast::new();
+                        broadcast.set_name(name.clone());
+                        broadcast.set_digest(digest.clone());
+                        broadcast
+                    })
+                    .collect(),
+            ));
 
             single_server_message.set_submission_request(submission_request);
         }
@@ -132,6 +175,20 @@ pub fn handle_heartbeat_response(
             &message_id, worker.status, worker.id
         );
 
+        // Backup copies report too, only the worker the task is recorded against updates its progress
+        if worker.status == WorkerStatus::Processing {
+            if let Some(mut task) = tasks.get_mut(&response.task_id) {
+                if task.status == TaskStatus::Running(worker.id.clone())
+                    && (task.progress != response.progress
+                        || task.progress_message != response.progress_message)
+                {
+                    task.progress = response.progress;
+                    task.progress_message = response.take_progress_message();
+                    task.progress_updated_at = util::current_secs();
+                }
+            }
+        }
+
         // Consensus passive mode
         if *consensus_mode && !consensus_state.active.read().unwrap().get_value() {
             info!("Passive mode!");
@@ -264,7 +321,20 @@ fn process_message(
                     }
                     WorkerUpdateType::Submission(task_id) => {
                         error!("{} || Task could not be assigned, unassigning task and removing worker from pool...", &message_id);
-                        tasks_queue.push(task_id.clone());
+                        let is_backup = match tasks.get_mut(task_id) {
+                            Some(mut task) => {
+                                if task.backup_worker.as_ref() == Some(&update.worker_id) {
+                                    task.backup_worker = None;
+                                    true
+                                } else {
+                                    false
+                                }
+                            }
+                            None => false,
+                        };
+                        if !is_backup {
+                            tasks_queue.push(task_id.clone());
+                        }
 
                         let names_option = worker_names.write();
                         if names_option.is_err() {
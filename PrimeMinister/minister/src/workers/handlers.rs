@@ -5,8 +5,7 @@
 
 use std::sync::{Arc, RwLock};
 use tokio::net::TcpStream;
-use std::mem;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use log::{info, warn, error, trace};
 use chashmap::CHashMap;
@@ -15,13 +14,18 @@ use byteorder::{WriteBytesExt, BigEndian};
 
 use shared::protos::intra_cluster::{WorkerFinishedRequest, WorkerConnectionRequest, WorkerFinishedRequest_WorkerTaskStatus, ConsensusRequest};
 use shared::protos::intra_cluster::{WorkerConnectionResponse, SingleServerMessage, WorkerFinishedResponse, ConsensusResponse, ConsensusRequest_Action};
-use model::{Worker, WTask, TaskStatus};
+use shared::protos::intra_cluster::{WorkerArtifactRequest, WorkerArtifactResponse, WorkerBroadcastRequest, WorkerBroadcastResponse, WorkerClosureRequest, WorkerClosureResponse};
+use model::{JobType, Worker, WTask, TaskStatus};
 use util;
 use config::State;
 use model::WorkerStatus;
 use model::WorkerUpdate;
+use crossbeam::queue::MsQueue;
 use crossbeam_channel::Sender;
 
+// Tasks whose inputs cannot be fetched are rescheduled this many times before they fail
+const MAX_FETCH_ATTEMPTS: u32 = 3;
+
 pub trait RequestHandler {
     fn handle_message(&mut self,
                       message_id: &String,
@@ -33,6 +37,7 @@ pub trait RequestHandler {
                       consensus_mode: bool,
                       consensus_state: Arc<State>,
                       running_tasks: &Arc<RwLock<HashSet<String>>>,
+                      tasks_queue: &Arc<MsQueue<String>>,
                       update_sender: &Sender<WorkerUpdate>);
 }
 
@@ -73,6 +78,7 @@ impl RequestHandler for WorkerConnectionRequest {
                       _consensus_mode: bool,
                       _consensus_state: Arc<State>,
                       _running_tasks: &Arc<RwLock<HashSet<String>>>,
+                      _tasks_queue: &Arc<MsQueue<String>>,
                       _update_sender: &Sender<WorkerUpdate>) {
         info!("{} || Processing message as a WorkerConnectionRequest", &message_id);
 
@@ -95,8 +101,14 @@ impl RequestHandler for WorkerConnectionRequest {
                 id = util::unique_id(&workers);
             }
 
+            let labels: HashMap<String, String> = self.take_labels()
+                .into_iter()
+                .map(|x| (x.key, x.value))
+                .collect();
+            info!("{} || Worker {} advertised labels {:?}", &message_id, &id, &labels);
+
             worker_names.write().unwrap().push(id.clone());
-            workers.insert(id.clone(), Worker::new(id.clone(), ip_addr, self.port.clone()));
+            workers.insert(id.clone(), Worker::new(id.clone(), ip_addr, self.port.clone(), labels));
 
             connection_response.set_worker_id(id);
             connection_response.set_connection_accepted(true);
@@ -114,21 +126,78 @@ impl RequestHandler for WorkerConnectionRequest {
 
 fn transfer_bytes(request: &mut WorkerFinishedRequest,
                   data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
-                  task: &WTask) {
-    let mut output = data.get_mut(&task.data_out_id).unwrap();
-    let mut data_out = request.take_data_out().to_vec();
-    if task.data_out_loc == -1 {
-        let mut i = 0;
-        let len = data_out.len();
+                  task: &mut WTask) -> bool {
+    // Both copies of a speculated task can finish, only the first result is kept
+    if task.status == TaskStatus::Completed {
+        return false;
+    }
+    task.status = TaskStatus::Completed;
+    task.finished_at = util::current_secs();
+
+    let data_out = request.take_data_out().into_vec();
+    task.output_size = data_out.iter().map(|x| x.len() as u64).sum();
+    task.accumulators = request
+        .take_accumulators()
+        .into_iter()
+        .map(|x| (x.name, x.value))
+        .collect();
+    if task.job_type == JobType::FlatMap {
+        // Concatenated with the outputs of the job's other tasks once they have all completed
+        data.insert(util::partial_output_key(&task.data_out_id, task.data_out_loc), data_out);
+        return true;
+    }
 
-        while i < len {
-            output.insert(i, mem::replace(&mut data_out[i], Vec::new()));
-            i = i + 1;
+    let mut output = match data.get_mut(&task.data_out_id) {
+        Some(output) => output,
+        None => {
+            warn!("Output of job {} has already been removed, discarding result of task {}", &task.job_id, &task.id);
+            return true;
         }
+    };
+    if task.data_out_loc == -1 {
+        *output = data_out;
     } else {
-        // Arguably we do not care what location it is in.
-        output.push(mem::replace(&mut data_out[0], Vec::new()));
+        // The output is allocated when the job's tasks are created, so it keeps the order of the input
+        match output.get_mut(task.data_out_loc as usize) {
+            Some(slot) => *slot = data_out.into_iter().next().unwrap_or_default(),
+            None => error!("Task {} has no place in the output of job {}!", &task.id, &task.job_id),
+        }
     }
+    return true;
+}
+
+fn take_losing_worker(task: &mut WTask, worker_id: &String) -> Option<String> {
+    return match (&task.status, task.backup_worker.take()) {
+        (TaskStatus::Running(primary_id), Some(backup_id)) => {
+            if backup_id == *worker_id {
+                Some(primary_id.clone())
+            } else {
+                Some(backup_id)
+            }
+        }
+        _ => None,
+    };
+}
+
+fn drop_speculative_copy(task: &mut WTask, worker_id: &String) -> bool {
+    if let Some(backup_id) = task.backup_worker.take() {
+        if backup_id != *worker_id {
+            task.status = TaskStatus::Running(backup_id);
+        }
+        return true;
+    }
+    return false;
+}
+
+fn complete_task(message_id: &String,
+                 request: &mut WorkerFinishedRequest,
+                 data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+                 task: &mut WTask) -> Option<String> {
+    let loser = take_losing_worker(task, &request.worker_id);
+    if !transfer_bytes(request, &data, task) {
+        info!("{} || Task {} has already been completed by another worker, discarding result from {}", &message_id, &task.id, &request.worker_id);
+    }
+    return loser;
 }
 
 impl RequestHandler for WorkerFinishedRequest {
@@ -142,17 +211,37 @@ impl RequestHandler for WorkerFinishedRequest {
                       consensus_mode: bool,
                       _consensus_state: Arc<State>,
                       running_tasks: &Arc<RwLock<HashSet<String>>>,
-                      _update_sender: &Sender<WorkerUpdate>) {
+                      tasks_queue: &Arc<MsQueue<String>>,
+                      update_sender: &Sender<WorkerUpdate>) {
         info!("{} || Processing message as a WorkerFinishedRequest", &message_id);
 
         let mut successful = false;
+        let mut losing_worker = None;
 
         match (workers.get(&self.worker_id), self.status) {
-            (Some(worker), WorkerFinishedRequest_WorkerTaskStatus::TASK_ERRORED) => {
-                info!("{} || WorkerFinishedRequest.status = TASK_ERRORED from {}", &message_id, &self.worker_id);
+            (Some(worker), WorkerFinishedRequest_WorkerTaskStatus::TASK_ERRORED) |
+            (Some(worker), WorkerFinishedRequest_WorkerTaskStatus::TASK_TIMED_OUT) |
+            (Some(worker), WorkerFinishedRequest_WorkerTaskStatus::TASK_OUT_OF_MEMORY) => {
+                info!("{} || WorkerFinishedRequest.status = {:?} from {}", &message_id, &self.status, &self.worker_id);
+                let failed_status = match self.status {
+                    WorkerFinishedRequest_WorkerTaskStatus::TASK_TIMED_OUT => TaskStatus::TimedOut,
+                    WorkerFinishedRequest_WorkerTaskStatus::TASK_OUT_OF_MEMORY => TaskStatus::OutOfMemory,
+                    _ => TaskStatus::Halted,
+                };
+                let failure_reason = match self.status {
+                    WorkerFinishedRequest_WorkerTaskStatus::TASK_TIMED_OUT => format!("Timed out on worker {}", &self.worker_id),
+                    WorkerFinishedRequest_WorkerTaskStatus::TASK_OUT_OF_MEMORY => format!("Ran out of memory on worker {}", &self.worker_id),
+                    _ => format!("Errored on worker {}", &self.worker_id),
+                };
                 if let Some(task_id) = worker.running_task.clone() {
                     if let Some(mut task) = tasks.get_mut(&task_id) {
-                        task.status = TaskStatus::Halted;
+                        if drop_speculative_copy(&mut task, &self.worker_id) {
+                            info!("{} || Copy of task {} on worker {} failed, the other copy carries on", &message_id, &task_id, &self.worker_id);
+                        } else {
+                            task.status = failed_status.clone();
+                            task.finished_at = util::current_secs();
+                            task.failure_reason = failure_reason;
+                        }
                         successful = true;
 
                         info!("{} || Successfully processed WorkerFinishedRequest from worker {}", &message_id, &self.worker_id);
@@ -163,7 +252,9 @@ impl RequestHandler for WorkerFinishedRequest {
                     // We'll have to allow data to come in
                     let task_id = self.take_task_id();
                     if let Some(mut task) = tasks.get_mut(&task_id) {
-                        task.status = TaskStatus::Halted;
+                        task.status = failed_status.clone();
+                        task.finished_at = util::current_secs();
+                        task.failure_reason = failure_reason;
                         successful = true;
 
                         info!("{} || Consensus allow, Successfully processed WorkerFinishedRequest from worker {}", &message_id, &self.worker_id);
@@ -174,12 +265,35 @@ impl RequestHandler for WorkerFinishedRequest {
                     warn!("{} || Worker {} has sent WorkerFinishedRequest when no record of job starting!", &message_id, &self.worker_id);
                 }
             }
+            (Some(worker), WorkerFinishedRequest_WorkerTaskStatus::TASK_UNAVAILABLE) => {
+                info!("{} || WorkerFinishedRequest.status = TASK_UNAVAILABLE from {}", &message_id, &self.worker_id);
+                if let Some(task_id) = worker.running_task.clone() {
+                    if let Some(mut task) = tasks.get_mut(&task_id) {
+                        if drop_speculative_copy(&mut task, &self.worker_id) {
+                            info!("{} || Copy of task {} on worker {} could not start, the other copy carries on", &message_id, &task_id, &self.worker_id);
+                        } else if task.attempts >= MAX_FETCH_ATTEMPTS {
+                            task.status = TaskStatus::Halted;
+                            task.finished_at = util::current_secs();
+                            task.failure_reason = format!("Could not be fetched after {} attempts", task.attempts);
+                        } else {
+                            info!("{} || Worker {} could not fetch task {}, rescheduling it", &message_id, &self.worker_id, &task_id);
+                            task.status = TaskStatus::Awaiting;
+                            task.failure_reason = format!("Could not be fetched by worker {}", &self.worker_id);
+                            tasks_queue.push(task_id.clone());
+                        }
+                    } else {
+                        warn!("{} || Worker {} has given updates on task that does not exist anymore!", &message_id, &self.worker_id);
+                    }
+                    successful = true;
+                } else {
+                    warn!("{} || Worker {} has sent WorkerFinishedRequest when no record of job starting!", &message_id, &self.worker_id);
+                }
+            }
             (Some(worker), WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED) => {
                 info!("{} || WorkerFinishedRequest.status = TASK_FINISHED from {} ", &message_id, &self.worker_id);
                 if let Some(task_id) = worker.running_task.clone() {
                     if let Some(mut task) = tasks.get_mut(&task_id) {
-                        task.status = TaskStatus::Completed;
-                        transfer_bytes(self, &data, &task);
+                        losing_worker = complete_task(&message_id, self, &data, &mut task);
                         successful = true;
                     } else {
                         warn!("{} || Worker {} has given updates on task that does not exist anymore: {}!", &message_id, &self.worker_id, &task_id);
@@ -192,8 +306,7 @@ impl RequestHandler for WorkerFinishedRequest {
                     if let Some(mut task) = tasks.get_mut(&task_id) {
                         info!("{} || Consensus allow, Successfully processed WorkerFinishedRequest from worker {}", &message_id, &self.worker_id);
                         running_tasks.write().unwrap().insert(task_id.clone());
-                        task.status = TaskStatus::Completed;
-                        transfer_bytes(self, &data, &task);
+                        losing_worker = complete_task(&message_id, self, &data, &mut task);
                         successful = true;
                     } else {
                         warn!("{} || Consensus allow, Worker {} has given updates on task that does not exist anymore!", &message_id, &self.worker_id);
@@ -219,6 +332,16 @@ impl RequestHandler for WorkerFinishedRequest {
             }
             workers.remove(&self.worker_id);
         }
+
+        // Cancel the slower copy of a speculated task
+        if let Some(loser_id) = losing_worker {
+            info!("{} || Cancelling losing copy of task on worker {}", &message_id, &loser_id);
+            // The loser restarts once cancelled and rejoins the pool under a fresh id
+            if let Some(loser) = workers.remove(&loser_id) {
+                update_sender.send(WorkerUpdate::cancellation(&loser));
+            }
+            util::vec_remove(&mut worker_names.write().unwrap(), loser_id);
+        }
     }
 }
 
@@ -253,6 +376,7 @@ impl RequestHandler for ConsensusRequest {
                       _consensus_mode: bool,
                       consensus_state: Arc<State>,
                       _running_tasks: &Arc<RwLock<HashSet<String>>>,
+                      _tasks_queue: &Arc<MsQueue<String>>,
                       update_sender: &Sender<WorkerUpdate>) {
         info!("{} || Processing message as a ConsensusRequest", &message_id);
 
@@ -276,4 +400,182 @@ impl RequestHandler for ConsensusRequest {
         single_response.set_consensus_response(ConsensusResponse::new());
         write_single_response(&message_id, single_response, stream);
     }
-}
\ No newline at end of file
+}
+
+impl RequestHandler for WorkerArtifactRequest {
+    fn handle_message(&mut self,
+                      message_id: &String,
+                      stream: &mut TcpStream,
+                      _workers: &Arc<CHashMap<String, Worker>>,
+                      _tasks: &Arc<CHashMap<String, WTask>>,
+                      _worker_names: &Arc<RwLock<Vec<String>>>,
+                      data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+                      _consensus_mode: bool,
+                      _consensus_state: Arc<State>,
+                      _running_tasks: &Arc<RwLock<HashSet<String>>>,
+                      _tasks_queue: &Arc<MsQueue<String>>,
+                      _update_sender: &Sender<WorkerUpdate>) {
+        info!("{} || Processing message as a WorkerArtifactRequest", &message_id);
+
+        let mut artifact_response = WorkerArtifactResponse::new();
+        artifact_response.set_digest(self.digest.clone());
+
+        match data.get(&util::artifact_key(&self.digest)) {
+            Some(artifact) => {
+                info!("{} || Sending artifact {} to worker {}", &message_id, &self.digest, &self.worker_id);
+                artifact_response.set_found(true);
+                artifact_response.set_artifact(artifact[0].clone());
+            }
+            None => {
+                warn!("{} || Worker {} requested unknown artifact {}", &message_id, &self.worker_id, &self.digest);
+                artifact_response.set_found(false);
+            }
+        }
+
+        let mut single_response = SingleServerMessage::new();
+        single_response.set_artifact_response(artifact_response);
+        write_single_response(&message_id, single_response, stream);
+    }
+}
+
+impl RequestHandler for WorkerClosureRequest {
+    fn handle_message(&mut self,
+                      message_id: &String,
+                      stream: &mut TcpStream,
+                      _workers: &Arc<CHashMap<String, Worker>>,
+                      tasks: &Arc<CHashMap<String, WTask>>,
+                      _worker_names: &Arc<RwLock<Vec<String>>>,
+                      _data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+                      _consensus_mode: bool,
+                      _consensus_state: Arc<State>,
+                      _running_tasks: &Arc<RwLock<HashSet<String>>>,
+                      _tasks_queue: &Arc<MsQueue<String>>,
+                      _update_sender: &Sender<WorkerUpdate>) {
+        info!("{} || Processing message as a WorkerClosureRequest", &message_id);
+
+        let mut closure_response = WorkerClosureResponse::new();
+        closure_response.set_digest(self.digest.clone());
+
+        match tasks.get(&self.task_id) {
+            Some(ref task) if task.closure_digest == self.digest => {
+                info!("{} || Sending closure {} to worker {}", &message_id, &self.digest, &self.worker_id);
+                closure_response.set_found(true);
+                closure_response.set_closure(Vec::from(task.closure.as_slice()));
+            }
+            _ => {
+                warn!("{} || Worker {} requested unknown closure {} for task {}", &message_id, &self.worker_id, &self.digest, &self.task_id);
+                closure_response.set_found(false);
+            }
+        }
+
+        let mut single_response = SingleServerMessage::new();
+        single_response.set_closure_response(closure_response);
+        write_single_response(&message_id, single_response, stream);
+    }
+}
+
+impl RequestHandler for WorkerBroadcastRequest {
+    fn handle_message(&mut self,
+                      message_id: &String,
+                      stream: &mut TcpStream,
+                      _workers: &Arc<CHashMap<String, Worker>>,
+                      _tasks: &Arc<CHashMap<String, WTask>>,
+                      _worker_names: &Arc<RwLock<Vec<String>>>,
+                      data: &Arc<CHashMap<String, Vec<Vec<u8>>>>,
+                      _consensus_mode: bool,
+                      _consensus_state: Arc<State>,
+                      _running_tasks: &Arc<RwLock<HashSet<String>>>,
+                      _tasks_queue: &Arc<MsQueue<String>>,
+                      _update_sender: &Sender<WorkerUpdate>) {
+        info!("{} || Processing message as a WorkerBroadcastRequest", &message_id);
+
+        let mut broadcast_response = WorkerBroadcastResponse::new();
+        broadcast_response.set_digest(self.digest.clone());
+
+        match data.get(&util::broadcast_key(&self.digest)) {
+            Some(value) => {
+                info!("{} || Sending broadcast {} to worker {}", &message_id, &self.digest, &self.worker_id);
+                broadcast_response.set_found(true);
+                broadcast_response.set_value(value[0].clone());
+            }
+            None => {
+                warn!("{} || Worker {} requested unknown broadcast {}", &message_id, &self.worker_id, &self.digest);
+                broadcast_response.set_found(false);
+            }
+        }
+
+        let mut single_response = SingleServerMessage::new();
+        single_response.set_broadcast_response(broadcast_response);
+        write_single_response(&message_id, single_response, stream);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::RepeatedField;
+    use util::tests::task;
+
+    fn finished(data_out: Vec<&[u8]>) -> WorkerFinishedRequest {
+        let mut request = WorkerFinishedRequest::new();
+        request.set_data_out(RepeatedField::from_vec(data_out.into_iter().map(|x| x.to_vec()).collect()));
+        return request;
+    }
+
+    fn running(data_out_loc: i32) -> WTask {
+        let mut task = task(&format!("user-1-{}", data_out_loc), TaskStatus::Running("w1".to_string()), 0.0, "", 0);
+        task.data_out_loc = data_out_loc;
+        return task;
+    }
+
+    #[test]
+    fn test_sanity_transfer_bytes_in_place() {
+        let data = Arc::new(CHashMap::new());
+        data.insert("user-1".to_string(), vec![Vec::new(); 3]);
+
+        // Tasks complete in any order, but their outputs keep the order of the input
+        let mut last = running(2);
+        let mut first = running(0);
+        assert_eq!(transfer_bytes(&mut finished(vec![b"c"]), &data, &mut last), true);
+        assert_eq!(transfer_bytes(&mut finished(vec![b"a"]), &data, &mut first), true);
+        assert_eq!(*data.get(&"user-1".to_string()).unwrap(), vec![b"a".to_vec(), Vec::new(), b"c".to_vec()]);
+        assert_eq!(first.status, TaskStatus::Completed);
+        assert_eq!(first.output_size, 1);
+    }
+
+    #[test]
+    fn test_sanity_transfer_bytes_once() {
+        let data = Arc::new(CHashMap::new());
+        data.insert("user-1".to_string(), vec![Vec::new(); 1]);
+
+        // The slower copy of a speculated task does not overwrite the result
+        let mut task = running(0);
+        assert_eq!(transfer_bytes(&mut finished(vec![b"first"]), &data, &mut task), true);
+        assert_eq!(transfer_bytes(&mut finished(vec![b"second"]), &data, &mut task), false);
+        assert_eq!(*data.get(&"user-1".to_string()).unwrap(), vec![b"first".to_vec()]);
+    }
+
+    #[test]
+    fn test_sanity_transfer_bytes_whole_output() {
+        let data = Arc::new(CHashMap::new());
+        data.insert("user-1".to_string(), vec![Vec::new()]);
+        let mut task = running(-1);
+        assert_eq!(transfer_bytes(&mut finished(vec![b"a", b"b"]), &data, &mut task), true);
+        assert_eq!(*data.get(&"user-1".to_string()).unwrap(), vec![b"a".to_vec(), b"b".to_vec()]);
+    }
+
+    #[test]
+    fn test_sanity_transfer_bytes_no_place() {
+        let data = Arc::new(CHashMap::new());
+        data.insert("user-1".to_string(), vec![Vec::new(); 2]);
+        let mut task = running(5);
+        assert_eq!(transfer_bytes(&mut finished(vec![b"a"]), &data, &mut task), true);
+        assert_eq!(*data.get(&"user-1".to_string()).unwrap(), vec![Vec::<u8>::new(); 2]);
+
+        // A job whose output was removed, such as a cancelled one, gets none back
+        let mut task = running(0);
+        data.remove(&"user-1".to_string());
+        assert_eq!(transfer_bytes(&mut finished(vec![b"a"]), &data, &mut task), true);
+        assert_eq!(data.contains_key(&"user-1".to_string()), false);
+    }
+}
@@ -17,6 +17,7 @@ use std::sync::atomic::Ordering::SeqCst;
 use std::thread::{Builder, JoinHandle};
 
 use chashmap::CHashMap;
+use crossbeam::queue::MsQueue;
 use crossbeam_channel::Sender;
 use log::{error, info, trace, warn};
 
@@ -35,6 +36,7 @@ fn server(
     tasks: Arc<CHashMap<String, WTask>>,
     data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
     running_tasks: Arc<RwLock<HashSet<String>>>,
+    tasks_queue: Arc<MsQueue<String>>,
     update_sender: Sender<WorkerUpdate>,
 ) {
     let server = listener
@@ -48,6 +50,7 @@ fn server(
             let consensus_mode = consensus_mode.clone();
             let consensus_state = consensus_state.clone();
             let running_tasks = running_tasks.clone();
+            let tasks_queue = tasks_queue.clone();
             let update_sender = update_sender.clone();
 
             tokio::spawn({
@@ -60,6 +63,7 @@ fn server(
                     consensus_mode,
                     consensus_state,
                     running_tasks,
+                    tasks_queue,
                     update_sender,
                 );
                 ok(())
@@ -79,6 +83,7 @@ fn process_message(
     consensus_mode: bool,
     consensus_state: Arc<State>,
     running_tasks: Arc<RwLock<HashSet<String>>>,
+    tasks_queue: Arc<MsQueue<String>>,
     update_sender: Sender<WorkerUpdate>,
 ) -> impl Future<Item = (), Error = ()> + Send {
     let ip_addr = stream.local_addr().unwrap().ip().to_string();
@@ -105,6 +110,7 @@ fn process_message(
                     consensus_mode,
                     consensus_state,
                     running_tasks,
+                    tasks_queue,
                     update_sender,
                 );
             } else {
@@ -122,6 +128,7 @@ fn process_message(
                         consensus_mode,
                         consensus_state,
                         running_tasks,
+                        tasks_queue,
                         update_sender,
                     );
                 } else {
@@ -152,6 +159,7 @@ fn handle_message(
     consensus_mode: bool,
     consensus_state: Arc<State>,
     running_tasks: Arc<RwLock<HashSet<String>>>,
+    tasks_queue: Arc<MsQueue<String>>,
     update_sender: Sender<WorkerUpdate>,
 ) {
     if let Some(message) = worker_message.message {
@@ -166,6 +174,7 @@ fn handle_message(
                 consensus_mode,
                 consensus_state,
                 &running_tasks,
+                &tasks_queue,
                 &update_sender,
             ),
             SingleWorkerMessage_oneof_message::finished_request(mut x) => x.handle_message(
@@ -178,6 +187,7 @@ fn handle_message(
                 consensus_mode,
                 consensus_state,
                 &running_tasks,
+                &tasks_queue,
                 &update_sender,
             ),
             SingleWorkerMessage_oneof_message::consensus_request(mut x) => x.handle_message(
@@ -190,6 +200,46 @@ fn handle_message(
                 consensus_mode,
                 consensus_state,
                 &running_tasks,
+                &tasks_queue,
+                &update_sender,
+            ),
+            SingleWorkerMessage_oneof_message::artifact_request(mut x) => x.handle_message(
+                &message_id,
+                stream,
+                &workers,
+                &tasks,
+                &worker_names,
+                &data,
+                consensus_mode,
+                consensus_state,
+                &running_tasks,
+                &tasks_queue,
+                &update_sender,
+            ),
+            SingleWorkerMessage_oneof_message::closure_request(mut x) => x.handle_message(
+                &message_id,
+                stream,
+                &workers,
+                &tasks,
+                &worker_names,
+                &data,
+                consensus_mode,
+                consensus_state,
+                &running_tasks,
+                &tasks_queue,
+                &update_sender,
+            ),
+            SingleWorkerMessage_oneof_message::broadcast_request(mut x) => x.handle_message(
+                &message_id,
+                stream,
+                &workers,
+                &tasks,
+                &worker_names,
+                &data,
+                consensus_mode,
+                consensus_state,
+                &running_tasks,
+                &tasks_queue,
                 &update_sender,
             ),
             _ => {
@@ -220,6 +270,7 @@ pub fn start(
     tasks: Arc<CHashMap<String, WTask>>,
     data: Arc<CHashMap<String, Vec<Vec<u8>>>>,
     running_tasks: Arc<RwLock<HashSet<String>>>,
+    tasks_queue: Arc<MsQueue<String>>,
     update_sender: Sender<WorkerUpdate>,
 ) -> std::io::Result<JoinHandle<()>> {
     info!(
@@ -240,6 +291,7 @@ pub fn start(
                 tasks,
                 data,
                 running_tasks,
+                tasks_queue,
                 update_sender,
             )
         }),
@@ -9,6 +9,7 @@ extern crate byteorder;
 extern crate log;
 extern crate tokio;
 extern crate futures;
+extern crate sha2;
 
 pub mod util;
 pub mod protos;
@@ -21,6 +21,211 @@
 use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerLabel {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerLabel {
+    pub fn new() -> WorkerLabel {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    // string value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl ::protobuf::Message for WorkerLabel {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerLabel {
+        WorkerLabel::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &WorkerLabel| { &m.key },
+                    |m: &mut WorkerLabel| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &WorkerLabel| { &m.value },
+                    |m: &mut WorkerLabel| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerLabel>(
+                    "WorkerLabel",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerLabel {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerLabel> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerLabel,
+        };
+        unsafe {
+            instance.get(WorkerLabel::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerLabel {
+    fn clear(&mut self) {
+        self.clear_key();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerLabel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerLabel {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct WorkerConnectionRequest {
     // message fields
@@ -28,6 +233,7 @@ pub struct WorkerConnectionRequest {
     pub no_of_cores: i32,
     pub port: i32,
     pub ip_override: ::std::string::String,
+    pub labels: ::protobuf::RepeatedField<WorkerLabel>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -119,10 +325,40 @@ impl WorkerConnectionRequest {
     pub fn get_ip_override(&self) -> &str {
         &self.ip_override
     }
+
+    // repeated .WorkerLabel labels = 5;
+
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::protobuf::RepeatedField<WorkerLabel>) {
+        self.labels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_labels(&mut self) -> &mut ::protobuf::RepeatedField<WorkerLabel> {
+        &mut self.labels
+    }
+
+    // Take field
+    pub fn take_labels(&mut self) -> ::protobuf::RepeatedField<WorkerLabel> {
+        ::std::mem::replace(&mut self.labels, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_labels(&self) -> &[WorkerLabel] {
+        &self.labels
+    }
 }
 
 impl ::protobuf::Message for WorkerConnectionRequest {
     fn is_initialized(&self) -> bool {
+        for v in &self.labels {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -150,6 +386,9 @@ impl ::protobuf::Message for WorkerConnectionRequest {
                 4 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.ip_override)?;
                 },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.labels)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -174,6 +413,10 @@ impl ::protobuf::Message for WorkerConnectionRequest {
         if !self.ip_override.is_empty() {
             my_size += ::protobuf::rt::string_size(4, &self.ip_override);
         }
+        for value in &self.labels {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -192,6 +435,11 @@ impl ::protobuf::Message for WorkerConnectionRequest {
         if !self.ip_override.is_empty() {
             os.write_string(4, &self.ip_override)?;
         }
+        for v in &self.labels {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -254,6 +502,11 @@ impl ::protobuf::Message for WorkerConnectionRequest {
                     |m: &WorkerConnectionRequest| { &m.ip_override },
                     |m: &mut WorkerConnectionRequest| { &mut m.ip_override },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkerLabel>>(
+                    "labels",
+                    |m: &WorkerConnectionRequest| { &m.labels },
+                    |m: &mut WorkerConnectionRequest| { &mut m.labels },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<WorkerConnectionRequest>(
                     "WorkerConnectionRequest",
                     fields,
@@ -280,6 +533,7 @@ impl ::protobuf::Clear for WorkerConnectionRequest {
         self.clear_no_of_cores();
         self.clear_port();
         self.clear_ip_override();
+        self.clear_labels();
         self.unknown_fields.clear();
     }
 }
@@ -662,6 +916,8 @@ pub struct WorkerHeartbeatResponse {
     // message fields
     pub status: WorkerHeartbeatResponse_HeartbeatStatus,
     pub task_id: ::std::string::String,
+    pub progress: f32,
+    pub progress_message: ::std::string::String,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -712,6 +968,47 @@ impl WorkerHeartbeatResponse {
     pub fn get_task_id(&self) -> &str {
         &self.task_id
     }
+
+    // float progress = 3;
+
+    pub fn clear_progress(&mut self) {
+        self.progress = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress(&mut self, v: f32) {
+        self.progress = v;
+    }
+
+    pub fn get_progress(&self) -> f32 {
+        self.progress
+    }
+
+    // string progress_message = 4;
+
+    pub fn clear_progress_message(&mut self) {
+        self.progress_message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_progress_message(&mut self, v: ::std::string::String) {
+        self.progress_message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_progress_message(&mut self) -> &mut ::std::string::String {
+        &mut self.progress_message
+    }
+
+    // Take field
+    pub fn take_progress_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.progress_message, ::std::string::String::new())
+    }
+
+    pub fn get_progress_message(&self) -> &str {
+        &self.progress_message
+    }
 }
 
 impl ::protobuf::Message for WorkerHeartbeatResponse {
@@ -729,6 +1026,16 @@ impl ::protobuf::Message for WorkerHeartbeatResponse {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.task_id)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_float()?;
+                    self.progress = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.progress_message)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -747,6 +1054,12 @@ impl ::protobuf::Message for WorkerHeartbeatResponse {
         if !self.task_id.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.task_id);
         }
+        if self.progress != 0. {
+            my_size += 5;
+        }
+        if !self.progress_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.progress_message);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -759,6 +1072,12 @@ impl ::protobuf::Message for WorkerHeartbeatResponse {
         if !self.task_id.is_empty() {
             os.write_string(2, &self.task_id)?;
         }
+        if self.progress != 0. {
+            os.write_float(3, self.progress)?;
+        }
+        if !self.progress_message.is_empty() {
+            os.write_string(4, &self.progress_message)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -811,6 +1130,16 @@ impl ::protobuf::Message for WorkerHeartbeatResponse {
                     |m: &WorkerHeartbeatResponse| { &m.task_id },
                     |m: &mut WorkerHeartbeatResponse| { &mut m.task_id },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeFloat>(
+                    "progress",
+                    |m: &WorkerHeartbeatResponse| { &m.progress },
+                    |m: &mut WorkerHeartbeatResponse| { &mut m.progress },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "progress_message",
+                    |m: &WorkerHeartbeatResponse| { &m.progress_message },
+                    |m: &mut WorkerHeartbeatResponse| { &mut m.progress_message },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<WorkerHeartbeatResponse>(
                     "WorkerHeartbeatResponse",
                     fields,
@@ -835,6 +1164,8 @@ impl ::protobuf::Clear for WorkerHeartbeatResponse {
     fn clear(&mut self) {
         self.clear_status();
         self.clear_task_id();
+        self.clear_progress();
+        self.clear_progress_message();
         self.unknown_fields.clear();
     }
 }
@@ -913,170 +1244,300 @@ impl ::protobuf::reflect::ProtobufValue for WorkerHeartbeatResponse_HeartbeatSta
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct WorkerTaskSubmissionRequest {
+pub struct WorkerResourceLimits {
     // message fields
-    pub worker_id: ::std::string::String,
-    pub task_id: ::std::string::String,
-    pub docker_name: ::std::string::String,
-    pub data_in: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
-    pub closure: ::std::vec::Vec<u8>,
-    pub map_type: WorkerTaskSubmissionRequest_MapType,
+    pub memory_mb: i32,
+    pub cpu_percent: i32,
+    pub open_files: i32,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl WorkerTaskSubmissionRequest {
-    pub fn new() -> WorkerTaskSubmissionRequest {
+impl WorkerResourceLimits {
+    pub fn new() -> WorkerResourceLimits {
         ::std::default::Default::default()
     }
 
-    // string worker_id = 1;
+    // int32 memory_mb = 1;
 
-    pub fn clear_worker_id(&mut self) {
-        self.worker_id.clear();
+    pub fn clear_memory_mb(&mut self) {
+        self.memory_mb = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_worker_id(&mut self, v: ::std::string::String) {
-        self.worker_id = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
-        &mut self.worker_id
-    }
-
-    // Take field
-    pub fn take_worker_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    pub fn set_memory_mb(&mut self, v: i32) {
+        self.memory_mb = v;
     }
 
-    pub fn get_worker_id(&self) -> &str {
-        &self.worker_id
+    pub fn get_memory_mb(&self) -> i32 {
+        self.memory_mb
     }
 
-    // string task_id = 2;
+    // int32 cpu_percent = 2;
 
-    pub fn clear_task_id(&mut self) {
-        self.task_id.clear();
+    pub fn clear_cpu_percent(&mut self) {
+        self.cpu_percent = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_task_id(&mut self, v: ::std::string::String) {
-        self.task_id = v;
+    pub fn set_cpu_percent(&mut self, v: i32) {
+        self.cpu_percent = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_task_id(&mut self) -> &mut ::std::string::String {
-        &mut self.task_id
+    pub fn get_cpu_percent(&self) -> i32 {
+        self.cpu_percent
     }
 
-    // Take field
-    pub fn take_task_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.task_id, ::std::string::String::new())
-    }
+    // int32 open_files = 3;
 
-    pub fn get_task_id(&self) -> &str {
-        &self.task_id
+    pub fn clear_open_files(&mut self) {
+        self.open_files = 0;
     }
 
-    // string docker_name = 3;
+    // Param is passed by value, moved
+    pub fn set_open_files(&mut self, v: i32) {
+        self.open_files = v;
+    }
 
-    pub fn clear_docker_name(&mut self) {
-        self.docker_name.clear();
+    pub fn get_open_files(&self) -> i32 {
+        self.open_files
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_docker_name(&mut self, v: ::std::string::String) {
-        self.docker_name = v;
+impl ::protobuf::Message for WorkerResourceLimits {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_docker_name(&mut self) -> &mut ::std::string::String {
-        &mut self.docker_name
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.memory_mb = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.cpu_percent = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.open_files = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_docker_name(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.docker_name, ::std::string::String::new())
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.memory_mb != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.memory_mb, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cpu_percent != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cpu_percent, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.open_files != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.open_files, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    pub fn get_docker_name(&self) -> &str {
-        &self.docker_name
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.memory_mb != 0 {
+            os.write_int32(1, self.memory_mb)?;
+        }
+        if self.cpu_percent != 0 {
+            os.write_int32(2, self.cpu_percent)?;
+        }
+        if self.open_files != 0 {
+            os.write_int32(3, self.open_files)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // repeated bytes data_in = 4;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
 
-    pub fn clear_data_in(&mut self) {
-        self.data_in.clear();
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    // Param is passed by value, moved
-    pub fn set_data_in(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
-        self.data_in = v;
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_data_in(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        &mut self.data_in
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // Take field
-    pub fn take_data_in(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        ::std::mem::replace(&mut self.data_in, ::protobuf::RepeatedField::new())
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    pub fn get_data_in(&self) -> &[::std::vec::Vec<u8>] {
-        &self.data_in
+    fn new() -> WorkerResourceLimits {
+        WorkerResourceLimits::new()
     }
 
-    // bytes closure = 5;
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "memory_mb",
+                    |m: &WorkerResourceLimits| { &m.memory_mb },
+                    |m: &mut WorkerResourceLimits| { &mut m.memory_mb },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "cpu_percent",
+                    |m: &WorkerResourceLimits| { &m.cpu_percent },
+                    |m: &mut WorkerResourceLimits| { &mut m.cpu_percent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "open_files",
+                    |m: &WorkerResourceLimits| { &m.open_files },
+                    |m: &mut WorkerResourceLimits| { &mut m.open_files },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerResourceLimits>(
+                    "WorkerResourceLimits",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
 
-    pub fn clear_closure(&mut self) {
-        self.closure.clear();
+    fn default_instance() -> &'static WorkerResourceLimits {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerResourceLimits> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerResourceLimits,
+        };
+        unsafe {
+            instance.get(WorkerResourceLimits::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerResourceLimits {
+    fn clear(&mut self) {
+        self.clear_memory_mb();
+        self.clear_cpu_percent();
+        self.clear_open_files();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerResourceLimits {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerResourceLimits {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerEnvironmentVariable {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerEnvironmentVariable {
+    pub fn new() -> WorkerEnvironmentVariable {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_closure(&mut self, v: ::std::vec::Vec<u8>) {
-        self.closure = v;
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_closure(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.closure
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
     }
 
     // Take field
-    pub fn take_closure(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.closure, ::std::vec::Vec::new())
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
     }
 
-    pub fn get_closure(&self) -> &[u8] {
-        &self.closure
+    pub fn get_key(&self) -> &str {
+        &self.key
     }
 
-    // .WorkerTaskSubmissionRequest.MapType map_type = 6;
+    // string value = 2;
 
-    pub fn clear_map_type(&mut self) {
-        self.map_type = WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT;
+    pub fn clear_value(&mut self) {
+        self.value.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_map_type(&mut self, v: WorkerTaskSubmissionRequest_MapType) {
-        self.map_type = v;
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
     }
 
-    pub fn get_map_type(&self) -> WorkerTaskSubmissionRequest_MapType {
-        self.map_type
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
     }
 }
 
-impl ::protobuf::Message for WorkerTaskSubmissionRequest {
+impl ::protobuf::Message for WorkerEnvironmentVariable {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1086,22 +1547,10 @@ impl ::protobuf::Message for WorkerTaskSubmissionRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.task_id)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.docker_name)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.data_in)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.closure)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.map_type, 6, &mut self.unknown_fields)?
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1115,23 +1564,11 @@ impl ::protobuf::Message for WorkerTaskSubmissionRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.worker_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
-        }
-        if !self.task_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.task_id);
-        }
-        if !self.docker_name.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.docker_name);
-        }
-        for value in &self.data_in {
-            my_size += ::protobuf::rt::bytes_size(4, &value);
-        };
-        if !self.closure.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(5, &self.closure);
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
         }
-        if self.map_type != WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT {
-            my_size += ::protobuf::rt::enum_size(6, self.map_type);
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1139,23 +1576,11 @@ impl ::protobuf::Message for WorkerTaskSubmissionRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.worker_id.is_empty() {
-            os.write_string(1, &self.worker_id)?;
-        }
-        if !self.task_id.is_empty() {
-            os.write_string(2, &self.task_id)?;
-        }
-        if !self.docker_name.is_empty() {
-            os.write_string(3, &self.docker_name)?;
-        }
-        for v in &self.data_in {
-            os.write_bytes(4, &v)?;
-        };
-        if !self.closure.is_empty() {
-            os.write_bytes(5, &self.closure)?;
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
         }
-        if self.map_type != WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT {
-            os.write_enum(6, self.map_type.value())?;
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1187,8 +1612,8 @@ impl ::protobuf::Message for WorkerTaskSubmissionRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerTaskSubmissionRequest {
-        WorkerTaskSubmissionRequest::new()
+    fn new() -> WorkerEnvironmentVariable {
+        WorkerEnvironmentVariable::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1200,37 +1625,17 @@ impl ::protobuf::Message for WorkerTaskSubmissionRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "worker_id",
-                    |m: &WorkerTaskSubmissionRequest| { &m.worker_id },
-                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.worker_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "task_id",
-                    |m: &WorkerTaskSubmissionRequest| { &m.task_id },
-                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.task_id },
+                    "key",
+                    |m: &WorkerEnvironmentVariable| { &m.key },
+                    |m: &mut WorkerEnvironmentVariable| { &mut m.key },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "docker_name",
-                    |m: &WorkerTaskSubmissionRequest| { &m.docker_name },
-                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.docker_name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data_in",
-                    |m: &WorkerTaskSubmissionRequest| { &m.data_in },
-                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.data_in },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "closure",
-                    |m: &WorkerTaskSubmissionRequest| { &m.closure },
-                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.closure },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerTaskSubmissionRequest_MapType>>(
-                    "map_type",
-                    |m: &WorkerTaskSubmissionRequest| { &m.map_type },
-                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.map_type },
+                    "value",
+                    |m: &WorkerEnvironmentVariable| { &m.value },
+                    |m: &mut WorkerEnvironmentVariable| { &mut m.value },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerTaskSubmissionRequest>(
-                    "WorkerTaskSubmissionRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerEnvironmentVariable>(
+                    "WorkerEnvironmentVariable",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1238,210 +1643,2880 @@ impl ::protobuf::Message for WorkerTaskSubmissionRequest {
         }
     }
 
-    fn default_instance() -> &'static WorkerTaskSubmissionRequest {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerTaskSubmissionRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerEnvironmentVariable {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerEnvironmentVariable> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerTaskSubmissionRequest,
+            ptr: 0 as *const WorkerEnvironmentVariable,
         };
         unsafe {
-            instance.get(WorkerTaskSubmissionRequest::new)
+            instance.get(WorkerEnvironmentVariable::new)
         }
     }
 }
 
-impl ::protobuf::Clear for WorkerTaskSubmissionRequest {
+impl ::protobuf::Clear for WorkerEnvironmentVariable {
     fn clear(&mut self) {
-        self.clear_worker_id();
-        self.clear_task_id();
-        self.clear_docker_name();
-        self.clear_data_in();
-        self.clear_closure();
-        self.clear_map_type();
+        self.clear_key();
+        self.clear_value();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorkerTaskSubmissionRequest {
+impl ::std::fmt::Debug for WorkerEnvironmentVariable {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerTaskSubmissionRequest {
+impl ::protobuf::reflect::ProtobufValue for WorkerEnvironmentVariable {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerBroadcast {
+    // message fields
+    pub name: ::std::string::String,
+    pub digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerBroadcast {
+    pub fn new() -> WorkerBroadcast {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // string digest = 2;
+
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
+    }
+
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
+    }
+
+    pub fn get_digest(&self) -> &str {
+        &self.digest
+    }
+}
+
+impl ::protobuf::Message for WorkerBroadcast {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.digest.is_empty() {
+            os.write_string(2, &self.digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerBroadcast {
+        WorkerBroadcast::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &WorkerBroadcast| { &m.name },
+                    |m: &mut WorkerBroadcast| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "digest",
+                    |m: &WorkerBroadcast| { &m.digest },
+                    |m: &mut WorkerBroadcast| { &mut m.digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerBroadcast>(
+                    "WorkerBroadcast",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerBroadcast {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerBroadcast> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerBroadcast,
+        };
+        unsafe {
+            instance.get(WorkerBroadcast::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerBroadcast {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_digest();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerBroadcast {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerBroadcast {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerExecutable {
+    // message fields
+    pub name: ::std::string::String,
+    pub args: ::protobuf::RepeatedField<::std::string::String>,
+    pub env: ::protobuf::RepeatedField<WorkerEnvironmentVariable>,
+    pub artifact_digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerExecutable {
+    pub fn new() -> WorkerExecutable {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // repeated string args = 2;
+
+    pub fn clear_args(&mut self) {
+        self.args.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_args(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.args = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_args(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.args
+    }
+
+    // Take field
+    pub fn take_args(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.args, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_args(&self) -> &[::std::string::String] {
+        &self.args
+    }
+
+    // repeated .WorkerEnvironmentVariable env = 3;
+
+    pub fn clear_env(&mut self) {
+        self.env.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_env(&mut self, v: ::protobuf::RepeatedField<WorkerEnvironmentVariable>) {
+        self.env = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_env(&mut self) -> &mut ::protobuf::RepeatedField<WorkerEnvironmentVariable> {
+        &mut self.env
+    }
+
+    // Take field
+    pub fn take_env(&mut self) -> ::protobuf::RepeatedField<WorkerEnvironmentVariable> {
+        ::std::mem::replace(&mut self.env, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_env(&self) -> &[WorkerEnvironmentVariable] {
+        &self.env
+    }
+
+    // string artifact_digest = 4;
+
+    pub fn clear_artifact_digest(&mut self) {
+        self.artifact_digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact_digest(&mut self, v: ::std::string::String) {
+        self.artifact_digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_artifact_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.artifact_digest
+    }
+
+    // Take field
+    pub fn take_artifact_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.artifact_digest, ::std::string::String::new())
+    }
+
+    pub fn get_artifact_digest(&self) -> &str {
+        &self.artifact_digest
+    }
+}
+
+impl ::protobuf::Message for WorkerExecutable {
+    fn is_initialized(&self) -> bool {
+        for v in &self.env {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.args)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.env)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.artifact_digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.args {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        for value in &self.env {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.artifact_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.artifact_digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.args {
+            os.write_string(2, &v)?;
+        };
+        for v in &self.env {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.artifact_digest.is_empty() {
+            os.write_string(4, &self.artifact_digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerExecutable {
+        WorkerExecutable::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &WorkerExecutable| { &m.name },
+                    |m: &mut WorkerExecutable| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "args",
+                    |m: &WorkerExecutable| { &m.args },
+                    |m: &mut WorkerExecutable| { &mut m.args },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkerEnvironmentVariable>>(
+                    "env",
+                    |m: &WorkerExecutable| { &m.env },
+                    |m: &mut WorkerExecutable| { &mut m.env },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "artifact_digest",
+                    |m: &WorkerExecutable| { &m.artifact_digest },
+                    |m: &mut WorkerExecutable| { &mut m.artifact_digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerExecutable>(
+                    "WorkerExecutable",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerExecutable {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerExecutable> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerExecutable,
+        };
+        unsafe {
+            instance.get(WorkerExecutable::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerExecutable {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_args();
+        self.clear_env();
+        self.clear_artifact_digest();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerExecutable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerExecutable {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerTaskSubmissionRequest {
+    // message fields
+    pub worker_id: ::std::string::String,
+    pub task_id: ::std::string::String,
+    pub docker_name: ::std::string::String,
+    pub data_in: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub closure: ::std::vec::Vec<u8>,
+    pub map_type: WorkerTaskSubmissionRequest_MapType,
+    pub task_timeout: i32,
+    pub limits: ::protobuf::SingularPtrField<WorkerResourceLimits>,
+    pub executable: ::protobuf::SingularPtrField<WorkerExecutable>,
+    pub closure_digest: ::std::string::String,
+    pub broadcasts: ::protobuf::RepeatedField<WorkerBroadcast>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerTaskSubmissionRequest {
+    pub fn new() -> WorkerTaskSubmissionRequest {
+        ::std::default::Default::default()
+    }
+
+    // string worker_id = 1;
+
+    pub fn clear_worker_id(&mut self) {
+        self.worker_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker_id(&mut self, v: ::std::string::String) {
+        self.worker_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
+        &mut self.worker_id
+    }
+
+    // Take field
+    pub fn take_worker_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    }
+
+    pub fn get_worker_id(&self) -> &str {
+        &self.worker_id
+    }
+
+    // string task_id = 2;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: ::std::string::String) {
+        self.task_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_task_id(&mut self) -> &mut ::std::string::String {
+        &mut self.task_id
+    }
+
+    // Take field
+    pub fn take_task_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.task_id, ::std::string::String::new())
+    }
+
+    pub fn get_task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    // string docker_name = 3;
+
+    pub fn clear_docker_name(&mut self) {
+        self.docker_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_docker_name(&mut self, v: ::std::string::String) {
+        self.docker_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_docker_name(&mut self) -> &mut ::std::string::String {
+        &mut self.docker_name
+    }
+
+    // Take field
+    pub fn take_docker_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.docker_name, ::std::string::String::new())
+    }
+
+    pub fn get_docker_name(&self) -> &str {
+        &self.docker_name
+    }
+
+    // repeated bytes data_in = 4;
+
+    pub fn clear_data_in(&mut self) {
+        self.data_in.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_in(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.data_in = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data_in(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.data_in
+    }
+
+    // Take field
+    pub fn take_data_in(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.data_in, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_data_in(&self) -> &[::std::vec::Vec<u8>] {
+        &self.data_in
+    }
+
+    // bytes closure = 5;
+
+    pub fn clear_closure(&mut self) {
+        self.closure.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_closure(&mut self, v: ::std::vec::Vec<u8>) {
+        self.closure = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_closure(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.closure
+    }
+
+    // Take field
+    pub fn take_closure(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.closure, ::std::vec::Vec::new())
+    }
+
+    pub fn get_closure(&self) -> &[u8] {
+        &self.closure
+    }
+
+    // .WorkerTaskSubmissionRequest.MapType map_type = 6;
+
+    pub fn clear_map_type(&mut self) {
+        self.map_type = WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_map_type(&mut self, v: WorkerTaskSubmissionRequest_MapType) {
+        self.map_type = v;
+    }
+
+    pub fn get_map_type(&self) -> WorkerTaskSubmissionRequest_MapType {
+        self.map_type
+    }
+
+    // int32 task_timeout = 7;
+
+    pub fn clear_task_timeout(&mut self) {
+        self.task_timeout = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_timeout(&mut self, v: i32) {
+        self.task_timeout = v;
+    }
+
+    pub fn get_task_timeout(&self) -> i32 {
+        self.task_timeout
+    }
+
+    // .WorkerResourceLimits limits = 8;
+
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    pub fn has_limits(&self) -> bool {
+        self.limits.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limits(&mut self, v: WorkerResourceLimits) {
+        self.limits = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_limits(&mut self) -> &mut WorkerResourceLimits {
+        if self.limits.is_none() {
+            self.limits.set_default();
+        }
+        self.limits.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_limits(&mut self) -> WorkerResourceLimits {
+        self.limits.take().unwrap_or_else(|| WorkerResourceLimits::new())
+    }
+
+    pub fn get_limits(&self) -> &WorkerResourceLimits {
+        self.limits.as_ref().unwrap_or_else(|| WorkerResourceLimits::default_instance())
+    }
+
+    // .WorkerExecutable executable = 9;
+
+    pub fn clear_executable(&mut self) {
+        self.executable.clear();
+    }
+
+    pub fn has_executable(&self) -> bool {
+        self.executable.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executable(&mut self, v: WorkerExecutable) {
+        self.executable = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_executable(&mut self) -> &mut WorkerExecutable {
+        if self.executable.is_none() {
+            self.executable.set_default();
+        }
+        self.executable.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_executable(&mut self) -> WorkerExecutable {
+        self.executable.take().unwrap_or_else(|| WorkerExecutable::new())
+    }
+
+    pub fn get_executable(&self) -> &WorkerExecutable {
+        self.executable.as_ref().unwrap_or_else(|| WorkerExecutable::default_instance())
+    }
+
+    // string closure_digest = 10;
+
+    pub fn clear_closure_digest(&mut self) {
+        self.closure_digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_closure_digest(&mut self, v: ::std::string::String) {
+        self.closure_digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_closure_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.closure_digest
+    }
+
+    // Take field
+    pub fn take_closure_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.closure_digest, ::std::string::String::new())
+    }
+
+    pub fn get_closure_digest(&self) -> &str {
+        &self.closure_digest
+    }
+
+    // repeated .WorkerBroadcast broadcasts = 11;
+
+    pub fn clear_broadcasts(&mut self) {
+        self.broadcasts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_broadcasts(&mut self, v: ::protobuf::RepeatedField<WorkerBroadcast>) {
+        self.broadcasts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_broadcasts(&mut self) -> &mut ::protobuf::RepeatedField<WorkerBroadcast> {
+        &mut self.broadcasts
+    }
+
+    // Take field
+    pub fn take_broadcasts(&mut self) -> ::protobuf::RepeatedField<WorkerBroadcast> {
+        ::std::mem::replace(&mut self.broadcasts, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_broadcasts(&self) -> &[WorkerBroadcast] {
+        &self.broadcasts
+    }
+}
+
+impl ::protobuf::Message for WorkerTaskSubmissionRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.limits {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.executable {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.broadcasts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.task_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.docker_name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.data_in)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.closure)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.map_type, 6, &mut self.unknown_fields)?
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.task_timeout = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.limits)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.executable)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.closure_digest)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.broadcasts)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.worker_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        }
+        if !self.task_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.task_id);
+        }
+        if !self.docker_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.docker_name);
+        }
+        for value in &self.data_in {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        if !self.closure.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.closure);
+        }
+        if self.map_type != WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT {
+            my_size += ::protobuf::rt::enum_size(6, self.map_type);
+        }
+        if self.task_timeout != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.task_timeout, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.limits.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.executable.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.closure_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.closure_digest);
+        }
+        for value in &self.broadcasts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.worker_id.is_empty() {
+            os.write_string(1, &self.worker_id)?;
+        }
+        if !self.task_id.is_empty() {
+            os.write_string(2, &self.task_id)?;
+        }
+        if !self.docker_name.is_empty() {
+            os.write_string(3, &self.docker_name)?;
+        }
+        for v in &self.data_in {
+            os.write_bytes(4, &v)?;
+        };
+        if !self.closure.is_empty() {
+            os.write_bytes(5, &self.closure)?;
+        }
+        if self.map_type != WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT {
+            os.write_enum(6, self.map_type.value())?;
+        }
+        if self.task_timeout != 0 {
+            os.write_int32(7, self.task_timeout)?;
+        }
+        if let Some(ref v) = self.limits.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.executable.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.closure_digest.is_empty() {
+            os.write_string(10, &self.closure_digest)?;
+        }
+        for v in &self.broadcasts {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerTaskSubmissionRequest {
+        WorkerTaskSubmissionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_id",
+                    |m: &WorkerTaskSubmissionRequest| { &m.worker_id },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.worker_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "task_id",
+                    |m: &WorkerTaskSubmissionRequest| { &m.task_id },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "docker_name",
+                    |m: &WorkerTaskSubmissionRequest| { &m.docker_name },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.docker_name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data_in",
+                    |m: &WorkerTaskSubmissionRequest| { &m.data_in },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.data_in },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "closure",
+                    |m: &WorkerTaskSubmissionRequest| { &m.closure },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.closure },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerTaskSubmissionRequest_MapType>>(
+                    "map_type",
+                    |m: &WorkerTaskSubmissionRequest| { &m.map_type },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.map_type },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "task_timeout",
+                    |m: &WorkerTaskSubmissionRequest| { &m.task_timeout },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.task_timeout },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkerResourceLimits>>(
+                    "limits",
+                    |m: &WorkerTaskSubmissionRequest| { &m.limits },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.limits },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkerExecutable>>(
+                    "executable",
+                    |m: &WorkerTaskSubmissionRequest| { &m.executable },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.executable },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "closure_digest",
+                    |m: &WorkerTaskSubmissionRequest| { &m.closure_digest },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.closure_digest },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkerBroadcast>>(
+                    "broadcasts",
+                    |m: &WorkerTaskSubmissionRequest| { &m.broadcasts },
+                    |m: &mut WorkerTaskSubmissionRequest| { &mut m.broadcasts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerTaskSubmissionRequest>(
+                    "WorkerTaskSubmissionRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerTaskSubmissionRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerTaskSubmissionRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerTaskSubmissionRequest,
+        };
+        unsafe {
+            instance.get(WorkerTaskSubmissionRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerTaskSubmissionRequest {
+    fn clear(&mut self) {
+        self.clear_worker_id();
+        self.clear_task_id();
+        self.clear_docker_name();
+        self.clear_data_in();
+        self.clear_closure();
+        self.clear_map_type();
+        self.clear_task_timeout();
+        self.clear_limits();
+        self.clear_executable();
+        self.clear_closure_digest();
+        self.clear_broadcasts();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerTaskSubmissionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerTaskSubmissionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WorkerTaskSubmissionRequest_MapType {
+    SINGLE_IN_MULTI_OUT = 0,
+    SINGLE_IN_SINGLE_OUT = 1,
+    MULTI_IN_SINGLE_OUT = 2,
+}
+
+impl ::protobuf::ProtobufEnum for WorkerTaskSubmissionRequest_MapType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WorkerTaskSubmissionRequest_MapType> {
+        match value {
+            0 => ::std::option::Option::Some(WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT),
+            1 => ::std::option::Option::Some(WorkerTaskSubmissionRequest_MapType::SINGLE_IN_SINGLE_OUT),
+            2 => ::std::option::Option::Some(WorkerTaskSubmissionRequest_MapType::MULTI_IN_SINGLE_OUT),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WorkerTaskSubmissionRequest_MapType] = &[
+            WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT,
+            WorkerTaskSubmissionRequest_MapType::SINGLE_IN_SINGLE_OUT,
+            WorkerTaskSubmissionRequest_MapType::MULTI_IN_SINGLE_OUT,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("WorkerTaskSubmissionRequest_MapType", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for WorkerTaskSubmissionRequest_MapType {
+}
+
+impl ::std::default::Default for WorkerTaskSubmissionRequest_MapType {
+    fn default() -> Self {
+        WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerTaskSubmissionRequest_MapType {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerAccumulator {
+    // message fields
+    pub name: ::std::string::String,
+    pub value: f64,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerAccumulator {
+    pub fn new() -> WorkerAccumulator {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // double value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value = 0.;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: f64) {
+        self.value = v;
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl ::protobuf::Message for WorkerAccumulator {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_double()?;
+                    self.value = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.value != 0. {
+            my_size += 9;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.value != 0. {
+            os.write_double(2, self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerAccumulator {
+        WorkerAccumulator::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &WorkerAccumulator| { &m.name },
+                    |m: &mut WorkerAccumulator| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                    "value",
+                    |m: &WorkerAccumulator| { &m.value },
+                    |m: &mut WorkerAccumulator| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerAccumulator>(
+                    "WorkerAccumulator",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerAccumulator {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerAccumulator> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerAccumulator,
+        };
+        unsafe {
+            instance.get(WorkerAccumulator::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerAccumulator {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerAccumulator {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerAccumulator {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerFinishedRequest {
+    // message fields
+    pub worker_id: ::std::string::String,
+    pub task_id: ::std::string::String,
+    pub status: WorkerFinishedRequest_WorkerTaskStatus,
+    pub data_out: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub accumulators: ::protobuf::RepeatedField<WorkerAccumulator>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerFinishedRequest {
+    pub fn new() -> WorkerFinishedRequest {
+        ::std::default::Default::default()
+    }
+
+    // string worker_id = 1;
+
+    pub fn clear_worker_id(&mut self) {
+        self.worker_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker_id(&mut self, v: ::std::string::String) {
+        self.worker_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
+        &mut self.worker_id
+    }
+
+    // Take field
+    pub fn take_worker_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    }
+
+    pub fn get_worker_id(&self) -> &str {
+        &self.worker_id
+    }
+
+    // string task_id = 2;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: ::std::string::String) {
+        self.task_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_task_id(&mut self) -> &mut ::std::string::String {
+        &mut self.task_id
+    }
+
+    // Take field
+    pub fn take_task_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.task_id, ::std::string::String::new())
+    }
+
+    pub fn get_task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    // .WorkerFinishedRequest.WorkerTaskStatus status = 3;
+
+    pub fn clear_status(&mut self) {
+        self.status = WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: WorkerFinishedRequest_WorkerTaskStatus) {
+        self.status = v;
+    }
+
+    pub fn get_status(&self) -> WorkerFinishedRequest_WorkerTaskStatus {
+        self.status
+    }
+
+    // repeated bytes data_out = 4;
+
+    pub fn clear_data_out(&mut self) {
+        self.data_out.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data_out(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.data_out = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data_out(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.data_out
+    }
+
+    // Take field
+    pub fn take_data_out(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.data_out, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_data_out(&self) -> &[::std::vec::Vec<u8>] {
+        &self.data_out
+    }
+
+    // repeated .WorkerAccumulator accumulators = 5;
+
+    pub fn clear_accumulators(&mut self) {
+        self.accumulators.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accumulators(&mut self, v: ::protobuf::RepeatedField<WorkerAccumulator>) {
+        self.accumulators = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accumulators(&mut self) -> &mut ::protobuf::RepeatedField<WorkerAccumulator> {
+        &mut self.accumulators
+    }
+
+    // Take field
+    pub fn take_accumulators(&mut self) -> ::protobuf::RepeatedField<WorkerAccumulator> {
+        ::std::mem::replace(&mut self.accumulators, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_accumulators(&self) -> &[WorkerAccumulator] {
+        &self.accumulators
+    }
+}
+
+impl ::protobuf::Message for WorkerFinishedRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.accumulators {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.task_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.data_out)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accumulators)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.worker_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        }
+        if !self.task_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.task_id);
+        }
+        if self.status != WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED {
+            my_size += ::protobuf::rt::enum_size(3, self.status);
+        }
+        for value in &self.data_out {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        for value in &self.accumulators {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.worker_id.is_empty() {
+            os.write_string(1, &self.worker_id)?;
+        }
+        if !self.task_id.is_empty() {
+            os.write_string(2, &self.task_id)?;
+        }
+        if self.status != WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED {
+            os.write_enum(3, self.status.value())?;
+        }
+        for v in &self.data_out {
+            os.write_bytes(4, &v)?;
+        };
+        for v in &self.accumulators {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerFinishedRequest {
+        WorkerFinishedRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_id",
+                    |m: &WorkerFinishedRequest| { &m.worker_id },
+                    |m: &mut WorkerFinishedRequest| { &mut m.worker_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "task_id",
+                    |m: &WorkerFinishedRequest| { &m.task_id },
+                    |m: &mut WorkerFinishedRequest| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerFinishedRequest_WorkerTaskStatus>>(
+                    "status",
+                    |m: &WorkerFinishedRequest| { &m.status },
+                    |m: &mut WorkerFinishedRequest| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data_out",
+                    |m: &WorkerFinishedRequest| { &m.data_out },
+                    |m: &mut WorkerFinishedRequest| { &mut m.data_out },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkerAccumulator>>(
+                    "accumulators",
+                    |m: &WorkerFinishedRequest| { &m.accumulators },
+                    |m: &mut WorkerFinishedRequest| { &mut m.accumulators },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerFinishedRequest>(
+                    "WorkerFinishedRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerFinishedRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerFinishedRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerFinishedRequest,
+        };
+        unsafe {
+            instance.get(WorkerFinishedRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerFinishedRequest {
+    fn clear(&mut self) {
+        self.clear_worker_id();
+        self.clear_task_id();
+        self.clear_status();
+        self.clear_data_out();
+        self.clear_accumulators();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerFinishedRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerFinishedRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum WorkerTaskSubmissionRequest_MapType {
-    SINGLE_IN_MULTI_OUT = 0,
-    SINGLE_IN_SINGLE_OUT = 1,
-    MULTI_IN_SINGLE_OUT = 2,
+pub enum WorkerFinishedRequest_WorkerTaskStatus {
+    TASK_FINISHED = 0,
+    TASK_ERRORED = 1,
+    TASK_TIMED_OUT = 2,
+    TASK_OUT_OF_MEMORY = 3,
+    TASK_UNAVAILABLE = 4,
+}
+
+impl ::protobuf::ProtobufEnum for WorkerFinishedRequest_WorkerTaskStatus {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WorkerFinishedRequest_WorkerTaskStatus> {
+        match value {
+            0 => ::std::option::Option::Some(WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED),
+            1 => ::std::option::Option::Some(WorkerFinishedRequest_WorkerTaskStatus::TASK_ERRORED),
+            2 => ::std::option::Option::Some(WorkerFinishedRequest_WorkerTaskStatus::TASK_TIMED_OUT),
+            3 => ::std::option::Option::Some(WorkerFinishedRequest_WorkerTaskStatus::TASK_OUT_OF_MEMORY),
+            4 => ::std::option::Option::Some(WorkerFinishedRequest_WorkerTaskStatus::TASK_UNAVAILABLE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WorkerFinishedRequest_WorkerTaskStatus] = &[
+            WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED,
+            WorkerFinishedRequest_WorkerTaskStatus::TASK_ERRORED,
+            WorkerFinishedRequest_WorkerTaskStatus::TASK_TIMED_OUT,
+            WorkerFinishedRequest_WorkerTaskStatus::TASK_OUT_OF_MEMORY,
+            WorkerFinishedRequest_WorkerTaskStatus::TASK_UNAVAILABLE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("WorkerFinishedRequest_WorkerTaskStatus", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for WorkerFinishedRequest_WorkerTaskStatus {
+}
+
+impl ::std::default::Default for WorkerFinishedRequest_WorkerTaskStatus {
+    fn default() -> Self {
+        WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerFinishedRequest_WorkerTaskStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerFinishedResponse {
+    // message fields
+    pub response_processed: bool,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerFinishedResponse {
+    pub fn new() -> WorkerFinishedResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool response_processed = 1;
+
+    pub fn clear_response_processed(&mut self) {
+        self.response_processed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_response_processed(&mut self, v: bool) {
+        self.response_processed = v;
+    }
+
+    pub fn get_response_processed(&self) -> bool {
+        self.response_processed
+    }
+}
+
+impl ::protobuf::Message for WorkerFinishedResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.response_processed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.response_processed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.response_processed != false {
+            os.write_bool(1, self.response_processed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerFinishedResponse {
+        WorkerFinishedResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "response_processed",
+                    |m: &WorkerFinishedResponse| { &m.response_processed },
+                    |m: &mut WorkerFinishedResponse| { &mut m.response_processed },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerFinishedResponse>(
+                    "WorkerFinishedResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerFinishedResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerFinishedResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerFinishedResponse,
+        };
+        unsafe {
+            instance.get(WorkerFinishedResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerFinishedResponse {
+    fn clear(&mut self) {
+        self.clear_response_processed();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerFinishedResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerFinishedResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerTaskCancellationRequest {
+    // message fields
+    pub worker_id: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerTaskCancellationRequest {
+    pub fn new() -> WorkerTaskCancellationRequest {
+        ::std::default::Default::default()
+    }
+
+    // string worker_id = 1;
+
+    pub fn clear_worker_id(&mut self) {
+        self.worker_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker_id(&mut self, v: ::std::string::String) {
+        self.worker_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
+        &mut self.worker_id
+    }
+
+    // Take field
+    pub fn take_worker_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    }
+
+    pub fn get_worker_id(&self) -> &str {
+        &self.worker_id
+    }
+}
+
+impl ::protobuf::Message for WorkerTaskCancellationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.worker_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.worker_id.is_empty() {
+            os.write_string(1, &self.worker_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerTaskCancellationRequest {
+        WorkerTaskCancellationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_id",
+                    |m: &WorkerTaskCancellationRequest| { &m.worker_id },
+                    |m: &mut WorkerTaskCancellationRequest| { &mut m.worker_id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerTaskCancellationRequest>(
+                    "WorkerTaskCancellationRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerTaskCancellationRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerTaskCancellationRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerTaskCancellationRequest,
+        };
+        unsafe {
+            instance.get(WorkerTaskCancellationRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerTaskCancellationRequest {
+    fn clear(&mut self) {
+        self.clear_worker_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerTaskCancellationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerTaskCancellationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerArtifactRequest {
+    // message fields
+    pub worker_id: ::std::string::String,
+    pub digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerArtifactRequest {
+    pub fn new() -> WorkerArtifactRequest {
+        ::std::default::Default::default()
+    }
+
+    // string worker_id = 1;
+
+    pub fn clear_worker_id(&mut self) {
+        self.worker_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker_id(&mut self, v: ::std::string::String) {
+        self.worker_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
+        &mut self.worker_id
+    }
+
+    // Take field
+    pub fn take_worker_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    }
+
+    pub fn get_worker_id(&self) -> &str {
+        &self.worker_id
+    }
+
+    // string digest = 2;
+
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
+    }
+
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
+    }
+
+    pub fn get_digest(&self) -> &str {
+        &self.digest
+    }
+}
+
+impl ::protobuf::Message for WorkerArtifactRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.worker_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        }
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.worker_id.is_empty() {
+            os.write_string(1, &self.worker_id)?;
+        }
+        if !self.digest.is_empty() {
+            os.write_string(2, &self.digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerArtifactRequest {
+        WorkerArtifactRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_id",
+                    |m: &WorkerArtifactRequest| { &m.worker_id },
+                    |m: &mut WorkerArtifactRequest| { &mut m.worker_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "digest",
+                    |m: &WorkerArtifactRequest| { &m.digest },
+                    |m: &mut WorkerArtifactRequest| { &mut m.digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerArtifactRequest>(
+                    "WorkerArtifactRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerArtifactRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerArtifactRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerArtifactRequest,
+        };
+        unsafe {
+            instance.get(WorkerArtifactRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerArtifactRequest {
+    fn clear(&mut self) {
+        self.clear_worker_id();
+        self.clear_digest();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerArtifactRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerArtifactRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerArtifactResponse {
+    // message fields
+    pub digest: ::std::string::String,
+    pub found: bool,
+    pub artifact: ::std::vec::Vec<u8>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerArtifactResponse {
+    pub fn new() -> WorkerArtifactResponse {
+        ::std::default::Default::default()
+    }
+
+    // string digest = 1;
+
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
+    }
+
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
+    }
+
+    pub fn get_digest(&self) -> &str {
+        &self.digest
+    }
+
+    // bool found = 2;
+
+    pub fn clear_found(&mut self) {
+        self.found = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_found(&mut self, v: bool) {
+        self.found = v;
+    }
+
+    pub fn get_found(&self) -> bool {
+        self.found
+    }
+
+    // bytes artifact = 3;
+
+    pub fn clear_artifact(&mut self) {
+        self.artifact.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact(&mut self, v: ::std::vec::Vec<u8>) {
+        self.artifact = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_artifact(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.artifact
+    }
+
+    // Take field
+    pub fn take_artifact(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.artifact, ::std::vec::Vec::new())
+    }
+
+    pub fn get_artifact(&self) -> &[u8] {
+        &self.artifact
+    }
+}
+
+impl ::protobuf::Message for WorkerArtifactResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.found = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.artifact)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.digest);
+        }
+        if self.found != false {
+            my_size += 2;
+        }
+        if !self.artifact.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.artifact);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.digest.is_empty() {
+            os.write_string(1, &self.digest)?;
+        }
+        if self.found != false {
+            os.write_bool(2, self.found)?;
+        }
+        if !self.artifact.is_empty() {
+            os.write_bytes(3, &self.artifact)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerArtifactResponse {
+        WorkerArtifactResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "digest",
+                    |m: &WorkerArtifactResponse| { &m.digest },
+                    |m: &mut WorkerArtifactResponse| { &mut m.digest },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "found",
+                    |m: &WorkerArtifactResponse| { &m.found },
+                    |m: &mut WorkerArtifactResponse| { &mut m.found },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "artifact",
+                    |m: &WorkerArtifactResponse| { &m.artifact },
+                    |m: &mut WorkerArtifactResponse| { &mut m.artifact },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerArtifactResponse>(
+                    "WorkerArtifactResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static WorkerArtifactResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerArtifactResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerArtifactResponse,
+        };
+        unsafe {
+            instance.get(WorkerArtifactResponse::new)
+        }
+    }
 }
 
-impl ::protobuf::ProtobufEnum for WorkerTaskSubmissionRequest_MapType {
-    fn value(&self) -> i32 {
-        *self as i32
+impl ::protobuf::Clear for WorkerArtifactResponse {
+    fn clear(&mut self) {
+        self.clear_digest();
+        self.clear_found();
+        self.clear_artifact();
+        self.unknown_fields.clear();
     }
+}
 
-    fn from_i32(value: i32) -> ::std::option::Option<WorkerTaskSubmissionRequest_MapType> {
-        match value {
-            0 => ::std::option::Option::Some(WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT),
-            1 => ::std::option::Option::Some(WorkerTaskSubmissionRequest_MapType::SINGLE_IN_SINGLE_OUT),
-            2 => ::std::option::Option::Some(WorkerTaskSubmissionRequest_MapType::MULTI_IN_SINGLE_OUT),
-            _ => ::std::option::Option::None
+impl ::std::fmt::Debug for WorkerArtifactResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerArtifactResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerClosureRequest {
+    // message fields
+    pub worker_id: ::std::string::String,
+    pub task_id: ::std::string::String,
+    pub digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl WorkerClosureRequest {
+    pub fn new() -> WorkerClosureRequest {
+        ::std::default::Default::default()
+    }
+
+    // string worker_id = 1;
+
+    pub fn clear_worker_id(&mut self) {
+        self.worker_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker_id(&mut self, v: ::std::string::String) {
+        self.worker_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
+        &mut self.worker_id
+    }
+
+    // Take field
+    pub fn take_worker_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    }
+
+    pub fn get_worker_id(&self) -> &str {
+        &self.worker_id
+    }
+
+    // string task_id = 2;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: ::std::string::String) {
+        self.task_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_task_id(&mut self) -> &mut ::std::string::String {
+        &mut self.task_id
+    }
+
+    // Take field
+    pub fn take_task_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.task_id, ::std::string::String::new())
+    }
+
+    pub fn get_task_id(&self) -> &str {
+        &self.task_id
+    }
+
+    // string digest = 3;
+
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
+    }
+
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
+    }
+
+    pub fn get_digest(&self) -> &str {
+        &self.digest
+    }
+}
+
+impl ::protobuf::Message for WorkerClosureRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.task_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    fn values() -> &'static [Self] {
-        static values: &'static [WorkerTaskSubmissionRequest_MapType] = &[
-            WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT,
-            WorkerTaskSubmissionRequest_MapType::SINGLE_IN_SINGLE_OUT,
-            WorkerTaskSubmissionRequest_MapType::MULTI_IN_SINGLE_OUT,
-        ];
-        values
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.worker_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        }
+        if !self.task_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.task_id);
+        }
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.worker_id.is_empty() {
+            os.write_string(1, &self.worker_id)?;
+        }
+        if !self.task_id.is_empty() {
+            os.write_string(2, &self.task_id)?;
+        }
+        if !self.digest.is_empty() {
+            os.write_string(3, &self.digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkerClosureRequest {
+        WorkerClosureRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_id",
+                    |m: &WorkerClosureRequest| { &m.worker_id },
+                    |m: &mut WorkerClosureRequest| { &mut m.worker_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "task_id",
+                    |m: &WorkerClosureRequest| { &m.task_id },
+                    |m: &mut WorkerClosureRequest| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "digest",
+                    |m: &WorkerClosureRequest| { &m.digest },
+                    |m: &mut WorkerClosureRequest| { &mut m.digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerClosureRequest>(
+                    "WorkerClosureRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
 
-    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerClosureRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerClosureRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+            ptr: 0 as *const WorkerClosureRequest,
         };
         unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("WorkerTaskSubmissionRequest_MapType", file_descriptor_proto())
-            })
+            instance.get(WorkerClosureRequest::new)
         }
     }
 }
 
-impl ::std::marker::Copy for WorkerTaskSubmissionRequest_MapType {
+impl ::protobuf::Clear for WorkerClosureRequest {
+    fn clear(&mut self) {
+        self.clear_worker_id();
+        self.clear_task_id();
+        self.clear_digest();
+        self.unknown_fields.clear();
+    }
 }
 
-impl ::std::default::Default for WorkerTaskSubmissionRequest_MapType {
-    fn default() -> Self {
-        WorkerTaskSubmissionRequest_MapType::SINGLE_IN_MULTI_OUT
+impl ::std::fmt::Debug for WorkerClosureRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerTaskSubmissionRequest_MapType {
+impl ::protobuf::reflect::ProtobufValue for WorkerClosureRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct WorkerFinishedRequest {
+pub struct WorkerClosureResponse {
     // message fields
-    pub worker_id: ::std::string::String,
-    pub task_id: ::std::string::String,
-    pub status: WorkerFinishedRequest_WorkerTaskStatus,
-    pub data_out: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub digest: ::std::string::String,
+    pub found: bool,
+    pub closure: ::std::vec::Vec<u8>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl WorkerFinishedRequest {
-    pub fn new() -> WorkerFinishedRequest {
+impl WorkerClosureResponse {
+    pub fn new() -> WorkerClosureResponse {
         ::std::default::Default::default()
     }
 
-    // string worker_id = 1;
-
-    pub fn clear_worker_id(&mut self) {
-        self.worker_id.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_worker_id(&mut self, v: ::std::string::String) {
-        self.worker_id = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
-        &mut self.worker_id
-    }
-
-    // Take field
-    pub fn take_worker_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
-    }
-
-    pub fn get_worker_id(&self) -> &str {
-        &self.worker_id
-    }
-
-    // string task_id = 2;
+    // string digest = 1;
 
-    pub fn clear_task_id(&mut self) {
-        self.task_id.clear();
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_task_id(&mut self, v: ::std::string::String) {
-        self.task_id = v;
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_task_id(&mut self) -> &mut ::std::string::String {
-        &mut self.task_id
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
     }
 
     // Take field
-    pub fn take_task_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.task_id, ::std::string::String::new())
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
     }
 
-    pub fn get_task_id(&self) -> &str {
-        &self.task_id
+    pub fn get_digest(&self) -> &str {
+        &self.digest
     }
 
-    // .WorkerFinishedRequest.WorkerTaskStatus status = 3;
+    // bool found = 2;
 
-    pub fn clear_status(&mut self) {
-        self.status = WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED;
+    pub fn clear_found(&mut self) {
+        self.found = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_status(&mut self, v: WorkerFinishedRequest_WorkerTaskStatus) {
-        self.status = v;
+    pub fn set_found(&mut self, v: bool) {
+        self.found = v;
     }
 
-    pub fn get_status(&self) -> WorkerFinishedRequest_WorkerTaskStatus {
-        self.status
+    pub fn get_found(&self) -> bool {
+        self.found
     }
 
-    // repeated bytes data_out = 4;
+    // bytes closure = 3;
 
-    pub fn clear_data_out(&mut self) {
-        self.data_out.clear();
+    pub fn clear_closure(&mut self) {
+        self.closure.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_data_out(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
-        self.data_out = v;
+    pub fn set_closure(&mut self, v: ::std::vec::Vec<u8>) {
+        self.closure = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_data_out(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        &mut self.data_out
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_closure(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.closure
     }
 
     // Take field
-    pub fn take_data_out(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
-        ::std::mem::replace(&mut self.data_out, ::protobuf::RepeatedField::new())
+    pub fn take_closure(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.closure, ::std::vec::Vec::new())
     }
 
-    pub fn get_data_out(&self) -> &[::std::vec::Vec<u8>] {
-        &self.data_out
+    pub fn get_closure(&self) -> &[u8] {
+        &self.closure
     }
 }
 
-impl ::protobuf::Message for WorkerFinishedRequest {
+impl ::protobuf::Message for WorkerClosureResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1451,16 +4526,17 @@ impl ::protobuf::Message for WorkerFinishedRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.task_id)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.found = tmp;
                 },
                 3 => {
-                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.status, 3, &mut self.unknown_fields)?
-                },
-                4 => {
-                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.data_out)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.closure)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1474,36 +4550,30 @@ impl ::protobuf::Message for WorkerFinishedRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.worker_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.digest);
         }
-        if !self.task_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.task_id);
+        if self.found != false {
+            my_size += 2;
         }
-        if self.status != WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED {
-            my_size += ::protobuf::rt::enum_size(3, self.status);
+        if !self.closure.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.closure);
         }
-        for value in &self.data_out {
-            my_size += ::protobuf::rt::bytes_size(4, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.worker_id.is_empty() {
-            os.write_string(1, &self.worker_id)?;
+        if !self.digest.is_empty() {
+            os.write_string(1, &self.digest)?;
         }
-        if !self.task_id.is_empty() {
-            os.write_string(2, &self.task_id)?;
+        if self.found != false {
+            os.write_bool(2, self.found)?;
         }
-        if self.status != WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED {
-            os.write_enum(3, self.status.value())?;
+        if !self.closure.is_empty() {
+            os.write_bytes(3, &self.closure)?;
         }
-        for v in &self.data_out {
-            os.write_bytes(4, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1534,8 +4604,8 @@ impl ::protobuf::Message for WorkerFinishedRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerFinishedRequest {
-        WorkerFinishedRequest::new()
+    fn new() -> WorkerClosureResponse {
+        WorkerClosureResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1547,27 +4617,22 @@ impl ::protobuf::Message for WorkerFinishedRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "worker_id",
-                    |m: &WorkerFinishedRequest| { &m.worker_id },
-                    |m: &mut WorkerFinishedRequest| { &mut m.worker_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "task_id",
-                    |m: &WorkerFinishedRequest| { &m.task_id },
-                    |m: &mut WorkerFinishedRequest| { &mut m.task_id },
+                    "digest",
+                    |m: &WorkerClosureResponse| { &m.digest },
+                    |m: &mut WorkerClosureResponse| { &mut m.digest },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerFinishedRequest_WorkerTaskStatus>>(
-                    "status",
-                    |m: &WorkerFinishedRequest| { &m.status },
-                    |m: &mut WorkerFinishedRequest| { &mut m.status },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "found",
+                    |m: &WorkerClosureResponse| { &m.found },
+                    |m: &mut WorkerClosureResponse| { &mut m.found },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data_out",
-                    |m: &WorkerFinishedRequest| { &m.data_out },
-                    |m: &mut WorkerFinishedRequest| { &mut m.data_out },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "closure",
+                    |m: &WorkerClosureResponse| { &m.closure },
+                    |m: &mut WorkerClosureResponse| { &mut m.closure },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerFinishedRequest>(
-                    "WorkerFinishedRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerClosureResponse>(
+                    "WorkerClosureResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1575,125 +4640,107 @@ impl ::protobuf::Message for WorkerFinishedRequest {
         }
     }
 
-    fn default_instance() -> &'static WorkerFinishedRequest {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerFinishedRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerClosureResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerClosureResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerFinishedRequest,
+            ptr: 0 as *const WorkerClosureResponse,
         };
         unsafe {
-            instance.get(WorkerFinishedRequest::new)
+            instance.get(WorkerClosureResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for WorkerFinishedRequest {
+impl ::protobuf::Clear for WorkerClosureResponse {
     fn clear(&mut self) {
-        self.clear_worker_id();
-        self.clear_task_id();
-        self.clear_status();
-        self.clear_data_out();
+        self.clear_digest();
+        self.clear_found();
+        self.clear_closure();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorkerFinishedRequest {
+impl ::std::fmt::Debug for WorkerClosureResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerFinishedRequest {
+impl ::protobuf::reflect::ProtobufValue for WorkerClosureResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
-#[derive(Clone,PartialEq,Eq,Debug,Hash)]
-pub enum WorkerFinishedRequest_WorkerTaskStatus {
-    TASK_FINISHED = 0,
-    TASK_ERRORED = 1,
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerBroadcastRequest {
+    // message fields
+    pub worker_id: ::std::string::String,
+    pub digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
 }
 
-impl ::protobuf::ProtobufEnum for WorkerFinishedRequest_WorkerTaskStatus {
-    fn value(&self) -> i32 {
-        *self as i32
+impl WorkerBroadcastRequest {
+    pub fn new() -> WorkerBroadcastRequest {
+        ::std::default::Default::default()
     }
 
-    fn from_i32(value: i32) -> ::std::option::Option<WorkerFinishedRequest_WorkerTaskStatus> {
-        match value {
-            0 => ::std::option::Option::Some(WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED),
-            1 => ::std::option::Option::Some(WorkerFinishedRequest_WorkerTaskStatus::TASK_ERRORED),
-            _ => ::std::option::Option::None
-        }
-    }
+    // string worker_id = 1;
 
-    fn values() -> &'static [Self] {
-        static values: &'static [WorkerFinishedRequest_WorkerTaskStatus] = &[
-            WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED,
-            WorkerFinishedRequest_WorkerTaskStatus::TASK_ERRORED,
-        ];
-        values
+    pub fn clear_worker_id(&mut self) {
+        self.worker_id.clear();
     }
 
-    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("WorkerFinishedRequest_WorkerTaskStatus", file_descriptor_proto())
-            })
-        }
+    // Param is passed by value, moved
+    pub fn set_worker_id(&mut self, v: ::std::string::String) {
+        self.worker_id = v;
     }
-}
 
-impl ::std::marker::Copy for WorkerFinishedRequest_WorkerTaskStatus {
-}
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
+        &mut self.worker_id
+    }
 
-impl ::std::default::Default for WorkerFinishedRequest_WorkerTaskStatus {
-    fn default() -> Self {
-        WorkerFinishedRequest_WorkerTaskStatus::TASK_FINISHED
+    // Take field
+    pub fn take_worker_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for WorkerFinishedRequest_WorkerTaskStatus {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    pub fn get_worker_id(&self) -> &str {
+        &self.worker_id
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct WorkerFinishedResponse {
-    // message fields
-    pub response_processed: bool,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
+    // string digest = 2;
 
-impl WorkerFinishedResponse {
-    pub fn new() -> WorkerFinishedResponse {
-        ::std::default::Default::default()
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
     }
 
-    // bool response_processed = 1;
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
 
-    pub fn clear_response_processed(&mut self) {
-        self.response_processed = false;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
     }
 
-    // Param is passed by value, moved
-    pub fn set_response_processed(&mut self, v: bool) {
-        self.response_processed = v;
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
     }
 
-    pub fn get_response_processed(&self) -> bool {
-        self.response_processed
+    pub fn get_digest(&self) -> &str {
+        &self.digest
     }
 }
 
-impl ::protobuf::Message for WorkerFinishedResponse {
+impl ::protobuf::Message for WorkerBroadcastRequest {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1703,11 +4750,10 @@ impl ::protobuf::Message for WorkerFinishedResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.response_processed = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1721,8 +4767,11 @@ impl ::protobuf::Message for WorkerFinishedResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.response_processed != false {
-            my_size += 2;
+        if !self.worker_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        }
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.digest);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1730,8 +4779,11 @@ impl ::protobuf::Message for WorkerFinishedResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.response_processed != false {
-            os.write_bool(1, self.response_processed)?;
+        if !self.worker_id.is_empty() {
+            os.write_string(1, &self.worker_id)?;
+        }
+        if !self.digest.is_empty() {
+            os.write_string(2, &self.digest)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1763,8 +4815,8 @@ impl ::protobuf::Message for WorkerFinishedResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerFinishedResponse {
-        WorkerFinishedResponse::new()
+    fn new() -> WorkerBroadcastRequest {
+        WorkerBroadcastRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1775,13 +4827,18 @@ impl ::protobuf::Message for WorkerFinishedResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "response_processed",
-                    |m: &WorkerFinishedResponse| { &m.response_processed },
-                    |m: &mut WorkerFinishedResponse| { &mut m.response_processed },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_id",
+                    |m: &WorkerBroadcastRequest| { &m.worker_id },
+                    |m: &mut WorkerBroadcastRequest| { &mut m.worker_id },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerFinishedResponse>(
-                    "WorkerFinishedResponse",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "digest",
+                    |m: &WorkerBroadcastRequest| { &m.digest },
+                    |m: &mut WorkerBroadcastRequest| { &mut m.digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerBroadcastRequest>(
+                    "WorkerBroadcastRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1789,78 +4846,122 @@ impl ::protobuf::Message for WorkerFinishedResponse {
         }
     }
 
-    fn default_instance() -> &'static WorkerFinishedResponse {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerFinishedResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerBroadcastRequest {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerBroadcastRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerFinishedResponse,
+            ptr: 0 as *const WorkerBroadcastRequest,
         };
         unsafe {
-            instance.get(WorkerFinishedResponse::new)
+            instance.get(WorkerBroadcastRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for WorkerFinishedResponse {
+impl ::protobuf::Clear for WorkerBroadcastRequest {
     fn clear(&mut self) {
-        self.clear_response_processed();
+        self.clear_worker_id();
+        self.clear_digest();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorkerFinishedResponse {
+impl ::std::fmt::Debug for WorkerBroadcastRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerFinishedResponse {
+impl ::protobuf::reflect::ProtobufValue for WorkerBroadcastRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct WorkerTaskCancellationRequest {
+pub struct WorkerBroadcastResponse {
     // message fields
-    pub worker_id: ::std::string::String,
+    pub digest: ::std::string::String,
+    pub found: bool,
+    pub value: ::std::vec::Vec<u8>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
-impl WorkerTaskCancellationRequest {
-    pub fn new() -> WorkerTaskCancellationRequest {
+impl WorkerBroadcastResponse {
+    pub fn new() -> WorkerBroadcastResponse {
         ::std::default::Default::default()
     }
 
-    // string worker_id = 1;
+    // string digest = 1;
 
-    pub fn clear_worker_id(&mut self) {
-        self.worker_id.clear();
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_worker_id(&mut self, v: ::std::string::String) {
-        self.worker_id = v;
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_worker_id(&mut self) -> &mut ::std::string::String {
-        &mut self.worker_id
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
     }
 
     // Take field
-    pub fn take_worker_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.worker_id, ::std::string::String::new())
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
     }
 
-    pub fn get_worker_id(&self) -> &str {
-        &self.worker_id
+    pub fn get_digest(&self) -> &str {
+        &self.digest
+    }
+
+    // bool found = 2;
+
+    pub fn clear_found(&mut self) {
+        self.found = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_found(&mut self, v: bool) {
+        self.found = v;
+    }
+
+    pub fn get_found(&self) -> bool {
+        self.found
+    }
+
+    // bytes value = 3;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
     }
 }
 
-impl ::protobuf::Message for WorkerTaskCancellationRequest {
+impl ::protobuf::Message for WorkerBroadcastResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1870,7 +4971,17 @@ impl ::protobuf::Message for WorkerTaskCancellationRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.worker_id)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.found = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1884,8 +4995,14 @@ impl ::protobuf::Message for WorkerTaskCancellationRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.worker_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.worker_id);
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.digest);
+        }
+        if self.found != false {
+            my_size += 2;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1893,8 +5010,14 @@ impl ::protobuf::Message for WorkerTaskCancellationRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.worker_id.is_empty() {
-            os.write_string(1, &self.worker_id)?;
+        if !self.digest.is_empty() {
+            os.write_string(1, &self.digest)?;
+        }
+        if self.found != false {
+            os.write_bool(2, self.found)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1926,8 +5049,8 @@ impl ::protobuf::Message for WorkerTaskCancellationRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> WorkerTaskCancellationRequest {
-        WorkerTaskCancellationRequest::new()
+    fn new() -> WorkerBroadcastResponse {
+        WorkerBroadcastResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1939,12 +5062,22 @@ impl ::protobuf::Message for WorkerTaskCancellationRequest {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "worker_id",
-                    |m: &WorkerTaskCancellationRequest| { &m.worker_id },
-                    |m: &mut WorkerTaskCancellationRequest| { &mut m.worker_id },
+                    "digest",
+                    |m: &WorkerBroadcastResponse| { &m.digest },
+                    |m: &mut WorkerBroadcastResponse| { &mut m.digest },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<WorkerTaskCancellationRequest>(
-                    "WorkerTaskCancellationRequest",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "found",
+                    |m: &WorkerBroadcastResponse| { &m.found },
+                    |m: &mut WorkerBroadcastResponse| { &mut m.found },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &WorkerBroadcastResponse| { &m.value },
+                    |m: &mut WorkerBroadcastResponse| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerBroadcastResponse>(
+                    "WorkerBroadcastResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1952,31 +5085,33 @@ impl ::protobuf::Message for WorkerTaskCancellationRequest {
         }
     }
 
-    fn default_instance() -> &'static WorkerTaskCancellationRequest {
-        static mut instance: ::protobuf::lazy::Lazy<WorkerTaskCancellationRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static WorkerBroadcastResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerBroadcastResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const WorkerTaskCancellationRequest,
+            ptr: 0 as *const WorkerBroadcastResponse,
         };
         unsafe {
-            instance.get(WorkerTaskCancellationRequest::new)
+            instance.get(WorkerBroadcastResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for WorkerTaskCancellationRequest {
+impl ::protobuf::Clear for WorkerBroadcastResponse {
     fn clear(&mut self) {
-        self.clear_worker_id();
+        self.clear_digest();
+        self.clear_found();
+        self.clear_value();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for WorkerTaskCancellationRequest {
+impl ::std::fmt::Debug for WorkerBroadcastResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for WorkerTaskCancellationRequest {
+impl ::protobuf::reflect::ProtobufValue for WorkerBroadcastResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -2327,6 +5462,9 @@ pub enum SingleWorkerMessage_oneof_message {
     heartbeat_response(WorkerHeartbeatResponse),
     finished_request(WorkerFinishedRequest),
     consensus_request(ConsensusRequest),
+    artifact_request(WorkerArtifactRequest),
+    closure_request(WorkerClosureRequest),
+    broadcast_request(WorkerBroadcastRequest),
 }
 
 impl SingleWorkerMessage {
@@ -2365,168 +5503,315 @@ impl SingleWorkerMessage {
     }
 
     // Take field
-    pub fn take_connection_request(&mut self) -> WorkerConnectionRequest {
-        if self.has_connection_request() {
+    pub fn take_connection_request(&mut self) -> WorkerConnectionRequest {
+        if self.has_connection_request() {
+            match self.message.take() {
+                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::connection_request(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerConnectionRequest::new()
+        }
+    }
+
+    pub fn get_connection_request(&self) -> &WorkerConnectionRequest {
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::connection_request(ref v)) => v,
+            _ => WorkerConnectionRequest::default_instance(),
+        }
+    }
+
+    // .WorkerHeartbeatResponse heartbeat_response = 2;
+
+    pub fn clear_heartbeat_response(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_heartbeat_response(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_heartbeat_response(&mut self, v: WorkerHeartbeatResponse) {
+        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_heartbeat_response(&mut self) -> &mut WorkerHeartbeatResponse {
+        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(WorkerHeartbeatResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_heartbeat_response(&mut self) -> WorkerHeartbeatResponse {
+        if self.has_heartbeat_response() {
+            match self.message.take() {
+                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerHeartbeatResponse::new()
+        }
+    }
+
+    pub fn get_heartbeat_response(&self) -> &WorkerHeartbeatResponse {
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(ref v)) => v,
+            _ => WorkerHeartbeatResponse::default_instance(),
+        }
+    }
+
+    // .WorkerFinishedRequest finished_request = 3;
+
+    pub fn clear_finished_request(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_finished_request(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_finished_request(&mut self, v: WorkerFinishedRequest) {
+        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_finished_request(&mut self) -> &mut WorkerFinishedRequest {
+        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(WorkerFinishedRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_finished_request(&mut self) -> WorkerFinishedRequest {
+        if self.has_finished_request() {
+            match self.message.take() {
+                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerFinishedRequest::new()
+        }
+    }
+
+    pub fn get_finished_request(&self) -> &WorkerFinishedRequest {
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(ref v)) => v,
+            _ => WorkerFinishedRequest::default_instance(),
+        }
+    }
+
+    // .ConsensusRequest consensus_request = 4;
+
+    pub fn clear_consensus_request(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_consensus_request(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_consensus_request(&mut self, v: ConsensusRequest) {
+        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_consensus_request(&mut self) -> &mut ConsensusRequest {
+        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(ConsensusRequest::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_consensus_request(&mut self) -> ConsensusRequest {
+        if self.has_consensus_request() {
             match self.message.take() {
-                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::connection_request(v)) => v,
+                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            WorkerConnectionRequest::new()
+            ConsensusRequest::new()
         }
     }
 
-    pub fn get_connection_request(&self) -> &WorkerConnectionRequest {
+    pub fn get_consensus_request(&self) -> &ConsensusRequest {
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::connection_request(ref v)) => v,
-            _ => WorkerConnectionRequest::default_instance(),
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(ref v)) => v,
+            _ => ConsensusRequest::default_instance(),
         }
     }
 
-    // .WorkerHeartbeatResponse heartbeat_response = 2;
+    // .WorkerArtifactRequest artifact_request = 5;
 
-    pub fn clear_heartbeat_response(&mut self) {
+    pub fn clear_artifact_request(&mut self) {
         self.message = ::std::option::Option::None;
     }
 
-    pub fn has_heartbeat_response(&self) -> bool {
+    pub fn has_artifact_request(&self) -> bool {
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(..)) => true,
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_heartbeat_response(&mut self, v: WorkerHeartbeatResponse) {
-        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(v))
+    pub fn set_artifact_request(&mut self, v: WorkerArtifactRequest) {
+        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_heartbeat_response(&mut self) -> &mut WorkerHeartbeatResponse {
-        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(_)) = self.message {
+    pub fn mut_artifact_request(&mut self) -> &mut WorkerArtifactRequest {
+        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(_)) = self.message {
         } else {
-            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(WorkerHeartbeatResponse::new()));
+            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(WorkerArtifactRequest::new()));
         }
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(ref mut v)) => v,
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_heartbeat_response(&mut self) -> WorkerHeartbeatResponse {
-        if self.has_heartbeat_response() {
+    pub fn take_artifact_request(&mut self) -> WorkerArtifactRequest {
+        if self.has_artifact_request() {
             match self.message.take() {
-                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(v)) => v,
+                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            WorkerHeartbeatResponse::new()
+            WorkerArtifactRequest::new()
         }
     }
 
-    pub fn get_heartbeat_response(&self) -> &WorkerHeartbeatResponse {
+    pub fn get_artifact_request(&self) -> &WorkerArtifactRequest {
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::heartbeat_response(ref v)) => v,
-            _ => WorkerHeartbeatResponse::default_instance(),
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(ref v)) => v,
+            _ => WorkerArtifactRequest::default_instance(),
         }
     }
 
-    // .WorkerFinishedRequest finished_request = 3;
+    // .WorkerClosureRequest closure_request = 6;
 
-    pub fn clear_finished_request(&mut self) {
+    pub fn clear_closure_request(&mut self) {
         self.message = ::std::option::Option::None;
     }
 
-    pub fn has_finished_request(&self) -> bool {
+    pub fn has_closure_request(&self) -> bool {
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(..)) => true,
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_finished_request(&mut self, v: WorkerFinishedRequest) {
-        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(v))
+    pub fn set_closure_request(&mut self, v: WorkerClosureRequest) {
+        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_finished_request(&mut self) -> &mut WorkerFinishedRequest {
-        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(_)) = self.message {
+    pub fn mut_closure_request(&mut self) -> &mut WorkerClosureRequest {
+        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(_)) = self.message {
         } else {
-            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(WorkerFinishedRequest::new()));
+            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(WorkerClosureRequest::new()));
         }
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(ref mut v)) => v,
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_finished_request(&mut self) -> WorkerFinishedRequest {
-        if self.has_finished_request() {
+    pub fn take_closure_request(&mut self) -> WorkerClosureRequest {
+        if self.has_closure_request() {
             match self.message.take() {
-                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(v)) => v,
+                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            WorkerFinishedRequest::new()
+            WorkerClosureRequest::new()
         }
     }
 
-    pub fn get_finished_request(&self) -> &WorkerFinishedRequest {
+    pub fn get_closure_request(&self) -> &WorkerClosureRequest {
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::finished_request(ref v)) => v,
-            _ => WorkerFinishedRequest::default_instance(),
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(ref v)) => v,
+            _ => WorkerClosureRequest::default_instance(),
         }
     }
 
-    // .ConsensusRequest consensus_request = 4;
+    // .WorkerBroadcastRequest broadcast_request = 7;
 
-    pub fn clear_consensus_request(&mut self) {
+    pub fn clear_broadcast_request(&mut self) {
         self.message = ::std::option::Option::None;
     }
 
-    pub fn has_consensus_request(&self) -> bool {
+    pub fn has_broadcast_request(&self) -> bool {
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(..)) => true,
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_consensus_request(&mut self, v: ConsensusRequest) {
-        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(v))
+    pub fn set_broadcast_request(&mut self, v: WorkerBroadcastRequest) {
+        self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_consensus_request(&mut self) -> &mut ConsensusRequest {
-        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(_)) = self.message {
+    pub fn mut_broadcast_request(&mut self) -> &mut WorkerBroadcastRequest {
+        if let ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(_)) = self.message {
         } else {
-            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(ConsensusRequest::new()));
+            self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(WorkerBroadcastRequest::new()));
         }
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(ref mut v)) => v,
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_consensus_request(&mut self) -> ConsensusRequest {
-        if self.has_consensus_request() {
+    pub fn take_broadcast_request(&mut self) -> WorkerBroadcastRequest {
+        if self.has_broadcast_request() {
             match self.message.take() {
-                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(v)) => v,
+                ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(v)) => v,
                 _ => panic!(),
             }
         } else {
-            ConsensusRequest::new()
+            WorkerBroadcastRequest::new()
         }
     }
 
-    pub fn get_consensus_request(&self) -> &ConsensusRequest {
+    pub fn get_broadcast_request(&self) -> &WorkerBroadcastRequest {
         match self.message {
-            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(ref v)) => v,
-            _ => ConsensusRequest::default_instance(),
+            ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(ref v)) => v,
+            _ => WorkerBroadcastRequest::default_instance(),
         }
     }
 }
@@ -2553,6 +5838,21 @@ impl ::protobuf::Message for SingleWorkerMessage {
                 return false;
             }
         }
+        if let Some(SingleWorkerMessage_oneof_message::artifact_request(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleWorkerMessage_oneof_message::closure_request(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleWorkerMessage_oneof_message::broadcast_request(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -2584,6 +5884,24 @@ impl ::protobuf::Message for SingleWorkerMessage {
                     }
                     self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::consensus_request(is.read_message()?));
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::artifact_request(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::closure_request(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(SingleWorkerMessage_oneof_message::broadcast_request(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2614,6 +5932,18 @@ impl ::protobuf::Message for SingleWorkerMessage {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &SingleWorkerMessage_oneof_message::artifact_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleWorkerMessage_oneof_message::closure_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleWorkerMessage_oneof_message::broadcast_request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -2644,6 +5974,21 @@ impl ::protobuf::Message for SingleWorkerMessage {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &SingleWorkerMessage_oneof_message::artifact_request(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleWorkerMessage_oneof_message::closure_request(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleWorkerMessage_oneof_message::broadcast_request(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -2708,6 +6053,21 @@ impl ::protobuf::Message for SingleWorkerMessage {
                     SingleWorkerMessage::has_consensus_request,
                     SingleWorkerMessage::get_consensus_request,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerArtifactRequest>(
+                    "artifact_request",
+                    SingleWorkerMessage::has_artifact_request,
+                    SingleWorkerMessage::get_artifact_request,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerClosureRequest>(
+                    "closure_request",
+                    SingleWorkerMessage::has_closure_request,
+                    SingleWorkerMessage::get_closure_request,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerBroadcastRequest>(
+                    "broadcast_request",
+                    SingleWorkerMessage::has_broadcast_request,
+                    SingleWorkerMessage::get_broadcast_request,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SingleWorkerMessage>(
                     "SingleWorkerMessage",
                     fields,
@@ -2734,6 +6094,9 @@ impl ::protobuf::Clear for SingleWorkerMessage {
         self.clear_heartbeat_response();
         self.clear_finished_request();
         self.clear_consensus_request();
+        self.clear_artifact_request();
+        self.clear_closure_request();
+        self.clear_broadcast_request();
         self.unknown_fields.clear();
     }
 }
@@ -2767,6 +6130,9 @@ pub enum SingleServerMessage_oneof_message {
     finished_response(WorkerFinishedResponse),
     cancellation_request(WorkerTaskCancellationRequest),
     consensus_response(ConsensusResponse),
+    artifact_response(WorkerArtifactResponse),
+    closure_response(WorkerClosureResponse),
+    broadcast_response(WorkerBroadcastResponse),
 }
 
 impl SingleServerMessage {
@@ -3067,6 +6433,153 @@ impl SingleServerMessage {
             _ => ConsensusResponse::default_instance(),
         }
     }
+
+    // .WorkerArtifactResponse artifact_response = 7;
+
+    pub fn clear_artifact_response(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_artifact_response(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact_response(&mut self, v: WorkerArtifactResponse) {
+        self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_artifact_response(&mut self) -> &mut WorkerArtifactResponse {
+        if let ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(WorkerArtifactResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_artifact_response(&mut self) -> WorkerArtifactResponse {
+        if self.has_artifact_response() {
+            match self.message.take() {
+                ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerArtifactResponse::new()
+        }
+    }
+
+    pub fn get_artifact_response(&self) -> &WorkerArtifactResponse {
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(ref v)) => v,
+            _ => WorkerArtifactResponse::default_instance(),
+        }
+    }
+
+    // .WorkerClosureResponse closure_response = 8;
+
+    pub fn clear_closure_response(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_closure_response(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_closure_response(&mut self, v: WorkerClosureResponse) {
+        self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_closure_response(&mut self) -> &mut WorkerClosureResponse {
+        if let ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(WorkerClosureResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_closure_response(&mut self) -> WorkerClosureResponse {
+        if self.has_closure_response() {
+            match self.message.take() {
+                ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerClosureResponse::new()
+        }
+    }
+
+    pub fn get_closure_response(&self) -> &WorkerClosureResponse {
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(ref v)) => v,
+            _ => WorkerClosureResponse::default_instance(),
+        }
+    }
+
+    // .WorkerBroadcastResponse broadcast_response = 9;
+
+    pub fn clear_broadcast_response(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_broadcast_response(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_broadcast_response(&mut self, v: WorkerBroadcastResponse) {
+        self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_broadcast_response(&mut self) -> &mut WorkerBroadcastResponse {
+        if let ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(WorkerBroadcastResponse::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_broadcast_response(&mut self) -> WorkerBroadcastResponse {
+        if self.has_broadcast_response() {
+            match self.message.take() {
+                ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            WorkerBroadcastResponse::new()
+        }
+    }
+
+    pub fn get_broadcast_response(&self) -> &WorkerBroadcastResponse {
+        match self.message {
+            ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(ref v)) => v,
+            _ => WorkerBroadcastResponse::default_instance(),
+        }
+    }
 }
 
 impl ::protobuf::Message for SingleServerMessage {
@@ -3101,6 +6614,21 @@ impl ::protobuf::Message for SingleServerMessage {
                 return false;
             }
         }
+        if let Some(SingleServerMessage_oneof_message::artifact_response(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleServerMessage_oneof_message::closure_response(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SingleServerMessage_oneof_message::broadcast_response(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -3144,6 +6672,24 @@ impl ::protobuf::Message for SingleServerMessage {
                     }
                     self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::consensus_response(is.read_message()?));
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::artifact_response(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::closure_response(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(SingleServerMessage_oneof_message::broadcast_response(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3182,6 +6728,18 @@ impl ::protobuf::Message for SingleServerMessage {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &SingleServerMessage_oneof_message::artifact_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleServerMessage_oneof_message::closure_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SingleServerMessage_oneof_message::broadcast_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -3222,6 +6780,21 @@ impl ::protobuf::Message for SingleServerMessage {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &SingleServerMessage_oneof_message::artifact_response(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleServerMessage_oneof_message::closure_response(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SingleServerMessage_oneof_message::broadcast_response(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -3296,6 +6869,21 @@ impl ::protobuf::Message for SingleServerMessage {
                     SingleServerMessage::has_consensus_response,
                     SingleServerMessage::get_consensus_response,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerArtifactResponse>(
+                    "artifact_response",
+                    SingleServerMessage::has_artifact_response,
+                    SingleServerMessage::get_artifact_response,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerClosureResponse>(
+                    "closure_response",
+                    SingleServerMessage::has_closure_response,
+                    SingleServerMessage::get_closure_response,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, WorkerBroadcastResponse>(
+                    "broadcast_response",
+                    SingleServerMessage::has_broadcast_response,
+                    SingleServerMessage::get_broadcast_response,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SingleServerMessage>(
                     "SingleServerMessage",
                     fields,
@@ -3324,6 +6912,9 @@ impl ::protobuf::Clear for SingleServerMessage {
         self.clear_finished_response();
         self.clear_cancellation_request();
         self.clear_consensus_response();
+        self.clear_artifact_response();
+        self.clear_closure_response();
+        self.clear_broadcast_response();
         self.unknown_fields.clear();
     }
 }
@@ -3341,54 +6932,100 @@ impl ::protobuf::reflect::ProtobufValue for SingleServerMessage {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x13intra_cluster.proto\"\x96\x01\n\x17WorkerConnectionRequest\x12&\n\
-    \x0eauthentication\x18\x01\x20\x01(\tR\x0eauthentication\x12\x1e\n\x0bno\
-    _of_cores\x18\x02\x20\x01(\x05R\tnoOfCores\x12\x12\n\x04port\x18\x03\x20\
-    \x01(\x05R\x04port\x12\x1f\n\x0bip_override\x18\x04\x20\x01(\tR\nipOverr\
-    ide\"h\n\x18WorkerConnectionResponse\x12\x1b\n\tworker_id\x18\x01\x20\
-    \x01(\tR\x08workerId\x12/\n\x13connection_accepted\x18\x02\x20\x01(\x08R\
-    \x12connectionAccepted\"5\n\x16WorkerHeartbeatRequest\x12\x1b\n\tworker_\
-    id\x18\x01\x20\x01(\tR\x08workerId\"\xd4\x01\n\x17WorkerHeartbeatRespons\
-    e\x12@\n\x06status\x18\x01\x20\x01(\x0e2(.WorkerHeartbeatResponse.Heartb\
-    eatStatusR\x06status\x12\x17\n\x07task_id\x18\x02\x20\x01(\tR\x06taskId\
-    \"^\n\x0fHeartbeatStatus\x12\x11\n\rAWAITING_TASK\x10\0\x12\x13\n\x0fPRO\
-    CESSING_TASK\x10\x01\x12\x0f\n\x0bHALTED_TASK\x10\x02\x12\x12\n\x0eCANCE\
-    LLED_TASK\x10\x03\"\xbf\x02\n\x1bWorkerTaskSubmissionRequest\x12\x1b\n\t\
-    worker_id\x18\x01\x20\x01(\tR\x08workerId\x12\x17\n\x07task_id\x18\x02\
-    \x20\x01(\tR\x06taskId\x12\x1f\n\x0bdocker_name\x18\x03\x20\x01(\tR\ndoc\
-    kerName\x12\x17\n\x07data_in\x18\x04\x20\x03(\x0cR\x06dataIn\x12\x18\n\
-    \x07closure\x18\x05\x20\x01(\x0cR\x07closure\x12?\n\x08map_type\x18\x06\
-    \x20\x01(\x0e2$.WorkerTaskSubmissionRequest.MapTypeR\x07mapType\"U\n\x07\
-    MapType\x12\x17\n\x13SINGLE_IN_MULTI_OUT\x10\0\x12\x18\n\x14SINGLE_IN_SI\
-    NGLE_OUT\x10\x01\x12\x17\n\x13MULTI_IN_SINGLE_OUT\x10\x02\"\xe2\x01\n\
-    \x15WorkerFinishedRequest\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\x08wo\
-    rkerId\x12\x17\n\x07task_id\x18\x02\x20\x01(\tR\x06taskId\x12?\n\x06stat\
-    us\x18\x03\x20\x01(\x0e2'.WorkerFinishedRequest.WorkerTaskStatusR\x06sta\
-    tus\x12\x19\n\x08data_out\x18\x04\x20\x03(\x0cR\x07dataOut\"7\n\x10Worke\
-    rTaskStatus\x12\x11\n\rTASK_FINISHED\x10\0\x12\x10\n\x0cTASK_ERRORED\x10\
-    \x01\"G\n\x16WorkerFinishedResponse\x12-\n\x12response_processed\x18\x01\
-    \x20\x01(\x08R\x11responseProcessed\"<\n\x1dWorkerTaskCancellationReques\
-    t\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\x08workerId\"}\n\x10Consensus\
-    Request\x120\n\x06action\x18\x01\x20\x01(\x0e2\x18.ConsensusRequest.Acti\
-    onR\x06action\"7\n\x06Action\x12\x0e\n\nSET_ACTIVE\x10\0\x12\x0f\n\x0bSE\
-    T_PASSIVE\x10\x01\x12\x0c\n\x08SHUTDOWN\x10\x02\"\x13\n\x11ConsensusResp\
-    onse\"\xbd\x02\n\x13SingleWorkerMessage\x12I\n\x12connection_request\x18\
-    \x01\x20\x01(\x0b2\x18.WorkerConnectionRequestH\0R\x11connectionRequest\
-    \x12I\n\x12heartbeat_response\x18\x02\x20\x01(\x0b2\x18.WorkerHeartbeatR\
-    esponseH\0R\x11heartbeatResponse\x12C\n\x10finished_request\x18\x03\x20\
-    \x01(\x0b2\x16.WorkerFinishedRequestH\0R\x0ffinishedRequest\x12@\n\x11co\
-    nsensus_request\x18\x04\x20\x01(\x0b2\x11.ConsensusRequestH\0R\x10consen\
-    susRequestB\t\n\x07message\"\xe7\x03\n\x13SingleServerMessage\x12L\n\x13\
-    connection_response\x18\x01\x20\x01(\x0b2\x19.WorkerConnectionResponseH\
-    \0R\x12connectionResponse\x12F\n\x11heartbeat_request\x18\x02\x20\x01(\
-    \x0b2\x17.WorkerHeartbeatRequestH\0R\x10heartbeatRequest\x12M\n\x12submi\
-    ssion_request\x18\x03\x20\x01(\x0b2\x1c.WorkerTaskSubmissionRequestH\0R\
-    \x11submissionRequest\x12F\n\x11finished_response\x18\x04\x20\x01(\x0b2\
-    \x17.WorkerFinishedResponseH\0R\x10finishedResponse\x12S\n\x14cancellati\
-    on_request\x18\x05\x20\x01(\x0b2\x1e.WorkerTaskCancellationRequestH\0R\
-    \x13cancellationRequest\x12C\n\x12consensus_response\x18\x06\x20\x01(\
-    \x0b2\x12.ConsensusResponseH\0R\x11consensusResponseB\t\n\x07messageb\
-    \x06proto3\
+    \n\x13intra_cluster.proto\"5\n\x0bWorkerLabel\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value\"\
+    \xbc\x01\n\x17WorkerConnectionRequest\x12&\n\x0eauthentication\x18\x01\
+    \x20\x01(\tR\x0eauthentication\x12\x1e\n\x0bno_of_cores\x18\x02\x20\x01(\
+    \x05R\tnoOfCores\x12\x12\n\x04port\x18\x03\x20\x01(\x05R\x04port\x12\x1f\
+    \n\x0bip_override\x18\x04\x20\x01(\tR\nipOverride\x12$\n\x06labels\x18\
+    \x05\x20\x03(\x0b2\x0c.WorkerLabelR\x06labels\"h\n\x18WorkerConnectionRe\
+    sponse\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\x08workerId\x12/\n\x13co\
+    nnection_accepted\x18\x02\x20\x01(\x08R\x12connectionAccepted\"5\n\x16Wo\
+    rkerHeartbeatRequest\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\x08workerI\
+    d\"\x9b\x02\n\x17WorkerHeartbeatResponse\x12@\n\x06status\x18\x01\x20\
+    \x01(\x0e2(.WorkerHeartbeatResponse.HeartbeatStatusR\x06status\x12\x17\n\
+    \x07task_id\x18\x02\x20\x01(\tR\x06taskId\x12\x1a\n\x08progress\x18\x03\
+    \x20\x01(\x02R\x08progress\x12)\n\x10progress_message\x18\x04\x20\x01(\t\
+    R\x0fprogressMessage\"^\n\x0fHeartbeatStatus\x12\x11\n\rAWAITING_TASK\
+    \x10\0\x12\x13\n\x0fPROCESSING_TASK\x10\x01\x12\x0f\n\x0bHALTED_TASK\x10\
+    \x02\x12\x12\n\x0eCANCELLED_TASK\x10\x03\"s\n\x14WorkerResourceLimits\
+    \x12\x1b\n\tmemory_mb\x18\x01\x20\x01(\x05R\x08memoryMb\x12\x1f\n\x0bcpu\
+    _percent\x18\x02\x20\x01(\x05R\ncpuPercent\x12\x1d\n\nopen_files\x18\x03\
+    \x20\x01(\x05R\topenFiles\"C\n\x19WorkerEnvironmentVariable\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
+    R\x05value\"=\n\x0fWorkerBroadcast\x12\x12\n\x04name\x18\x01\x20\x01(\tR\
+    \x04name\x12\x16\n\x06digest\x18\x02\x20\x01(\tR\x06digest\"\x91\x01\n\
+    \x10WorkerExecutable\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\
+    \x12\n\x04args\x18\x02\x20\x03(\tR\x04args\x12,\n\x03env\x18\x03\x20\x03\
+    (\x0b2\x1a.WorkerEnvironmentVariableR\x03env\x12'\n\x0fartifact_digest\
+    \x18\x04\x20\x01(\tR\x0eartifactDigest\"\x9d\x04\n\x1bWorkerTaskSubmissi\
+    onRequest\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\x08workerId\x12\x17\n\
+    \x07task_id\x18\x02\x20\x01(\tR\x06taskId\x12\x1f\n\x0bdocker_name\x18\
+    \x03\x20\x01(\tR\ndockerName\x12\x17\n\x07data_in\x18\x04\x20\x03(\x0cR\
+    \x06dataIn\x12\x18\n\x07closure\x18\x05\x20\x01(\x0cR\x07closure\x12?\n\
+    \x08map_type\x18\x06\x20\x01(\x0e2$.WorkerTaskSubmissionRequest.MapTypeR\
+    \x07mapType\x12!\n\x0ctask_timeout\x18\x07\x20\x01(\x05R\x0btaskTimeout\
+    \x12-\n\x06limits\x18\x08\x20\x01(\x0b2\x15.WorkerResourceLimitsR\x06lim\
+    its\x121\n\nexecutable\x18\t\x20\x01(\x0b2\x11.WorkerExecutableR\nexecut\
+    able\x12%\n\x0eclosure_digest\x18\n\x20\x01(\tR\rclosureDigest\x120\n\nb\
+    roadcasts\x18\x0b\x20\x03(\x0b2\x10.WorkerBroadcastR\nbroadcasts\"U\n\
+    \x07MapType\x12\x17\n\x13SINGLE_IN_MULTI_OUT\x10\0\x12\x18\n\x14SINGLE_I\
+    N_SINGLE_OUT\x10\x01\x12\x17\n\x13MULTI_IN_SINGLE_OUT\x10\x02\"=\n\x11Wo\
+    rkerAccumulator\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\
+    \x05value\x18\x02\x20\x01(\x01R\x05value\"\xdc\x02\n\x15WorkerFinishedRe\
+    quest\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\x08workerId\x12\x17\n\x07\
+    task_id\x18\x02\x20\x01(\tR\x06taskId\x12?\n\x06status\x18\x03\x20\x01(\
+    \x0e2'.WorkerFinishedRequest.WorkerTaskStatusR\x06status\x12\x19\n\x08da\
+    ta_out\x18\x04\x20\x03(\x0cR\x07dataOut\x126\n\x0caccumulators\x18\x05\
+    \x20\x03(\x0b2\x12.WorkerAccumulatorR\x0caccumulators\"y\n\x10WorkerTask\
+    Status\x12\x11\n\rTASK_FINISHED\x10\0\x12\x10\n\x0cTASK_ERRORED\x10\x01\
+    \x12\x12\n\x0eTASK_TIMED_OUT\x10\x02\x12\x16\n\x12TASK_OUT_OF_MEMORY\x10\
+    \x03\x12\x14\n\x10TASK_UNAVAILABLE\x10\x04\"G\n\x16WorkerFinishedRespons\
+    e\x12-\n\x12response_processed\x18\x01\x20\x01(\x08R\x11responseProcesse\
+    d\"<\n\x1dWorkerTaskCancellationRequest\x12\x1b\n\tworker_id\x18\x01\x20\
+    \x01(\tR\x08workerId\"L\n\x15WorkerArtifactRequest\x12\x1b\n\tworker_id\
+    \x18\x01\x20\x01(\tR\x08workerId\x12\x16\n\x06digest\x18\x02\x20\x01(\tR\
+    \x06digest\"b\n\x16WorkerArtifactResponse\x12\x16\n\x06digest\x18\x01\
+    \x20\x01(\tR\x06digest\x12\x14\n\x05found\x18\x02\x20\x01(\x08R\x05found\
+    \x12\x1a\n\x08artifact\x18\x03\x20\x01(\x0cR\x08artifact\"d\n\x14WorkerC\
+    losureRequest\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\x08workerId\x12\
+    \x17\n\x07task_id\x18\x02\x20\x01(\tR\x06taskId\x12\x16\n\x06digest\x18\
+    \x03\x20\x01(\tR\x06digest\"_\n\x15WorkerClosureResponse\x12\x16\n\x06di\
+    gest\x18\x01\x20\x01(\tR\x06digest\x12\x14\n\x05found\x18\x02\x20\x01(\
+    \x08R\x05found\x12\x18\n\x07closure\x18\x03\x20\x01(\x0cR\x07closure\"M\
+    \n\x16WorkerBroadcastRequest\x12\x1b\n\tworker_id\x18\x01\x20\x01(\tR\
+    \x08workerId\x12\x16\n\x06digest\x18\x02\x20\x01(\tR\x06digest\"]\n\x17W\
+    orkerBroadcastResponse\x12\x16\n\x06digest\x18\x01\x20\x01(\tR\x06digest\
+    \x12\x14\n\x05found\x18\x02\x20\x01(\x08R\x05found\x12\x14\n\x05value\
+    \x18\x03\x20\x01(\x0cR\x05value\"}\n\x10ConsensusRequest\x120\n\x06actio\
+    n\x18\x01\x20\x01(\x0e2\x18.ConsensusRequest.ActionR\x06action\"7\n\x06A\
+    ction\x12\x0e\n\nSET_ACTIVE\x10\0\x12\x0f\n\x0bSET_PASSIVE\x10\x01\x12\
+    \x0c\n\x08SHUTDOWN\x10\x02\"\x13\n\x11ConsensusResponse\"\x8c\x04\n\x13S\
+    ingleWorkerMessage\x12I\n\x12connection_request\x18\x01\x20\x01(\x0b2\
+    \x18.WorkerConnectionRequestH\0R\x11connectionRequest\x12I\n\x12heartbea\
+    t_response\x18\x02\x20\x01(\x0b2\x18.WorkerHeartbeatResponseH\0R\x11hear\
+    tbeatResponse\x12C\n\x10finished_request\x18\x03\x20\x01(\x0b2\x16.Worke\
+    rFinishedRequestH\0R\x0ffinishedRequest\x12@\n\x11consensus_request\x18\
+    \x04\x20\x01(\x0b2\x11.ConsensusRequestH\0R\x10consensusRequest\x12C\n\
+    \x10artifact_request\x18\x05\x20\x01(\x0b2\x16.WorkerArtifactRequestH\0R\
+    \x0fartifactRequest\x12@\n\x0fclosure_request\x18\x06\x20\x01(\x0b2\x15.\
+    WorkerClosureRequestH\0R\x0eclosureRequest\x12F\n\x11broadcast_request\
+    \x18\x07\x20\x01(\x0b2\x17.WorkerBroadcastRequestH\0R\x10broadcastReques\
+    tB\t\n\x07message\"\xbf\x05\n\x13SingleServerMessage\x12L\n\x13connectio\
+    n_response\x18\x01\x20\x01(\x0b2\x19.WorkerConnectionResponseH\0R\x12con\
+    nectionResponse\x12F\n\x11heartbeat_request\x18\x02\x20\x01(\x0b2\x17.Wo\
+    rkerHeartbeatRequestH\0R\x10heartbeatRequest\x12M\n\x12submission_reques\
+    t\x18\x03\x20\x01(\x0b2\x1c.WorkerTaskSubmissionRequestH\0R\x11submissio\
+    nRequest\x12F\n\x11finished_response\x18\x04\x20\x01(\x0b2\x17.WorkerFin\
+    ishedResponseH\0R\x10finishedResponse\x12S\n\x14cancellation_request\x18\
+    \x05\x20\x01(\x0b2\x1e.WorkerTaskCancellationRequestH\0R\x13cancellation\
+    Request\x12C\n\x12consensus_response\x18\x06\x20\x01(\x0b2\x12.Consensus\
+    ResponseH\0R\x11consensusResponse\x12F\n\x11artifact_response\x18\x07\
+    \x20\x01(\x0b2\x17.WorkerArtifactResponseH\0R\x10artifactResponse\x12C\n\
+    \x10closure_response\x18\x08\x20\x01(\x0b2\x16.WorkerClosureResponseH\0R\
+    \x0fclosureResponse\x12I\n\x12broadcast_response\x18\t\x20\x01(\x0b2\x18\
+    .WorkerBroadcastResponseH\0R\x11broadcastResponseB\t\n\x07messageb\x06pr\
+    oto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
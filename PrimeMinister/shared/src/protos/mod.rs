@@ -6,3 +6,4 @@
 pub mod intra_cluster;
 pub mod user_cluster;
 pub mod consensus;
+pub mod sessions;
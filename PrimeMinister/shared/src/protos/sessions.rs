@@ -0,0 +1,1018 @@
+// This file is generated by rust-protobuf 2.0.5. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SavedVariable {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl SavedVariable {
+    pub fn new() -> SavedVariable {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    // string value = 2;
+
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl ::protobuf::Message for SavedVariable {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_string(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SavedVariable {
+        SavedVariable::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "key",
+                    |m: &SavedVariable| { &m.key },
+                    |m: &mut SavedVariable| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &SavedVariable| { &m.value },
+                    |m: &mut SavedVariable| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SavedVariable>(
+                    "SavedVariable",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SavedVariable {
+        static mut instance: ::protobuf::lazy::Lazy<SavedVariable> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SavedVariable,
+        };
+        unsafe {
+            instance.get(SavedVariable::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SavedVariable {
+    fn clear(&mut self) {
+        self.clear_key();
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SavedVariable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SavedVariable {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SavedSubmission {
+    // message fields
+    pub idempotency_key: ::std::string::String,
+    pub digest: ::std::string::String,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl SavedSubmission {
+    pub fn new() -> SavedSubmission {
+        ::std::default::Default::default()
+    }
+
+    // string idempotency_key = 1;
+
+    pub fn clear_idempotency_key(&mut self) {
+        self.idempotency_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_idempotency_key(&mut self, v: ::std::string::String) {
+        self.idempotency_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_idempotency_key(&mut self) -> &mut ::std::string::String {
+        &mut self.idempotency_key
+    }
+
+    // Take field
+    pub fn take_idempotency_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.idempotency_key, ::std::string::String::new())
+    }
+
+    pub fn get_idempotency_key(&self) -> &str {
+        &self.idempotency_key
+    }
+
+    // string digest = 2;
+
+    pub fn clear_digest(&mut self) {
+        self.digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_digest(&mut self, v: ::std::string::String) {
+        self.digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.digest
+    }
+
+    // Take field
+    pub fn take_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.digest, ::std::string::String::new())
+    }
+
+    pub fn get_digest(&self) -> &str {
+        &self.digest
+    }
+}
+
+impl ::protobuf::Message for SavedSubmission {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.idempotency_key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.digest)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.idempotency_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.idempotency_key);
+        }
+        if !self.digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.digest);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.idempotency_key.is_empty() {
+            os.write_string(1, &self.idempotency_key)?;
+        }
+        if !self.digest.is_empty() {
+            os.write_string(2, &self.digest)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SavedSubmission {
+        SavedSubmission::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "idempotency_key",
+                    |m: &SavedSubmission| { &m.idempotency_key },
+                    |m: &mut SavedSubmission| { &mut m.idempotency_key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "digest",
+                    |m: &SavedSubmission| { &m.digest },
+                    |m: &mut SavedSubmission| { &mut m.digest },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SavedSubmission>(
+                    "SavedSubmission",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SavedSubmission {
+        static mut instance: ::protobuf::lazy::Lazy<SavedSubmission> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SavedSubmission,
+        };
+        unsafe {
+            instance.get(SavedSubmission::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SavedSubmission {
+    fn clear(&mut self) {
+        self.clear_idempotency_key();
+        self.clear_digest();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SavedSubmission {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SavedSubmission {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SavedSession {
+    // message fields
+    pub name: ::std::string::String,
+    pub user_id: ::std::string::String,
+    pub secret_digest: ::std::string::String,
+    pub docker_name: ::std::string::String,
+    pub executable_name: ::std::string::String,
+    pub executable_args: ::protobuf::RepeatedField<::std::string::String>,
+    pub executable_env: ::protobuf::RepeatedField<SavedVariable>,
+    pub artifact_digest: ::std::string::String,
+    pub jobs: ::protobuf::RepeatedField<::std::string::String>,
+    pub submissions: ::protobuf::RepeatedField<SavedSubmission>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl SavedSession {
+    pub fn new() -> SavedSession {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    // string user_id = 2;
+
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    // string secret_digest = 3;
+
+    pub fn clear_secret_digest(&mut self) {
+        self.secret_digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret_digest(&mut self, v: ::std::string::String) {
+        self.secret_digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.secret_digest
+    }
+
+    // Take field
+    pub fn take_secret_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret_digest, ::std::string::String::new())
+    }
+
+    pub fn get_secret_digest(&self) -> &str {
+        &self.secret_digest
+    }
+
+    // string docker_name = 4;
+
+    pub fn clear_docker_name(&mut self) {
+        self.docker_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_docker_name(&mut self, v: ::std::string::String) {
+        self.docker_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_docker_name(&mut self) -> &mut ::std::string::String {
+        &mut self.docker_name
+    }
+
+    // Take field
+    pub fn take_docker_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.docker_name, ::std::string::String::new())
+    }
+
+    pub fn get_docker_name(&self) -> &str {
+        &self.docker_name
+    }
+
+    // string executable_name = 5;
+
+    pub fn clear_executable_name(&mut self) {
+        self.executable_name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executable_name(&mut self, v: ::std::string::String) {
+        self.executable_name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_executable_name(&mut self) -> &mut ::std::string::String {
+        &mut self.executable_name
+    }
+
+    // Take field
+    pub fn take_executable_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.executable_name, ::std::string::String::new())
+    }
+
+    pub fn get_executable_name(&self) -> &str {
+        &self.executable_name
+    }
+
+    // repeated string executable_args = 6;
+
+    pub fn clear_executable_args(&mut self) {
+        self.executable_args.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executable_args(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.executable_args = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_executable_args(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.executable_args
+    }
+
+    // Take field
+    pub fn take_executable_args(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.executable_args, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_executable_args(&self) -> &[::std::string::String] {
+        &self.executable_args
+    }
+
+    // repeated .SavedVariable executable_env = 7;
+
+    pub fn clear_executable_env(&mut self) {
+        self.executable_env.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executable_env(&mut self, v: ::protobuf::RepeatedField<SavedVariable>) {
+        self.executable_env = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_executable_env(&mut self) -> &mut ::protobuf::RepeatedField<SavedVariable> {
+        &mut self.executable_env
+    }
+
+    // Take field
+    pub fn take_executable_env(&mut self) -> ::protobuf::RepeatedField<SavedVariable> {
+        ::std::mem::replace(&mut self.executable_env, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_executable_env(&self) -> &[SavedVariable] {
+        &self.executable_env
+    }
+
+    // string artifact_digest = 8;
+
+    pub fn clear_artifact_digest(&mut self) {
+        self.artifact_digest.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact_digest(&mut self, v: ::std::string::String) {
+        self.artifact_digest = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_artifact_digest(&mut self) -> &mut ::std::string::String {
+        &mut self.artifact_digest
+    }
+
+    // Take field
+    pub fn take_artifact_digest(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.artifact_digest, ::std::string::String::new())
+    }
+
+    pub fn get_artifact_digest(&self) -> &str {
+        &self.artifact_digest
+    }
+
+    // repeated string jobs = 9;
+
+    pub fn clear_jobs(&mut self) {
+        self.jobs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jobs(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.jobs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_jobs(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.jobs
+    }
+
+    // Take field
+    pub fn take_jobs(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.jobs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_jobs(&self) -> &[::std::string::String] {
+        &self.jobs
+    }
+
+    // repeated .SavedSubmission submissions = 10;
+
+    pub fn clear_submissions(&mut self) {
+        self.submissions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_submissions(&mut self, v: ::protobuf::RepeatedField<SavedSubmission>) {
+        self.submissions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_submissions(&mut self) -> &mut ::protobuf::RepeatedField<SavedSubmission> {
+        &mut self.submissions
+    }
+
+    // Take field
+    pub fn take_submissions(&mut self) -> ::protobuf::RepeatedField<SavedSubmission> {
+        ::std::mem::replace(&mut self.submissions, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_submissions(&self) -> &[SavedSubmission] {
+        &self.submissions
+    }
+}
+
+impl ::protobuf::Message for SavedSession {
+    fn is_initialized(&self) -> bool {
+        for v in &self.executable_env {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.submissions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret_digest)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.docker_name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.executable_name)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.executable_args)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.executable_env)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.artifact_digest)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.jobs)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.submissions)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if !self.secret_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.secret_digest);
+        }
+        if !self.docker_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.docker_name);
+        }
+        if !self.executable_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.executable_name);
+        }
+        for value in &self.executable_args {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        for value in &self.executable_env {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.artifact_digest.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.artifact_digest);
+        }
+        for value in &self.jobs {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
+        for value in &self.submissions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if !self.secret_digest.is_empty() {
+            os.write_string(3, &self.secret_digest)?;
+        }
+        if !self.docker_name.is_empty() {
+            os.write_string(4, &self.docker_name)?;
+        }
+        if !self.executable_name.is_empty() {
+            os.write_string(5, &self.executable_name)?;
+        }
+        for v in &self.executable_args {
+            os.write_string(6, &v)?;
+        };
+        for v in &self.executable_env {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.artifact_digest.is_empty() {
+            os.write_string(8, &self.artifact_digest)?;
+        }
+        for v in &self.jobs {
+            os.write_string(9, &v)?;
+        };
+        for v in &self.submissions {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SavedSession {
+        SavedSession::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &SavedSession| { &m.name },
+                    |m: &mut SavedSession| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "user_id",
+                    |m: &SavedSession| { &m.user_id },
+                    |m: &mut SavedSession| { &mut m.user_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "secret_digest",
+                    |m: &SavedSession| { &m.secret_digest },
+                    |m: &mut SavedSession| { &mut m.secret_digest },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "docker_name",
+                    |m: &SavedSession| { &m.docker_name },
+                    |m: &mut SavedSession| { &mut m.docker_name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "executable_name",
+                    |m: &SavedSession| { &m.executable_name },
+                    |m: &mut SavedSession| { &mut m.executable_name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "executable_args",
+                    |m: &SavedSession| { &m.executable_args },
+                    |m: &mut SavedSession| { &mut m.executable_args },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SavedVariable>>(
+                    "executable_env",
+                    |m: &SavedSession| { &m.executable_env },
+                    |m: &mut SavedSession| { &mut m.executable_env },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "artifact_digest",
+                    |m: &SavedSession| { &m.artifact_digest },
+                    |m: &mut SavedSession| { &mut m.artifact_digest },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "jobs",
+                    |m: &SavedSession| { &m.jobs },
+                    |m: &mut SavedSession| { &mut m.jobs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SavedSubmission>>(
+                    "submissions",
+                    |m: &SavedSession| { &m.submissions },
+                    |m: &mut SavedSession| { &mut m.submissions },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SavedSession>(
+                    "SavedSession",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SavedSession {
+        static mut instance: ::protobuf::lazy::Lazy<SavedSession> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SavedSession,
+        };
+        unsafe {
+            instance.get(SavedSession::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SavedSession {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_user_id();
+        self.clear_secret_digest();
+        self.clear_docker_name();
+        self.clear_executable_name();
+        self.clear_executable_args();
+        self.clear_executable_env();
+        self.clear_artifact_digest();
+        self.clear_jobs();
+        self.clear_submissions();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SavedSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SavedSession {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0esessions.proto\"7\n\rSavedVariable\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value\"R\n\x0f\
+    SavedSubmission\x12'\n\x0fidempotency_key\x18\x01\x20\x01(\tR\x0eidempot\
+    encyKey\x12\x16\n\x06digest\x18\x02\x20\x01(\tR\x06digest\"\xfb\x02\n\
+    \x0cSavedSession\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x17\n\
+    \x07user_id\x18\x02\x20\x01(\tR\x06userId\x12#\n\rsecret_digest\x18\x03\
+    \x20\x01(\tR\x0csecretDigest\x12\x1f\n\x0bdocker_name\x18\x04\x20\x01(\t\
+    R\ndockerName\x12'\n\x0fexecutable_name\x18\x05\x20\x01(\tR\x0eexecutabl\
+    eName\x12'\n\x0fexecutable_args\x18\x06\x20\x03(\tR\x0eexecutableArgs\
+    \x125\n\x0eexecutable_env\x18\x07\x20\x03(\x0b2\x0e.SavedVariableR\rexec\
+    utableEnv\x12'\n\x0fartifact_digest\x18\x08\x20\x01(\tR\x0eartifactDiges\
+    t\x12\x12\n\x04jobs\x18\t\x20\x03(\tR\x04jobs\x122\n\x0bsubmissions\x18\
+    \n\x20\x03(\x0b2\x10.SavedSubmissionR\x0bsubmissionsb\x06proto3\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
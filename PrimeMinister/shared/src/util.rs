@@ -13,6 +13,7 @@ use rand::distributions::Alphanumeric;
 use byteorder::{ReadBytesExt, BigEndian};
 use protobuf::{Message, ProtobufError, CodedInputStream};
 use protobuf::error::WireError;
+use sha2::{Digest, Sha256};
 
 pub fn random_alphanum_string(length: usize) -> String {
     return thread_rng()
@@ -21,6 +22,20 @@ pub fn random_alphanum_string(length: usize) -> String {
         .collect();
 }
 
+// Hex encoded SHA-256, used to address uploaded artifacts
+pub fn sha256_digest(bytes: &[u8]) -> String {
+    return format!("{:x}", Sha256::digest(bytes));
+}
+
+// Compares every byte whatever the first difference, so secrets cannot be guessed one byte at a time.
+// Workers have their own copy in MemberOfParliament/src/util.rs
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    return a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0;
+}
+
 pub fn process_input<T: Message>(mut stream: &TcpStream, message: &mut T, consensus_mode: bool) -> Result<(usize), ProtobufError> {
     let size_option = stream.read_u32::<BigEndian>();
     if size_option.is_err() {
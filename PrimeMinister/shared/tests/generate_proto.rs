@@ -12,7 +12,7 @@ use protoc_rust::Customize;
 fn generate_proto() {
     protoc_rust::run(protoc_rust::Args {
         out_dir: "src/protos",
-        input: &["protos/intra_cluster.proto", "protos/user_cluster.proto", "protos/consensus.proto"],
+        input: &["protos/intra_cluster.proto", "protos/user_cluster.proto", "protos/consensus.proto", "protos/sessions.proto"],
         includes: &["protos"],
         customize: Customize {
             ..Default::default()